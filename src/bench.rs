@@ -0,0 +1,71 @@
+use std::time::{Duration, Instant};
+
+use crate::cpu::CpuKind;
+
+// An endless loop of loads, stores, ALU operations, calls and stack traffic, in
+// 8080 code that runs the same on every core
+#[rustfmt::skip]
+const WORKLOAD: [u8; 32] = [
+    0x31, 0x00, 0xF0, // LXI SP,F000
+    0x21, 0x00, 0x20, // LXI H,2000
+    0x06, 0x00, // MVI B,0
+    0x7E, // MOV A,M
+    0x80, // ADD B
+    0x77, // MOV M,A
+    0x23, // INX H
+    0xA9, // XRA C
+    0x07, // RLC
+    0x4F, // MOV C,A
+    0xCD, 0x19, 0x00, // CALL 0019
+    0x05, // DCR B
+    0xC2, 0x08, 0x00, // JNZ 0008
+    0xC3, 0x03, 0x00, // JMP 0003
+    0xC5, // PUSH B
+    0xE6, 0x0F, // ANI 0F
+    0xFE, 0x07, // CPI 07
+    0xC1, // POP B
+    0xC9, // RET
+];
+
+/// Runs a fixed workload on a `kind` core for at least `cycles` emulated cycles.
+/// Returns the cycles actually run and the host time they took.
+pub fn run(kind: CpuKind, cycles: u64) -> (u64, Duration) {
+    let mut mem = vec![0; 0x10000];
+    mem[..WORKLOAD.len()].copy_from_slice(&WORKLOAD);
+    let mut cpu = kind.build(&mut mem);
+
+    let start = Instant::now();
+    while cpu.cycles() < cycles {
+        cpu.step();
+    }
+    (cpu.cycles(), start.elapsed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::emulator::Status;
+
+    #[test]
+    fn workload_keeps_running() {
+        for kind in [
+            CpuKind::I8080,
+            CpuKind::I8085,
+            CpuKind::Z80,
+            CpuKind::Cached,
+            CpuKind::Lockstep,
+            CpuKind::Native,
+        ] {
+            let (cycles, _) = run(kind, 100_000);
+            assert!(cycles >= 100_000);
+        }
+
+        let mut mem = vec![0; 0x10000];
+        mem[..WORKLOAD.len()].copy_from_slice(&WORKLOAD);
+        let mut cpu = CpuKind::I8080.build(&mut mem);
+        for _ in 0..10_000 {
+            assert_eq!(cpu.step(), Status::Running);
+        }
+        assert_eq!(cpu.sp(), 0xF000);
+    }
+}
@@ -0,0 +1,267 @@
+use std::error;
+use std::ops::Range;
+
+use crate::emulator::native::invaders;
+use crate::emulator::{Cached, Io, Lockstep, Model, Native, State, Status, Unconnected};
+use crate::z80::Z80;
+
+/// The registers every core shares with the 8080, for machines, debuggers and
+/// tracers. `flags` is the low byte of PSW, or F on a Z80.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Registers {
+    pub a: u8,
+    pub flags: u8,
+    pub bc: u16,
+    pub de: u16,
+    pub hl: u16,
+    pub sp: u16,
+    pub pc: u16,
+}
+
+/// A CPU core, as machines drive it.
+pub trait Cpu {
+    /// Executes one instruction, or idles for a few cycles while halted.
+    fn step_io(&mut self, io: &mut dyn Io) -> Status;
+
+    fn step(&mut self) -> Status {
+        self.step_io(&mut Unconnected)
+    }
+
+    /// Pulls the RESET line.
+    fn reset(&mut self);
+
+    /// Requests an interrupt with `RST vector` on the data bus.
+    fn interrupt(&mut self, vector: u8);
+
+    /// Requests an interrupt with `data` on the data bus during acknowledge.
+    fn interrupt_with(&mut self, data: &[u8]);
+
+    fn cycles(&self) -> u64;
+
+    fn status(&self) -> Status;
+
+    fn registers(&self) -> Registers;
+
+    fn set_registers(&mut self, registers: Registers);
+
+    fn memory(&self) -> &[u8];
+
+    /// Memory for the host to write directly, ignoring write protection.
+    fn memory_mut(&mut self) -> &mut [u8];
+
+    /// Ignores writes to `range`, as for ROM or unpopulated address space.
+    fn protect(&mut self, range: Range<usize>);
+
+    /// Everything about the CPU but memory, for `load_state` to restore.
+    fn save_state(&self) -> Vec<u8>;
+
+    fn load_state(&mut self, state: &[u8]) -> Result<(), Box<dyn error::Error>>;
+
+    fn a(&self) -> u8 {
+        self.registers().a
+    }
+
+    fn set_a(&mut self, value: u8) {
+        let registers = self.registers();
+        self.set_registers(Registers {
+            a: value,
+            ..registers
+        });
+    }
+
+    fn bc(&self) -> u16 {
+        self.registers().bc
+    }
+
+    fn set_bc(&mut self, value: u16) {
+        let registers = self.registers();
+        self.set_registers(Registers {
+            bc: value,
+            ..registers
+        });
+    }
+
+    fn de(&self) -> u16 {
+        self.registers().de
+    }
+
+    fn set_de(&mut self, value: u16) {
+        let registers = self.registers();
+        self.set_registers(Registers {
+            de: value,
+            ..registers
+        });
+    }
+
+    fn hl(&self) -> u16 {
+        self.registers().hl
+    }
+
+    fn set_hl(&mut self, value: u16) {
+        let registers = self.registers();
+        self.set_registers(Registers {
+            hl: value,
+            ..registers
+        });
+    }
+
+    fn sp(&self) -> u16 {
+        self.registers().sp
+    }
+
+    fn set_sp(&mut self, value: u16) {
+        let registers = self.registers();
+        self.set_registers(Registers {
+            sp: value,
+            ..registers
+        });
+    }
+
+    fn pc(&self) -> u16 {
+        self.registers().pc
+    }
+
+    fn set_pc(&mut self, value: u16) {
+        let registers = self.registers();
+        self.set_registers(Registers {
+            pc: value,
+            ..registers
+        });
+    }
+}
+
+/// Which CPU a machine is built with.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CpuKind {
+    I8080,
+    I8085,
+    Z80,
+    /// An 8080 running cached translations of its basic blocks
+    Cached,
+    /// A cached 8080 checked against the interpreter after every block
+    Lockstep,
+    /// An 8080 running Space Invaders recompiled to Rust, interpreting other code
+    Native,
+}
+
+impl CpuKind {
+    /// A core of this kind on `mem`.
+    pub fn build(self, mem: &mut [u8]) -> Box<dyn Cpu + '_> {
+        match self {
+            CpuKind::I8080 => Box::new(State::new(mem)),
+            CpuKind::I8085 => {
+                let mut cpu = State::new(mem);
+                cpu.set_model(Model::I8085);
+                Box::new(cpu)
+            }
+            CpuKind::Z80 => Box::new(Z80::new(mem)),
+            CpuKind::Cached => Box::new(Cached::new(mem)),
+            CpuKind::Lockstep => Box::new(Lockstep::new(Box::new(Cached::new(mem)))),
+            CpuKind::Native => Box::new(Native::new(mem, &invaders::BLOCKS)),
+        }
+    }
+}
+
+// Reads saved state back in the order it was written
+pub(crate) struct StateReader<'a> {
+    state: &'a [u8],
+}
+
+impl<'a> StateReader<'a> {
+    /// Checks `state` starts with `tag` and is `len` bytes long in all.
+    pub(crate) fn new(
+        state: &'a [u8],
+        tag: &[u8],
+        len: usize,
+    ) -> Result<StateReader<'a>, Box<dyn error::Error>> {
+        if !state.starts_with(tag) {
+            return Err("Saved state is for another CPU".into());
+        }
+        if state.len() != len {
+            return Err("Saved state is the wrong size".into());
+        }
+        Ok(StateReader {
+            state: &state[tag.len()..],
+        })
+    }
+
+    pub(crate) fn byte(&mut self) -> u8 {
+        let (first, rest) = self.state.split_first().unwrap();
+        self.state = rest;
+        *first
+    }
+
+    pub(crate) fn word(&mut self) -> u16 {
+        u16::from_le_bytes([self.byte(), self.byte()])
+    }
+
+    pub(crate) fn long(&mut self) -> u64 {
+        let mut bytes = [0; 8];
+        for byte in bytes.iter_mut() {
+            *byte = self.byte();
+        }
+        u64::from_le_bytes(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Runs a short program on each kind of core through the trait alone
+    #[test]
+    fn every_kind_through_the_trait() {
+        for kind in [
+            CpuKind::I8080,
+            CpuKind::I8085,
+            CpuKind::Z80,
+            CpuKind::Cached,
+            CpuKind::Lockstep,
+            CpuKind::Native,
+        ] {
+            let mut mem = vec![0; 0x10000];
+            // LXI H,1234 MVI A,56 PUSH H HLT
+            mem[..7].copy_from_slice(&[0x21, 0x34, 0x12, 0x3E, 0x56, 0xE5, 0x76]);
+            let mut cpu = kind.build(&mut mem);
+            cpu.set_sp(0x2000);
+
+            while cpu.step() == Status::Running {}
+
+            assert_eq!(cpu.hl(), 0x1234, "{:?}", kind);
+            assert_eq!(cpu.a(), 0x56, "{:?}", kind);
+            assert_eq!(cpu.pc(), 7, "{:?}", kind);
+            assert_eq!(&cpu.memory()[0x1FFE..0x2000], &[0x34, 0x12], "{:?}", kind);
+            assert!(cpu.cycles() > 0);
+        }
+    }
+
+    #[test]
+    fn save_and_load_state() {
+        for kind in [CpuKind::I8080, CpuKind::I8085, CpuKind::Z80] {
+            let mut mem = vec![0; 0x10000];
+            // MVI B,1 INR B INR B
+            mem[..4].copy_from_slice(&[0x06, 0x01, 0x04, 0x04]);
+            let mut cpu = kind.build(&mut mem);
+
+            cpu.step();
+            let saved = cpu.save_state();
+            cpu.step();
+            cpu.step();
+            assert_eq!(cpu.bc(), 0x0300);
+
+            cpu.load_state(&saved).unwrap();
+            assert_eq!(cpu.save_state(), saved, "{:?}", kind);
+            assert_eq!(cpu.registers().pc, 2);
+            cpu.step();
+            assert_eq!(cpu.bc(), 0x0200);
+
+            assert!(cpu.load_state(&saved[1..]).is_err());
+            assert!(cpu.load_state(&saved[..saved.len() - 1]).is_err());
+        }
+
+        let mut mem = vec![0; 0x10000];
+        let z80 = CpuKind::Z80.build(&mut mem).save_state();
+        let mut mem = vec![0; 0x10000];
+        assert!(CpuKind::I8080.build(&mut mem).load_state(&z80).is_err());
+    }
+}
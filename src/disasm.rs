@@ -1,17 +1,151 @@
+use std::collections::BTreeSet;
 use std::error;
 use std::fmt::Write;
 
+use crate::emulator::OPCODES;
+
+// Column the undocumented comment starts in
+const COMMENT_COLUMN: usize = 28;
+
+/// Whether the 8080 runs `opcode` only as an undocumented alias of another
+/// instruction: the NOPs at 0x08 to 0x38, JMP at 0xCB, RET at 0xD9 and the CALLs at
+/// 0xDD, 0xED and 0xFD. They disassemble as the instruction they alias.
+pub fn undocumented(opcode: u8) -> bool {
+    matches!(
+        opcode,
+        0x08 | 0x10 | 0x18 | 0x20 | 0x28 | 0x30 | 0x38 | 0xCB | 0xD9 | 0xDD | 0xED | 0xFD
+    )
+}
+
 pub fn disasm(file_contents: &[u8]) -> Result<String, Box<dyn error::Error>> {
+    disasm_with(file_contents, false)
+}
+
+/// Disassembles `file_contents`, with a comment on each undocumented opcode if
+/// `flag_undocumented` is set.
+pub fn disasm_with(
+    file_contents: &[u8],
+    flag_undocumented: bool,
+) -> Result<String, Box<dyn error::Error>> {
     let mut asm = String::new();
     let mut pos: usize = 0;
 
     while pos < file_contents.len() {
+        let line = asm.len();
+        let opcode = file_contents[pos];
         pos += disasm_single(&mut asm, file_contents, pos)?;
+
+        if flag_undocumented && undocumented(opcode) {
+            asm.pop();
+            let pad = COMMENT_COLUMN.saturating_sub(asm.len() - line);
+            writeln!(asm, "{:pad$}; undocumented", "", pad = pad)?;
+        }
     }
 
     Ok(asm)
 }
 
+/// Where an instruction can pass control besides the next instruction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Flow {
+    Next,
+    Jump {
+        target: usize,
+        conditional: bool,
+    },
+    /// Including RSTs
+    Call {
+        target: usize,
+        conditional: bool,
+    },
+    Return {
+        conditional: bool,
+    },
+    /// PCHL, to wherever HL points
+    Indirect,
+    /// HLT, which carries on with the next instruction after an interrupt
+    Halt,
+}
+
+impl Flow {
+    /// Whether the next instruction can run after this one, counting returns from
+    /// calls.
+    pub fn falls_through(self) -> bool {
+        match self {
+            Flow::Jump { conditional, .. } | Flow::Return { conditional } => conditional,
+            Flow::Indirect => false,
+            Flow::Next | Flow::Call { .. } | Flow::Halt => true,
+        }
+    }
+}
+
+/// How the instruction at `pos` in `code` passes control on.
+pub fn flow(code: &[u8], pos: usize) -> Flow {
+    let opcode = code[pos];
+    let byte = |offset| code.get(pos + offset).copied().unwrap_or(0);
+    let target = u16::from_le_bytes([byte(1), byte(2)]) as usize;
+
+    match opcode {
+        0x76 => Flow::Halt,
+        0xE9 => Flow::Indirect,
+        0xC3 | 0xCB => Flow::Jump {
+            target,
+            conditional: false,
+        },
+        0xC9 | 0xD9 => Flow::Return { conditional: false },
+        0xCD | 0xDD | 0xED | 0xFD => Flow::Call {
+            target,
+            conditional: false,
+        },
+        0xC0..=0xFF => match opcode & 0x07 {
+            0 => Flow::Return { conditional: true },
+            2 => Flow::Jump {
+                target,
+                conditional: true,
+            },
+            4 => Flow::Call {
+                target,
+                conditional: true,
+            },
+            7 => Flow::Call {
+                target: (opcode & 0x38) as usize,
+                conditional: false,
+            },
+            _ => Flow::Next,
+        },
+        _ => Flow::Next,
+    }
+}
+
+/// Disassembles recursively: the addresses of the instructions in `code`, loaded
+/// at 0, that are reachable from `entries` through jumps, calls and returns to
+/// their callers. Indirect jumps can only be followed at run time, so end a path.
+pub fn trace(code: &[u8], entries: &[usize]) -> BTreeSet<usize> {
+    let mut found = BTreeSet::new();
+    let mut pending = entries.to_vec();
+
+    while let Some(mut pos) = pending.pop() {
+        while pos < code.len() && found.insert(pos) {
+            let len = OPCODES[code[pos] as usize].len as usize;
+            if pos + len > code.len() {
+                found.remove(&pos);
+                break;
+            }
+
+            let flow = flow(code, pos);
+            if let Flow::Jump { target, .. } | Flow::Call { target, .. } = flow {
+                pending.push(target);
+            }
+            if !flow.falls_through() {
+                break;
+            }
+            pos += len;
+        }
+    }
+
+    found
+}
+
 pub fn disasm_single(
     asm: &mut String,
     code: &[u8],
@@ -764,7 +898,7 @@ pub fn disasm_single(
             op_len = 2;
         }
         0xDC => {
-            writeln!(asm, "CC     ${:02x}{:02x}", code[pos + 2], code[pos + 1])?;
+            writeln!(asm, "CC      ${:02x}{:02x}", code[pos + 2], code[pos + 1])?;
             op_len = 3;
         }
         0xDD => {
@@ -896,3 +1030,69 @@ pub fn disasm_single(
 
     Ok(op_len)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aliases_disassemble_as_what_they_run() {
+        let code = [
+            0x08, 0xCB, 0x00, 0x40, 0xD9, 0xDD, 0x34, 0x12, 0xDC, 0x00, 0x01,
+        ];
+
+        assert_eq!(
+            disasm(&code).unwrap(),
+            "0000   NOP\n\
+             0001   JMP     $4000\n\
+             0004   RET\n\
+             0005   CALL    $1234\n\
+             0008   CC      $0100\n"
+        );
+    }
+
+    #[test]
+    fn flag_undocumented() {
+        let code = [0x00, 0x38, 0xFD, 0x00, 0x02, 0xC3, 0x00, 0x00];
+
+        assert_eq!(
+            disasm_with(&code, true).unwrap(),
+            "0000   NOP\n\
+             0001   NOP                  ; undocumented\n\
+             0002   CALL    $0200        ; undocumented\n\
+             0005   JMP     $0000\n"
+        );
+        assert_eq!((0..=0xFF).filter(|op| undocumented(*op)).count(), 12);
+    }
+
+    #[test]
+    fn trace_follows_control_flow() {
+        #[rustfmt::skip]
+        let code = [
+            0xCD, 0x0A, 0x00, // CALL 000A
+            0xCA, 0x0C, 0x00, // JZ 000C
+            0xE9, // PCHL
+            0x3E, 0x01, // unreachable MVI A,1
+            0x00,
+            0xC9, // RET
+            0x00,
+            0x76, // HLT
+            0xC3, 0x10, // JMP cut short by the end of the code
+        ];
+
+        let found = trace(&code, &[0]);
+
+        assert_eq!(
+            found.into_iter().collect::<Vec<_>>(),
+            vec![0x00, 0x03, 0x06, 0x0A, 0x0C]
+        );
+        assert_eq!(flow(&code, 6), Flow::Indirect);
+        assert_eq!(
+            flow(&[0xFF], 0),
+            Flow::Call {
+                target: 0x38,
+                conditional: false
+            }
+        );
+    }
+}
@@ -1,12 +1,145 @@
+use std::error;
+use std::ops::Range;
+
+use crate::cpu::{Cpu, Registers, StateReader};
 use crate::disasm;
 
-struct ConditionCodes {
-    z: bool,
-    s: bool,
-    p: bool,
-    cy: bool,
-    ac: bool,
-    pad: bool,
+mod cache;
+pub mod native;
+mod opcodes;
+
+pub use cache::{Cached, Lockstep};
+pub use native::Native;
+pub use opcodes::{Opcode, OPCODES};
+
+/// Devices on the 8080's I/O ports, reached through `IN` and `OUT`.
+pub trait Io {
+    fn input(&mut self, port: u8) -> u8;
+    fn output(&mut self, port: u8, value: u8);
+}
+
+/// Nothing attached: reads float high and writes are dropped.
+pub struct Unconnected;
+
+impl Io for Unconnected {
+    fn input(&mut self, _port: u8) -> u8 {
+        0xFF
+    }
+
+    fn output(&mut self, _port: u8, _value: u8) {}
+}
+
+/// What the CPU is doing after a `step`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Status {
+    Running,
+    /// Stopped on a HLT until the next interrupt
+    Halted,
+    /// Stopped on a HLT with interrupts disabled, so nothing can wake it
+    Deadlock,
+}
+
+// Clock cycles used by each step while halted
+const HALT_CYCLES: u64 = 4;
+
+// Vectors and cycles for the 8085's own interrupt inputs
+const TRAP_VECTOR: u16 = 0x24;
+const RST5_5_VECTOR: u16 = 0x2C;
+const RST6_5_VECTOR: u16 = 0x34;
+const RST7_5_VECTOR: u16 = 0x3C;
+const INTERRUPT_8085_CYCLES: u64 = 12;
+
+/// Which Intel CPU a `State` behaves as.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Model {
+    I8080,
+    /// The 8080's instructions with the 8085's timings, plus RIM and SIM, the
+    /// RST 5.5, 6.5 and 7.5 and TRAP interrupt inputs and the serial pins
+    I8085,
+}
+
+// The 8085's extra pins and the interrupt masks SIM sets
+#[derive(Debug, Clone, Copy)]
+struct Pins8085 {
+    // Masks for RST 5.5, 6.5 and 7.5, in the low three bits as SIM takes them
+    masks: u8,
+    // RST 7.5 is latched on a rising edge, 5.5 and 6.5 are levels
+    rst7_5: bool,
+    rst6_5: bool,
+    rst5_5: bool,
+    trap: bool,
+    // Interrupt enable from before a TRAP, which the next RIM reports
+    trap_enable: Option<bool>,
+    sid: bool,
+    sod: bool,
+    // DSUB, ARHL, RDEL, LDHI, LDSI, RSTV, SHLX, LHLX, JNK and JK in place of the
+    // 8080's NOPs and aliases
+    undocumented: bool,
+}
+
+impl Default for Pins8085 {
+    fn default() -> Self {
+        Pins8085 {
+            masks: 0x07,
+            rst7_5: false,
+            rst6_5: false,
+            rst5_5: false,
+            trap: false,
+            trap_enable: None,
+            sid: false,
+            sod: false,
+            undocumented: false,
+        }
+    }
+}
+
+/// The five 8080 flags.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ConditionCodes {
+    pub z: bool,
+    pub s: bool,
+    pub p: bool,
+    pub cy: bool,
+    pub ac: bool,
+}
+
+impl ConditionCodes {
+    const SIGN: u8 = 0b10000000;
+    const ZERO: u8 = 0b01000000;
+    const AUX_CARRY: u8 = 0b00010000;
+    const PARITY: u8 = 0b00000100;
+    const CARRY: u8 = 0b00000001;
+    // Bit 1 always reads as set, bits 3 and 5 as clear
+    const FIXED: u8 = 0b00000010;
+
+    /// Packs the flags into the low byte of PSW: S Z 0 AC 0 P 1 CY.
+    pub fn to_byte(self) -> u8 {
+        let mut byte = Self::FIXED;
+        for (flag, bit) in [
+            (self.s, Self::SIGN),
+            (self.z, Self::ZERO),
+            (self.ac, Self::AUX_CARRY),
+            (self.p, Self::PARITY),
+            (self.cy, Self::CARRY),
+        ]
+        .iter()
+        {
+            if *flag {
+                byte |= bit;
+            }
+        }
+        byte
+    }
+
+    pub fn from_byte(byte: u8) -> ConditionCodes {
+        ConditionCodes {
+            z: byte & Self::ZERO != 0,
+            s: byte & Self::SIGN != 0,
+            p: byte & Self::PARITY != 0,
+            cy: byte & Self::CARRY != 0,
+            ac: byte & Self::AUX_CARRY != 0,
+        }
+    }
 }
 
 pub struct State<'a> {
@@ -15,8 +148,6 @@ pub struct State<'a> {
     c: u8,
     d: u8,
     e: u8,
-    f: u8,
-    g: u8,
     h: u8,
     l: u8,
 
@@ -25,20 +156,34 @@ pub struct State<'a> {
 
     cc: ConditionCodes,
     mem: &'a mut [u8],
+    read_only: Vec<Range<usize>>,
+    // Bytes the block cache has translated, and those of them written since it
+    // last looked. Empty unless the cache is in use.
+    watched: Vec<bool>,
+    written: Vec<usize>,
+
+    // The 8085's undocumented signed overflow and K flags, in bits 1 and 5 of PSW
+    overflow: bool,
+    k: bool,
 
     int_enable: bool,
+    halted: bool,
+    // Instruction placed on the data bus by an interrupting device
+    bus: Option<[u8; 3]>,
+    cycles: u64,
+
+    model: Model,
+    pins: Pins8085,
 }
 
 impl State<'_> {
-    pub fn new(mem: &mut [u8]) -> State {
+    pub fn new(mem: &mut [u8]) -> State<'_> {
         State {
             a: 0,
             b: 0,
             c: 0,
             d: 0,
             e: 0,
-            f: 0,
-            g: 0,
             h: 0,
             l: 0,
 
@@ -51,19 +196,33 @@ impl State<'_> {
                 p: true,
                 cy: true,
                 ac: true,
-                pad: true,
             },
 
             mem,
+            read_only: Vec::new(),
+            watched: Vec::new(),
+            written: Vec::new(),
+
+            overflow: false,
+            k: false,
 
             int_enable: true,
+            halted: false,
+            bus: None,
+            cycles: 0,
+
+            model: Model::I8080,
+            pins: Pins8085::default(),
         }
     }
 
-    pub fn start(&mut self) {
-        let mut running = true;
-        while running {
-            running = self.step();
+    /// Runs until the CPU halts, with nothing to interrupt it.
+    pub fn start(&mut self) -> Status {
+        loop {
+            let status = self.step();
+            if status != Status::Running {
+                return status;
+            }
         }
     }
 
@@ -82,10 +241,223 @@ impl State<'_> {
             let mut rep = String::new();
             disasm::disasm_single(&mut rep, self.mem, self.pc).expect("Failed to write");
             rep.pop();
-            println!("af: {:02x}{:02x}, bc: {:02x}{:02x}, de: {:02x}{:02x}, hl:{:02x}{:02x}, pc: {:04x}, sp: {:04x}\n{} opcode: {:02x} {:02x} {:02x}\nflags: z: {}, s: {}, p: {}, cy: {}",self.a, self.f, self.b, self.c, self.d, self.e, self.h, self.l, self.pc, self.sp, rep, self.mem[self.pc], self.mem[self.pc + 1], self.mem[self.pc + 2], self.cc.z, self.cc.s, self.cc.p, self.cc.cy);
+            println!("af: {:04x}, bc: {:04x}, de: {:04x}, hl:{:04x}, pc: {:04x}, sp: {:04x}\n{} opcode: {:02x} {:02x} {:02x}\nflags: z: {}, s: {}, p: {}, cy: {}, ac: {}",self.psw(), self.bc(), self.de(), self.hl(), self.pc, self.sp, rep, self.mem[self.pc], self.mem[self.pc + 1], self.mem[self.pc + 2], self.cc.z, self.cc.s, self.cc.p, self.cc.cy, self.cc.ac);
+        }
+    }
+
+    pub fn a(&self) -> u8 {
+        self.a
+    }
+
+    pub fn set_a(&mut self, value: u8) {
+        self.a = value;
+    }
+
+    pub fn bc(&self) -> u16 {
+        Self::extend(self.b, self.c)
+    }
+
+    pub fn set_bc(&mut self, value: u16) {
+        Self::assign_ref((&mut self.b, &mut self.c), Self::separate(value));
+    }
+
+    pub fn de(&self) -> u16 {
+        Self::extend(self.d, self.e)
+    }
+
+    pub fn set_de(&mut self, value: u16) {
+        Self::assign_ref((&mut self.d, &mut self.e), Self::separate(value));
+    }
+
+    pub fn hl(&self) -> u16 {
+        Self::extend(self.h, self.l)
+    }
+
+    pub fn set_hl(&mut self, value: u16) {
+        Self::assign_ref((&mut self.h, &mut self.l), Self::separate(value));
+    }
+
+    /// A in the high byte and the packed flags in the low byte, as PUSH PSW stores them.
+    pub fn psw(&self) -> u16 {
+        let mut flags = self.cc.to_byte();
+        if self.model == Model::I8085 {
+            flags &= !(Self::OVERFLOW | Self::K);
+            flags |= if self.overflow { Self::OVERFLOW } else { 0 };
+            flags |= if self.k { Self::K } else { 0 };
+        }
+        Self::extend(self.a, flags)
+    }
+
+    pub fn set_psw(&mut self, value: u16) {
+        let (a, flags) = Self::separate(value);
+        self.a = a;
+        self.cc = ConditionCodes::from_byte(flags);
+        self.overflow = flags & Self::OVERFLOW != 0;
+        self.k = flags & Self::K != 0;
+    }
+
+    pub fn sp(&self) -> u16 {
+        self.sp as u16
+    }
+
+    pub fn set_sp(&mut self, sp: u16) {
+        self.sp = sp as usize;
+    }
+
+    pub fn pc(&self) -> u16 {
+        self.pc as u16
+    }
+
+    pub fn set_pc(&mut self, pc: u16) {
+        self.pc = pc as usize;
+    }
+
+    pub fn flags(&self) -> ConditionCodes {
+        self.cc
+    }
+
+    pub fn set_flags(&mut self, flags: ConditionCodes) {
+        self.cc = flags;
+    }
+
+    pub fn memory(&self) -> &[u8] {
+        self.mem
+    }
+
+    /// Memory for the host to write directly, ignoring write protection.
+    pub fn memory_mut(&mut self) -> &mut [u8] {
+        self.mem
+    }
+
+    /// Pulls the RESET line: jumps to 0 with interrupts off, leaving the other
+    /// registers, memory and the cycle count alone. An 8085 also masks RST 5.5 to
+    /// 7.5 and clears SOD.
+    pub fn reset(&mut self) {
+        self.pc = 0;
+        self.int_enable = false;
+        self.halted = false;
+        self.bus = None;
+        self.pins = Pins8085 {
+            undocumented: self.pins.undocumented,
+            ..Pins8085::default()
+        };
+    }
+
+    pub fn model(&self) -> Model {
+        self.model
+    }
+
+    pub fn set_model(&mut self, model: Model) {
+        self.model = model;
+    }
+
+    /// Runs the undocumented 8085 instructions, when an 8085.
+    pub fn set_undocumented(&mut self, enabled: bool) {
+        self.pins.undocumented = enabled;
+    }
+
+    fn undocumented_8085(&self) -> bool {
+        self.model == Model::I8085 && self.pins.undocumented
+    }
+
+    /// Raises the 8085's non-maskable TRAP input.
+    pub fn trap(&mut self) {
+        self.pins.trap = true;
+    }
+
+    /// A rising edge on RST 7.5, latched until it's serviced or cleared by SIM.
+    pub fn rst7_5(&mut self) {
+        self.pins.rst7_5 = true;
+    }
+
+    pub fn set_rst6_5(&mut self, level: bool) {
+        self.pins.rst6_5 = level;
+    }
+
+    pub fn set_rst5_5(&mut self, level: bool) {
+        self.pins.rst5_5 = level;
+    }
+
+    /// The serial input pin, read by RIM.
+    pub fn set_sid(&mut self, level: bool) {
+        self.pins.sid = level;
+    }
+
+    /// The serial output pin, written by SIM.
+    pub fn sod(&self) -> bool {
+        self.pins.sod
+    }
+
+    // RIM: SID, pending RST 7.5, 6.5 and 5.5, interrupt enable and the masks
+    fn read_interrupt_mask(&mut self) -> u8 {
+        let enabled = self.pins.trap_enable.take().unwrap_or(self.int_enable);
+        let pins = &self.pins;
+        (pins.sid as u8) << 7
+            | (pins.rst7_5 as u8) << 6
+            | (pins.rst6_5 as u8) << 5
+            | (pins.rst5_5 as u8) << 4
+            | (enabled as u8) << 3
+            | pins.masks
+    }
+
+    // SIM: new masks if bit 3 is set, bit 4 clears the RST 7.5 latch and bit 6
+    // latches bit 7 onto SOD
+    fn set_interrupt_mask(&mut self, value: u8) {
+        if value & 0x08 != 0 {
+            self.pins.masks = value & 0x07;
+        }
+        if value & 0x10 != 0 {
+            self.pins.rst7_5 = false;
+        }
+        if value & 0x40 != 0 {
+            self.pins.sod = value & 0x80 != 0;
+        }
+    }
+
+    // Takes the highest priority 8085 interrupt that's pending and unmasked
+    fn service_8085(&mut self) {
+        let pins = &mut self.pins;
+        let vector = if pins.trap {
+            pins.trap = false;
+            pins.trap_enable = Some(self.int_enable);
+            TRAP_VECTOR
+        } else if !self.int_enable {
+            return;
+        } else if pins.rst7_5 && pins.masks & 0x04 == 0 {
+            pins.rst7_5 = false;
+            RST7_5_VECTOR
+        } else if pins.rst6_5 && pins.masks & 0x02 == 0 {
+            RST6_5_VECTOR
+        } else if pins.rst5_5 && pins.masks & 0x01 == 0 {
+            RST5_5_VECTOR
+        } else {
+            return;
+        };
+
+        self.int_enable = false;
+        self.halted = false;
+        self.push(self.pc as u16);
+        self.pc = vector as usize;
+        self.cycles += INTERRUPT_8085_CYCLES;
+    }
+
+    /// Ignores writes to `range`, as for ROM or unpopulated address space.
+    pub fn protect(&mut self, range: Range<usize>) {
+        self.read_only.push(range);
+    }
+
+    fn write_byte(&mut self, offset: usize, value: u8) {
+        if offset < self.mem.len() && !self.read_only.iter().any(|range| range.contains(&offset)) {
+            self.mem[offset] = value;
+            if self.watched.get(offset) == Some(&true) {
+                self.written.push(offset);
+            }
         }
     }
 
+    const OVERFLOW: u8 = 0b00000010;
+    const K: u8 = 0b00100000;
+
     fn extend(first: u8, second: u8) -> u16 {
         ((first as u16) << 8) | second as u16
     }
@@ -129,1405 +501,436 @@ impl State<'_> {
         *assigns.1 = value.1;
     }
 
-    fn add(&mut self, value: u8) {
-        let result = self.a as u16 + value as u16;
+    // Adds `value` and a carry in to A, setting every flag, and returns the result.
+    fn add_with(&mut self, value: u8, carry: bool) -> u8 {
+        self.add_bytes(self.a, value, carry)
+    }
+
+    fn add_bytes(&mut self, left: u8, right: u8, carry: bool) -> u8 {
+        let result = left as u16 + right as u16 + carry as u16;
+        self.cc.ac = (left & 0x0F) + (right & 0x0F) + carry as u8 > 0x0F;
         self.carry_flag(result);
-        self.arith_flags(result);
-        self.a = result as u8;
+        self.arith_flags(result & 0xFF);
+        // K follows the signed comparison, S xor V
+        self.overflow = (left ^ result as u8) & (right ^ result as u8) & 0x80 != 0;
+        self.k = self.cc.s != self.overflow;
+        result as u8
+    }
+
+    // The 8080 subtracts by adding the complement, so AC is the carry out of bit 3
+    // of that addition while CY is inverted to become the borrow.
+    fn sub_with(&mut self, value: u8, borrow: bool) -> u8 {
+        self.sub_bytes(self.a, value, borrow)
+    }
+
+    fn sub_bytes(&mut self, left: u8, right: u8, borrow: bool) -> u8 {
+        let result = self.add_bytes(left, !right, !borrow);
+        self.cc.cy = !self.cc.cy;
+        result
+    }
+
+    fn add(&mut self, value: u8) {
+        self.a = self.add_with(value, false);
     }
 
     fn add_cy(&mut self, value: u8) {
-        let result = self.a as u16 + value as u16 + self.cc.cy as u16;
-        self.carry_flag(result);
-        self.arith_flags(result);
-        self.a = result as u8;
+        self.a = self.add_with(value, self.cc.cy);
     }
 
     fn sub(&mut self, value: u8) {
-        let result = self.a as u16 - value as u16;
-        self.carry_flag(result);
-        self.arith_flags(result);
-        self.a = result as u8;
+        self.a = self.sub_with(value, false);
     }
 
     fn sub_cy(&mut self, value: u8) {
-        let result = self.a as u16 - value as u16 - self.cc.cy as u16;
-        self.carry_flag(result);
-        self.arith_flags(result);
-        self.a = result as u8;
+        self.a = self.sub_with(value, self.cc.cy);
     }
 
     fn and(&mut self, value: u8) {
         let result = self.a & value;
         self.cc.cy = false;
+        // ANA sets AC from bit 3 of either operand
+        self.cc.ac = (self.a | value) & 0x08 != 0;
         self.arith_flags(result as u16);
 
-        self.a = result as u8;
+        self.a = result;
     }
 
     fn xor(&mut self, value: u8) {
         let result = self.a ^ value;
         self.cc.cy = false;
+        self.cc.ac = false;
         self.arith_flags(result as u16);
 
-        self.a = result as u8;
+        self.a = result;
     }
 
     fn or(&mut self, value: u8) {
         let result = self.a | value;
         self.cc.cy = false;
+        self.cc.ac = false;
         self.arith_flags(result as u16);
 
-        self.a = result as u8;
+        self.a = result;
     }
 
     fn cmp(&mut self, value: u8) {
-        let result = self.a as u16 - value as u16;
-        self.cc.z = self.a == value;
-        self.cc.cy = self.a < value;
-        self.sign_flag(result);
-        self.parity_flag(result);
+        self.sub_with(value, false);
     }
 
-    fn ret(&mut self) {
-        self.pc = Self::extend(self.mem[self.sp + 1], self.mem[self.sp]) as usize;
-        self.sp += 2;
-
-        self.pc -= 1;
+    // INR and DCR leave CY alone
+    fn inr(&mut self, value: u8) -> u8 {
+        let result = value.wrapping_add(1);
+        self.cc.ac = value & 0x0F == 0x0F;
+        self.arith_flags(result as u16);
+        result
     }
 
-    fn jmp(&mut self) {
-        self.pc = Self::extend(self.mem[self.pc + 2], self.mem[self.pc + 1]) as usize;
-        self.pc -= 1;
+    fn dcr(&mut self, value: u8) -> u8 {
+        let result = value.wrapping_sub(1);
+        self.cc.ac = value & 0x0F != 0;
+        self.arith_flags(result as u16);
+        result
     }
 
-    fn call_jmp(&mut self) {
-        // PC of next instruction
-        let split = Self::separate((self.pc + 3) as u16);
-
-        self.mem[self.sp - 1] = split.0;
-        self.mem[self.sp - 2] = split.1;
+    fn ret(&mut self) {
+        self.pc = self.pop() as usize;
 
-        self.sp -= 2;
-        self.pc -= 1;
+        self.pc = self.pc.wrapping_sub(1);
     }
 
-    fn call(&mut self) {
-        let bytes = Self::extend(self.mem[self.pc + 2], self.mem[self.pc + 1]);
-        self.call_jmp();
-        self.pc = bytes as usize;
-        self.pc -= 1;
-    }
-
-    fn step(&mut self) -> bool {
-        match self.mem[self.pc] {
-            0x00 => {} // NOP
-            0x01 => {
-                // LXI B, word
-                self.b = self.mem[self.pc + 2];
-                self.c = self.mem[self.pc + 1];
-                self.pc += 2;
-            }
-
-            0x02 => {
-                // STAX B
-                let offset = Self::extend(self.b, self.c) as usize;
-                self.mem[offset] = self.a;
-            }
-
-            0x03 => {
-                // INX B
-                let answer = Self::extend(self.b, self.c) + 1;
-
-                Self::assign_ref((&mut self.b, &mut self.c), Self::separate(answer));
-            }
-
-            0x04 => {
-                // INR B
-                let answer = self.b as u16 + 1;
-                self.arith_flags(answer);
-                self.b = (answer & 0xff) as u8;
-            }
-
-            0x05 => {
-                // DCR B
-                let answer = self.b as u16 - 1;
-                self.arith_flags(answer);
-                self.b = (answer & 0xff) as u8;
-            }
-
-            0x06 => {
-                // MVI B, byte
-                self.b = self.mem[self.pc + 1];
-                self.pc += 1;
-            }
-
-            0x07 => {
-                // RLC
-                self.a = (self.a >> 7) | self.a << 1;
-                self.cc.cy = (self.a & 0x01) == 0x01;
-            }
-
-            0x08 => {} // NOP
-
-            0x09 => {
-                // DAD B
-                let hl = Self::extend(self.h, self.l);
-                let de = Self::extend(self.b, self.c);
-
-                let answer: u16 = hl + de;
-
-                Self::assign_ref((&mut self.h, &mut self.l), Self::separate(answer));
-
-                self.carry_flag(answer);
-            }
-
-            0x0A => {
-                // LDAX B
-                let offset = Self::extend(self.b, self.c) as usize;
-                self.a = self.mem[offset];
-            }
-
-            0x0B => {
-                // DCX B
-                let answer = Self::extend(self.b, self.c) - 1;
-
-                Self::assign_ref((&mut self.b, &mut self.c), Self::separate(answer));
-            }
-
-            0x0C => {
-                // INR C
-                let answer = self.c as u16 + 1;
-                self.arith_flags(answer);
-                self.c = (answer & 0xff) as u8;
-            }
-
-            0x0D => {
-                // DCR C
-                let answer = self.c as u16 - 1;
-                self.arith_flags(answer);
-                self.c = (answer & 0xff) as u8;
-            }
-
-            0x0E => {
-                // MVI C, byte
-                self.c = self.mem[self.pc + 1];
-                self.pc += 1;
-            }
-
-            0x0F => {
-                // RRC
-                let previous = self.a;
-                self.a = (self.a << 7) | self.a >> 1;
-                self.cc.cy = (previous & 0x01) == 0x01;
-            }
-
-            0x10 => {} // NOP
-            0x11 => {
-                // LXI D, D16
-                self.d = self.mem[self.pc + 2];
-                self.e = self.mem[self.pc + 1];
-                self.pc += 2;
-            }
-
-            0x12 => {
-                // STAX D
-                let offset = Self::extend(self.d, self.e) as usize;
-                self.mem[offset] = self.a;
-            }
-
-            0x13 => {
-                // INX D
-                let answer = Self::extend(self.d, self.e) + 1;
-
-                Self::assign_ref((&mut self.d, &mut self.e), Self::separate(answer));
-            }
-
-            0x14 => {
-                // INR D
-                let answer = self.d as u16 + 1;
-                self.arith_flags(answer);
-                self.d = (answer & 0xff) as u8;
-            }
-
-            0x15 => {
-                // DCR D
-                let answer = self.d as u16 - 1;
-                self.arith_flags(answer);
-                self.d = (answer & 0xff) as u8;
-            }
-
-            0x16 => {
-                // MVI D, byte
-                self.d = self.mem[self.pc + 1];
-                self.pc += 1;
-            }
-
-            0x17 => {
-                // RAL
-                let previous = self.a;
-                self.a = (self.a << 1) | (self.cc.cy as u8);
-                self.cc.cy = (previous & 0b10000000) == 0b10000000;
-            }
-
-            0x18 => {} // NOP
-
-            0x19 => {
-                // DAD D
-                let hl = Self::extend(self.h, self.l);
-                let de = Self::extend(self.d, self.e);
-
-                let answer: u16 = hl + de;
-
-                Self::assign_ref((&mut self.h, &mut self.l), Self::separate(answer));
-
-                self.carry_flag(answer);
-            }
-
-            0x1A => {
-                // LDAX D
-                let offset = Self::extend(self.d, self.e) as usize;
-                self.a = self.mem[offset];
-            }
-
-            0x1B => {
-                // DCX D
-                let answer = Self::extend(self.d, self.e) - 1;
-
-                Self::assign_ref((&mut self.d, &mut self.e), Self::separate(answer));
-            }
-
-            0x1C => {
-                // INR E
-                let answer = self.e as u16 + 1;
-                self.arith_flags(answer);
-                self.e = (answer & 0xff) as u8;
-            }
-
-            0x1D => {
-                // DCR E
-                let answer = self.e as u16 - 1;
-                self.arith_flags(answer);
-                self.e = (answer & 0xff) as u8;
-            }
-
-            0x1E => {
-                // MVI E, byte
-                self.e = self.mem[self.pc + 1];
-                self.pc += 1;
-            }
-
-            0x1F => {
-                // RAR
-                let previous = self.a;
-                self.a = (self.a >> 1) | ((self.cc.cy as u8) << 7);
-                self.cc.cy = (previous & 0b10000000) != 0b10000000;
-            }
-
-            0x20 => {} // NOP
-
-            0x21 => {
-                // LXI H, D16
-                self.h = self.mem[self.pc + 2];
-                self.l = self.mem[self.pc + 1];
-                self.pc += 2;
-            }
-
-            0x22 => {
-                // SHLD
-                let offset = Self::extend(self.mem[self.pc + 1], self.mem[self.pc + 2]) as usize;
-                self.mem[offset] = self.l;
-                self.mem[offset + 1] = self.h;
-
-                self.pc += 2;
-            }
-
-            0x23 => {
-                // INX H
-                let answer = Self::extend(self.h, self.l) + 1;
-
-                Self::assign_ref((&mut self.h, &mut self.l), Self::separate(answer));
-            }
-
-            0x24 => {
-                // INR H
-                let answer = self.h as u16 + 1;
-                self.arith_flags(answer);
-                self.h = (answer & 0xff) as u8;
-            }
-
-            0x25 => {
-                // DCR H
-                let answer = self.h as u16 - 1;
-                self.arith_flags(answer);
-                self.h = (answer & 0xff) as u8;
-            }
-
-            0x26 => {
-                // MVI H, byte
-                self.h = self.mem[self.pc + 1];
-                self.pc += 1;
-            }
-
-            0x28 => {} // NOP
-
-            0x29 => {
-                // DAD H
-                let hl = Self::extend(self.h, self.l);
-
-                let answer: u16 = hl + hl;
-
-                Self::assign_ref((&mut self.h, &mut self.l), Self::separate(answer));
-
-                self.carry_flag(answer);
-            }
-
-            0x2A => {
-                // LDHD bytes
-                let offset = Self::extend(self.mem[self.pc + 1], self.mem[self.pc + 2]) as usize;
-                self.h = self.mem[offset];
-                self.l = self.mem[offset + 1];
-
-                self.pc += 2;
-            }
-
-            0x2B => {
-                // DCX H
-                let answer = Self::extend(self.h, self.l) - 1;
-
-                Self::assign_ref((&mut self.h, &mut self.l), Self::separate(answer));
-            }
-
-            0x2C => {
-                // INR L
-                let answer = self.l as u16 + 1;
-                self.arith_flags(answer);
-                self.l = (answer & 0xff) as u8;
-            }
-
-            0x2D => {
-                // DCR L
-                let answer = self.l as u16 - 1;
-                self.arith_flags(answer);
-                self.l = (answer & 0xff) as u8;
-            }
-
-            0x2E => {
-                // MVI L, byte
-                self.l = self.mem[self.pc + 1];
-                self.pc += 1;
-            }
-
-            0x2F => {
-                // CMA
-                self.a = !self.a;
-            }
-
-            0x30 => {} // NOP
-
-            0x31 => {
-                // LXI SP, D16
-                self.sp = Self::extend(self.mem[self.pc + 2], self.mem[self.pc + 1]) as usize;
-                self.pc += 2;
-            }
-
-            0x32 => {
-                // STA addr
-                let offset = Self::extend(self.mem[self.pc + 2], self.mem[self.pc + 1]) as usize;
-                self.mem[offset] = self.a;
-
-                self.pc += 2;
-            }
-
-            0x33 => {
-                // INX SP
-                self.sp += 1;
-            }
-
-            0x34 => {
-                // INR M
-                let offset = Self::extend(self.h, self.l) as usize;
-                self.mem[offset] = self.mem[offset] + 1;
-                self.arith_flags(self.mem[offset].into());
-            }
-
-            0x35 => {
-                // DCR M
-                let offset = Self::extend(self.h, self.l) as usize;
-                self.mem[offset] = self.mem[offset] - 1;
-                self.arith_flags(self.mem[offset].into());
-            }
-
-            0x36 => {
-                // MVI H, byte
-                let offset = Self::extend(self.h, self.l) as usize;
-                self.mem[offset] = self.mem[self.pc + 1];
-                self.pc += 1;
-            }
-
-            0x37 => {
-                // STC
-                self.cc.cy = true;
-            }
-
-            0x38 => {} // NOP
-
-            0x39 => {
-                // DAD SP
-                let hl = Self::extend(self.h, self.l);
-
-                let answer: u16 = hl + self.sp as u16;
-
-                Self::assign_ref((&mut self.h, &mut self.l), Self::separate(answer));
-
-                self.carry_flag(answer);
-            }
-
-            0x3A => {
-                // LDA, bytes
-                let offset = Self::extend(self.mem[self.pc + 1], self.mem[self.pc + 2]) as usize;
-                self.a = self.mem[offset];
-            }
-
-            0x3B => {
-                // DCX SP
-                self.sp -= 1;
-            }
-
-            0x3C => {
-                // INR A
-                let result = self.a + 1;
-                self.arith_flags(result.into());
-                self.a = result;
-            }
-
-            0x3D => {
-                // DCR A
-                let result = self.a - 1;
-                self.arith_flags(result.into());
-                self.a = result;
-            }
-
-            0x3E => {
-                // MVI A, byte
-                self.a = self.mem[self.pc + 1];
-                self.pc += 1;
-            }
-
-            0x3F => {
-                // CMC
-                self.cc.cy = !self.cc.cy;
-            }
-
-            0x40 => {
-                self.b = self.b;
-            } // MOV B,B
-            0x41 => {
-                self.b = self.c;
-            } // MOV B,C
-            0x42 => {
-                self.b = self.d;
-            } // MOV B,D
-            0x43 => {
-                self.b = self.e;
-            } // MOV B,E
-            0x44 => {
-                self.b = self.h;
-            } // MOV B,H
-            0x45 => {
-                self.b = self.l;
-            } // MOV B,L
-            0x46 => {
-                // MOV B,M
-                let offset: usize = Self::extend(self.h, self.l) as usize;
-                self.b = self.mem[offset];
-            }
-            0x47 => {
-                self.b = self.a;
-            } // MOV B,A
-
-            0x48 => {
-                self.c = self.b;
-            } // MOV C,B
-            0x49 => {
-                self.c = self.c;
-            } // MOV C,C
-            0x4A => {
-                self.c = self.d;
-            } // MOV C,D
-            0x4B => {
-                self.c = self.e;
-            } // MOV C,E
-            0x4C => {
-                self.c = self.h;
-            } // MOV C,H
-            0x4D => {
-                self.c = self.l;
-            } // MOV C,L
-            0x4E => {
-                // MOV C,M
-                let offset: usize = Self::extend(self.h, self.l) as usize;
-                self.b = self.mem[offset];
-            }
-            0x4F => {
-                self.c = self.a;
-            } // MOV C,A
-
-            0x50 => {
-                self.d = self.b;
-            } // MOV D,B
-            0x51 => {
-                self.d = self.c;
-            } // MOV D,C
-            0x52 => {
-                self.d = self.d;
-            } // MOV D,D
-            0x53 => {
-                self.d = self.e;
-            } // MOV D,E
-            0x54 => {
-                self.d = self.h;
-            } // MOV D,H
-            0x55 => {
-                self.d = self.l;
-            } // MOV D,L
-            0x56 => {
-                // MOV D,M
-                let offset: usize = Self::extend(self.h, self.l) as usize;
-                self.d = self.mem[offset];
-            }
-            0x57 => {
-                self.d = self.a;
-            } // MOV D,A
-
-            0x58 => {
-                self.e = self.b;
-            } // MOV E,B
-            0x59 => {
-                self.e = self.c;
-            } // MOV E,C
-            0x5A => {
-                self.e = self.d;
-            } // MOV E,D
-            0x5B => {
-                self.e = self.e;
-            } // MOV E,E
-            0x5C => {
-                self.e = self.h;
-            } // MOV E,H
-            0x5D => {
-                self.e = self.l;
-            } // MOV E,L
-            0x5E => {
-                // MOV E,M
-                let offset: usize = Self::extend(self.h, self.l) as usize;
-                self.e = self.mem[offset];
-            }
-            0x5F => {
-                self.e = self.a;
-            } // MOV E,A
-
-            0x60 => {
-                self.h = self.b;
-            } // MOV H,B
-            0x61 => {
-                self.h = self.c;
-            } // MOV H,C
-            0x62 => {
-                self.h = self.d;
-            } // MOV H,D
-            0x63 => {
-                self.h = self.e;
-            } // MOV H,E
-            0x64 => {
-                self.h = self.h;
-            } // MOV H,H
-            0x65 => {
-                self.h = self.l;
-            } // MOV H,L
-            0x66 => {
-                // MOV H,M
-                let offset: usize = Self::extend(self.h, self.l) as usize;
-                self.h = self.mem[offset];
-            }
-            0x67 => {
-                self.h = self.a;
-            } // MOV H,A
-
-            0x68 => {
-                self.l = self.b;
-            } // MOV L,B
-            0x69 => {
-                self.l = self.c;
-            } // MOV L,C
-            0x6A => {
-                self.l = self.d;
-            } // MOV L,D
-            0x6B => {
-                self.l = self.e;
-            } // MOV L,E
-            0x6C => {
-                self.l = self.h;
-            } // MOV L,H
-            0x6D => {
-                self.l = self.l;
-            } // MOV L,L
-            0x6E => {
-                // MOV L,M
-                let offset: usize = Self::extend(self.h, self.l) as usize;
-                self.l = self.mem[offset];
-            }
-            0x6F => {
-                self.l = self.a;
-            } // MOV L,A
-
-            0x70 => {
-                // MOV M,B
-                let offset: usize = Self::extend(self.h, self.l) as usize;
-                self.mem[offset] = self.b;
-            }
-            0x71 => {
-                // MOV M,C
-                let offset: usize = Self::extend(self.h, self.l) as usize;
-                self.mem[offset] = self.c;
-            }
-            0x72 => {
-                // MOV M,D
-                let offset: usize = Self::extend(self.h, self.l) as usize;
-                self.mem[offset] = self.d;
-            }
-            0x73 => {
-                // MOV M,E
-                let offset: usize = Self::extend(self.h, self.l) as usize;
-                self.mem[offset] = self.e;
-            }
-            0x74 => {
-                // MOV M,H
-                let offset: usize = Self::extend(self.h, self.l) as usize;
-                self.mem[offset] = self.h;
-            }
-            0x75 => {
-                // MOV M,L
-                let offset: usize = Self::extend(self.h, self.l) as usize;
-                self.mem[offset] = self.l;
-            }
-
-            0x76 => {
-                // HLT
-                return false;
-            }
-
-            0x77 => {
-                // MOV M,A
-                let offset: usize = Self::extend(self.h, self.l) as usize;
-                self.mem[offset] = self.a;
-            }
-
-            0x78 => {
-                self.a = self.b;
-            } // MOV A,B
-            0x79 => {
-                self.a = self.c;
-            } // MOV A,C
-            0x7A => {
-                self.a = self.d;
-            } // MOV A,D
-            0x7B => {
-                self.a = self.e;
-            } // MOV A,E
-            0x7C => {
-                self.a = self.h;
-            } // MOV A,H
-            0x7D => {
-                self.a = self.l;
-            } // MOV A,L
-            0x7E => {
-                // MOV A,M
-                let offset: usize = Self::extend(self.h, self.l) as usize;
-                self.a = self.mem[offset];
-            }
-            0x7F => {
-                self.a = self.a;
-            } // MOV A,A
-
-            0x80 => {
-                self.add(self.b);
-            } // ADD B
-            0x81 => {
-                self.add(self.c);
-            } // ADD C
-            0x82 => {
-                self.add(self.d);
-            } // ADD D
-            0x83 => {
-                self.add(self.e);
-            } // ADD E
-            0x84 => {
-                self.add(self.h);
-            } // ADD H
-            0x85 => {
-                self.add(self.l);
-            } // ADD L
-            0x86 => {
-                // ADD M
-                let offset = Self::extend(self.h, self.l) as usize;
-                self.add(self.mem[offset]);
-            }
-            0x87 => {
-                self.add(self.a);
-            } // ADD A
-
-            0x88 => {
-                self.add_cy(self.b);
-            } // ADC B
-            0x89 => {
-                self.add_cy(self.c);
-            } // ADC C
-            0x8A => {
-                self.add_cy(self.d);
-            } // ADC D
-            0x8B => {
-                self.add_cy(self.e);
-            } // ADC E
-            0x8C => {
-                self.add_cy(self.h);
-            } // ADC H
-            0x8D => {
-                self.add_cy(self.l);
-            } // ADC L
-            0x8E => {
-                // ADC M
-                let offset = Self::extend(self.h, self.l) as usize;
-                self.add_cy(self.mem[offset]);
-            }
-            0x8F => {
-                self.add_cy(self.a);
-            } // ADC A
-
-            0x90 => {
-                self.sub(self.b);
-            } // SUB B
-            0x91 => {
-                self.sub(self.c);
-            } // SUB C
-            0x92 => {
-                self.sub(self.d);
-            } // SUB D
-            0x93 => {
-                self.sub(self.e);
-            } // SUB E
-            0x94 => {
-                self.sub(self.h);
-            } // SUB H
-            0x95 => {
-                self.sub(self.l);
-            } // SUB L
-            0x96 => {
-                // SUB M
-                let offset = Self::extend(self.h, self.l) as usize;
-                self.sub(self.mem[offset]);
-            }
-            0x97 => {
-                self.sub(self.a);
-            } // SUB A
-
-            0x98 => {
-                self.sub_cy(self.b);
-            } // SBB B
-            0x99 => {
-                self.sub_cy(self.a);
-            } // SBB C
-            0x9A => {
-                self.sub_cy(self.d);
-            } // SBB D
-            0x9B => {
-                self.sub_cy(self.e);
-            } // SBB E
-            0x9C => {
-                self.sub_cy(self.h);
-            } // SBB H
-            0x9D => {
-                self.sub_cy(self.l);
-            } // SBB L
-            0x9E => {
-                // SBB M
-                let offset = Self::extend(self.h, self.l) as usize;
-                self.sub_cy(self.mem[offset]);
-            }
-            0x9F => {
-                self.sub_cy(self.a);
-            } // SBB A
-
-            0xA0 => {
-                self.and(self.b);
-            } // ANA B
-            0xA1 => {
-                self.and(self.c);
-            } // ANA C
-            0xA2 => {
-                self.and(self.d);
-            } // ANA D
-            0xA3 => {
-                self.and(self.e);
-            } // ANA E
-            0xA4 => {
-                self.and(self.h);
-            } // ANA H
-            0xA5 => {
-                self.and(self.l);
-            } // ANA L
-            0xA6 => {
-                // ANA M
-                let offset = Self::extend(self.h, self.l) as usize;
-                self.and(self.mem[offset]);
-            }
-            0xA7 => {
-                self.and(self.a);
-            } // ANA A
-
-            0xA8 => {
-                self.xor(self.b);
-            } // XRA B
-            0xA9 => {
-                self.xor(self.a);
-            } // XRA C
-            0xAA => {
-                self.xor(self.d);
-            } // XRA D
-            0xAB => {
-                self.xor(self.e);
-            } // XRA E
-            0xAC => {
-                self.xor(self.h);
-            } // XRA H
-            0xAD => {
-                self.xor(self.l);
-            } // XRA L
-            0xAE => {
-                // XRA M
-                let offset = Self::extend(self.h, self.l) as usize;
-                self.xor(self.mem[offset]);
-            }
-            0xAF => {
-                self.xor(self.a);
-            } // XRA A
-
-            0xB0 => {
-                self.or(self.b);
-            } // ORA B
-            0xB1 => {
-                self.or(self.c);
-            } // ORA C
-            0xB2 => {
-                self.or(self.d);
-            } // ORA D
-            0xB3 => {
-                self.or(self.e);
-            } // ORA E
-            0xB4 => {
-                self.or(self.h);
-            } // ORA H
-            0xB5 => {
-                self.or(self.l);
-            } // ORA L
-            0xB6 => {
-                // ORA M
-                let offset = Self::extend(self.h, self.l) as usize;
-                self.or(self.mem[offset]);
-            }
-            0xB7 => {
-                self.or(self.a);
-            } // ORA A
-
-            0xB8 => {
-                self.cmp(self.b);
-            } // CMP B
-            0xB9 => {
-                self.cmp(self.a);
-            } // CMP C
-            0xBA => {
-                self.cmp(self.d);
-            } // CMP D
-            0xBB => {
-                self.cmp(self.e);
-            } // CMP E
-            0xBC => {
-                self.cmp(self.h);
-            } // CMP H
-            0xBD => {
-                self.cmp(self.l);
-            } // CMP L
-            0xBE => {
-                // CMP M
-                let offset = Self::extend(self.h, self.l) as usize;
-                self.cmp(self.mem[offset]);
-            }
-            0xBF => {
-                self.cmp(self.a);
-            } // CMP A
-
-            0xC0 => {
-                // RNZ
-                if !self.cc.z {
-                    self.ret();
-                }
-            }
-
-            0xC1 => {
-                // POP B
-                self.c = self.mem[self.sp];
-                self.b = self.mem[self.sp + 1];
-                self.sp += 2;
-            }
-
-            0xC2 => {
-                // JNZ bytes
-                if !self.cc.z {
-                    self.jmp();
-                } else {
-                    self.pc += 2;
-                }
-            }
-
-            0xC3 => {
-                // JMP bytes
-                self.jmp();
-            }
-
-            0xC4 => {
-                // CNZ bytes
-                if !self.cc.z {
-                    self.call();
-                } else {
-                    self.pc += 2;
-                }
-            }
-
-            0xC5 => {
-                // PUSH B
-                self.mem[self.sp - 1] = self.b;
-                self.mem[self.sp - 2] = self.c;
-                self.sp -= 2;
-            }
-
-            0xC6 => {
-                // ADI byte
-                self.add(self.mem[self.pc + 1]);
-                self.pc += 1;
-            }
-
-            0xC7 => {
-                // RST 0
-                self.call_jmp();
-                self.pc = 0;
-            }
-
-            0xC8 => {
-                // RZ
-                if self.cc.z {
-                    self.call();
-                } else {
-                    self.pc += 2;
-                }
-            }
-
-            0xC9 => {
-                // RET
-                self.ret();
-            }
-
-            0xCA => {
-                // JZ bytes
-                if self.cc.z {
-                    self.jmp();
-                } else {
-                    self.pc += 2;
-                }
-            }
-
-            0xCB => {
-                // JMP bytes
-                self.jmp();
-            }
-
-            0xCC => {
-                // CZ bytes
-                if self.cc.z {
-                    self.call();
-                } else {
-                    self.pc += 2;
-                }
-            }
-
-            0xCD => {
-                // CALL bytes
-                self.call();
-            }
-
-            0xCE => {
-                // ACI byte
-                self.add_cy(self.mem[self.pc + 1]);
-                self.pc += 1;
-            }
-
-            0xCF => {
-                // RST 1
-                self.call_jmp();
-                self.pc = 8;
-            }
-
-            0xD0 => {
-                // RNC
-                if !self.cc.cy {
-                    self.ret();
-                }
-            }
-
-            0xD1 => {
-                // POP D
-                self.e = self.mem[self.sp];
-                self.d = self.mem[self.sp + 1];
-                self.sp += 2;
-            }
-
-            0xD2 => {
-                // JNC bytes
-                if !self.cc.cy {
-                    self.jmp();
-                } else {
-                    self.pc += 2;
-                }
-            }
-
-            0xD3 => {
-                // OUT byte
-                self.pc += 1;
-            }
-
-            0xD4 => {
-                // CNC bytes
-                if !self.cc.cy {
-                    self.call();
-                } else {
-                    self.pc += 2;
-                }
-            }
-
-            0xD5 => {
-                // PUSH D
-                self.mem[self.sp - 1] = self.d;
-                self.mem[self.sp - 2] = self.e;
-                self.sp -= 2;
-            }
-
-            0xD6 => {
-                // SUI byte
-                self.sub(self.mem[self.pc + 1]);
-                self.pc += 1;
-            }
-
-            0xD7 => {
-                // RST 2
-                self.call_jmp();
-                self.pc = 16;
-            }
-
-            0xD8 => {
-                // RC
-                if self.cc.cy {
-                    self.ret();
-                }
-            }
+    fn jmp(&mut self) {
+        self.pc = Self::extend(self.fetch(2), self.fetch(1)) as usize;
+        self.pc = self.pc.wrapping_sub(1);
+    }
 
-            0xD9 => {
-                // RET
-                self.ret();
+    fn jmp_if(&mut self, condition: bool) {
+        if condition {
+            if self.model == Model::I8085 {
+                self.cycles += 3;
             }
+            self.jmp();
+        } else {
+            self.pc += 2;
+        }
+    }
 
-            0xDA => {
-                // JC bytes
-                if self.cc.cy {
-                    self.jmp();
-                } else {
-                    self.pc += 2;
-                }
-            }
+    fn call(&mut self) {
+        let bytes = Self::extend(self.fetch(2), self.fetch(1));
+        // PC of next instruction
+        self.push((self.pc + 3) as u16);
+        self.pc = (bytes as usize).wrapping_sub(1);
+    }
 
-            0xDB => {
-                // In byte
-                self.pc += 1;
-            }
+    fn rst(&mut self, vector: usize) {
+        self.push((self.pc + 1) as u16);
+        self.pc = (vector * 8).wrapping_sub(1);
+    }
 
-            0xDC => {
-                // CC bytes
-                if self.cc.cy {
-                    self.call();
-                } else {
-                    self.pc += 2;
-                }
-            }
+    fn daa(&mut self) {
+        let mut correction = 0;
+        let mut carry = self.cc.cy;
+        let low = self.a & 0x0F;
 
-            0xDD => {
-                // CALL bytes
-                self.call();
-            }
+        if low > 9 || self.cc.ac {
+            correction |= 0x06;
+        }
+        if self.a > 0x99 || carry {
+            correction |= 0x60;
+            carry = true;
+        }
 
-            0xDE => {
-                // SBI byte
-                self.sub_cy(self.mem[self.pc + 1]);
-                self.pc += 1;
-            }
+        let result = self.a.wrapping_add(correction);
+        self.cc.ac = low + (correction & 0x0F) > 0x0F;
+        self.cc.cy = carry;
+        self.arith_flags(result as u16);
+        self.a = result;
+    }
 
-            0xDF => {
-                // RST 3
-                self.call_jmp();
-                self.pc = 24;
-            }
+    fn ret_if(&mut self, condition: bool) {
+        if condition {
+            self.cycles += 6;
+            self.ret();
+        }
+    }
 
-            0xE0 => {
-                // RPO
-                if !self.cc.p {
-                    self.ret();
-                }
-            }
+    fn call_if(&mut self, condition: bool) {
+        if condition {
+            self.cycles += match self.model {
+                Model::I8080 => 6,
+                Model::I8085 => 9,
+            };
+            self.call();
+        } else {
+            self.pc += 2;
+        }
+    }
 
-            0xE1 => {
-                // POP H
-                self.l = self.mem[self.sp];
-                self.h = self.mem[self.sp + 1];
-                self.sp += 2;
-            }
+    // Word accesses wrap at the top of the 16-bit address space
+    fn read_word(&self, address: usize) -> u16 {
+        Self::extend(self.mem[(address + 1) & 0xFFFF], self.mem[address & 0xFFFF])
+    }
 
-            0xE2 => {
-                // JPO bytes
-                if !self.cc.p {
-                    self.jmp();
-                } else {
-                    self.pc += 2;
-                }
-            }
+    fn write_word(&mut self, address: usize, value: u16) {
+        let (high, low) = Self::separate(value);
+        self.write_byte(address & 0xFFFF, low);
+        self.write_byte((address + 1) & 0xFFFF, high);
+    }
 
-            0xE3 => {
-                // XTHL
-                std::mem::swap(&mut self.l, &mut self.mem[self.sp]);
-                std::mem::swap(&mut self.h, &mut self.mem[self.sp + 1]);
-            }
+    fn push(&mut self, value: u16) {
+        self.sp = self.sp.wrapping_sub(2) & 0xFFFF;
+        self.write_word(self.sp, value);
+    }
 
-            0xE4 => {
-                // CPO bytes
-                if !self.cc.p {
-                    self.call();
-                } else {
-                    self.pc += 2;
-                }
-            }
+    fn pop(&mut self) -> u16 {
+        let value = self.read_word(self.sp);
+        self.sp = (self.sp + 2) & 0xFFFF;
+        value
+    }
 
-            0xE5 => {
-                // PUSH H
-                self.mem[self.sp - 1] = self.h;
-                self.mem[self.sp - 2] = self.l;
-                self.sp -= 2;
-            }
+    // Adds `value` to HL, with CY set by a carry out of bit 15
+    fn dad(&mut self, value: u16) {
+        let (answer, carry) = Self::extend(self.h, self.l).overflowing_add(value);
+        Self::assign_ref((&mut self.h, &mut self.l), Self::separate(answer));
+        self.cc.cy = carry;
+    }
 
-            0xE6 => {
-                // ANI byte
-                let answer = self.a & self.mem[self.pc + 1];
-                self.a = answer;
-                self.arith_flags(answer as u16);
-                self.cc.cy = false;
-            }
+    /// Requests an interrupt that executes `RST vector`. Ignored while interrupts are
+    /// disabled; accepting one disables further interrupts until the next `EI`.
+    pub fn interrupt(&mut self, vector: u8) {
+        self.interrupt_with(&[0xC7 | (vector & 0x07) << 3]);
+    }
 
-            0xE7 => {
-                // RST 4
-                self.call_jmp();
-                self.pc = 32;
-            }
+    /// Requests an interrupt that executes `instruction`, as placed on the data bus by
+    /// the interrupting device during INTA. This is usually an RST, but can be any
+    /// instruction, such as the 3-byte CALL supplied by an 8259.
+    pub fn interrupt_with(&mut self, instruction: &[u8]) {
+        assert!(
+            (1..=3).contains(&instruction.len()),
+            "An 8080 instruction is 1 to 3 bytes"
+        );
+        if !self.int_enable {
+            return;
+        }
 
-            0xE8 => {
-                // RPE
-                if self.cc.p {
-                    self.ret();
-                }
-            }
+        self.int_enable = false;
+        self.halted = false;
+
+        let mut bus = [0; 3];
+        bus[..instruction.len()].copy_from_slice(instruction);
+        self.bus = Some(bus);
+        // PC doesn't advance over an instruction from the bus, so start it that far
+        // back to leave it on the interrupted instruction
+        self.pc = self.pc.wrapping_sub(instruction.len()) & 0xFFFF;
+        self.step_io(&mut Unconnected);
+        self.bus = None;
+    }
 
-            0xE9 => {
-                // PCHL
-                self.pc = Self::extend(self.h, self.l) as usize;
-            }
+    // Byte `offset` of the current instruction, from memory or from the data bus
+    // during an interrupt acknowledge
+    fn fetch(&self, offset: usize) -> u8 {
+        match self.bus {
+            Some(bus) => bus[offset],
+            None => self.mem[(self.pc + offset) & 0xFFFF],
+        }
+    }
 
-            0xEA => {
-                // JPE bytes
-                if self.cc.p {
-                    self.jmp();
-                } else {
-                    self.pc += 2;
-                }
-            }
+    pub fn cycles(&self) -> u64 {
+        self.cycles
+    }
 
-            0xEB => {
-                // XCHG
-                std::mem::swap(&mut self.h, &mut self.d);
-                std::mem::swap(&mut self.l, &mut self.e);
-            }
+    pub fn status(&self) -> Status {
+        if !self.halted {
+            Status::Running
+        } else if self.int_enable {
+            Status::Halted
+        } else {
+            Status::Deadlock
+        }
+    }
 
-            0xEC => {
-                // CPE bytes
-                if self.cc.p {
-                    self.call();
-                } else {
-                    self.pc += 2;
-                }
-            }
+    pub fn step(&mut self) -> Status {
+        self.step_io(&mut Unconnected)
+    }
 
-            0xED => {
-                // CALL bytes
-                self.call()
-            }
+    /// Executes one instruction, or idles for a few cycles while halted.
+    pub fn step_io(&mut self, io: &mut dyn Io) -> Status {
+        if self.model == Model::I8085 && self.bus.is_none() {
+            self.service_8085();
+        }
+        if self.halted {
+            self.cycles += HALT_CYCLES;
+            return self.status();
+        }
 
-            0xEE => {
-                // XRI byte
-                let answer = self.a ^ self.mem[self.pc + 1];
-                self.a = answer;
-                self.arith_flags(answer as u16);
-                self.cc.cy = false;
-            }
+        let op = &OPCODES[self.fetch(0) as usize];
+        self.cycles += match self.model {
+            Model::I8080 => op.cycles,
+            Model::I8085 => op.cycles_8085,
+        } as u64;
+        (op.execute)(self, io);
 
-            0xEF => {
-                // RST 5
-                self.call_jmp();
-                self.pc = 40;
-            }
+        self.pc = self.pc.wrapping_add(1) & 0xFFFF;
+        self.status()
+    }
+}
 
-            0xF0 => {
-                // RP
-                if self.cc.p {
-                    self.ret();
-                }
-            }
+// Tag and length of an 8080's saved state
+const SAVED_TAG: &[u8] = b"8080";
+const SAVED_LEN: usize = 30;
 
-            0xF1 => {
-                // POP PSW
-                self.cc.cy = (self.mem[self.sp] & 0b00000001) == 0b00000001;
-                self.cc.p = (self.mem[self.sp] & 0b00000100) == 0b00000100;
-                self.cc.z = (self.mem[self.sp] & 0b01000000) == 0b01000000;
-                self.cc.s = (self.mem[self.sp] & 0b10000000) == 0b10000000;
-                self.a = self.mem[self.sp + 1];
-                self.sp += 2;
-            }
+impl Cpu for State<'_> {
+    fn step_io(&mut self, io: &mut dyn Io) -> Status {
+        State::step_io(self, io)
+    }
 
-            0xF2 => {
-                // JP bytes
-                if !self.cc.s {
-                    self.jmp();
-                } else {
-                    self.pc += 2;
-                }
-            }
+    fn reset(&mut self) {
+        State::reset(self)
+    }
 
-            0xF3 => {
-                self.int_enable = false;
-            }
+    fn interrupt(&mut self, vector: u8) {
+        State::interrupt(self, vector)
+    }
 
-            0xF4 => {
-                // CP btyes
-                if !self.cc.s {
-                    self.call();
-                }
-            }
+    fn interrupt_with(&mut self, data: &[u8]) {
+        State::interrupt_with(self, data)
+    }
 
-            0xF5 => {
-                // PUSH PSW
-                self.mem[self.sp - 1] = self.a;
-                let psw = self.cc.cy as u8
-                    | 0b10
-                    | (self.cc.p as u8) << 2
-                    | (self.cc.z as u8) << 6
-                    | (self.cc.s as u8) << 7;
-                self.mem[self.sp - 2] = psw;
-                self.sp -= 2;
-            }
+    fn cycles(&self) -> u64 {
+        self.cycles
+    }
 
-            0xF6 => {
-                // ORI byte
-                let answer = self.a | self.mem[self.pc + 1];
-                self.a = answer;
-                self.arith_flags(answer as u16);
-                self.cc.cy = false;
-            }
+    fn status(&self) -> Status {
+        State::status(self)
+    }
 
-            0xF7 => {
-                // RST 6
-                self.call_jmp();
-                self.pc = 48;
-            }
+    fn registers(&self) -> Registers {
+        Registers {
+            a: self.a,
+            flags: self.psw() as u8,
+            bc: self.bc(),
+            de: self.de(),
+            hl: self.hl(),
+            sp: self.sp as u16,
+            pc: self.pc as u16,
+        }
+    }
 
-            0xF8 => {
-                // RM
-                if self.cc.s {
-                    self.ret();
-                }
-            }
+    fn set_registers(&mut self, registers: Registers) {
+        self.set_psw(Self::extend(registers.a, registers.flags));
+        self.set_bc(registers.bc);
+        self.set_de(registers.de);
+        self.set_hl(registers.hl);
+        self.sp = registers.sp as usize;
+        self.pc = registers.pc as usize;
+    }
 
-            0xF9 => {
-                // SPHL
-                self.sp = Self::extend(self.h, self.l) as usize;
-            }
+    fn memory(&self) -> &[u8] {
+        self.mem
+    }
 
-            0xFA => {
-                // JM bytes
-                if self.cc.s {
-                    self.jmp();
-                } else {
-                    self.pc += 2;
-                }
-            }
+    fn memory_mut(&mut self) -> &mut [u8] {
+        self.mem
+    }
 
-            0xFB => {
-                self.int_enable = true;
-            }
+    fn protect(&mut self, range: Range<usize>) {
+        State::protect(self, range)
+    }
 
-            0xFC => {
-                // CM
-                if self.cc.s {
-                    self.call();
-                } else {
-                    self.pc += 2;
-                }
-            }
+    fn save_state(&self) -> Vec<u8> {
+        let mut flags = self.cc.to_byte() & !(Self::OVERFLOW | Self::K);
+        flags |= if self.overflow { Self::OVERFLOW } else { 0 };
+        flags |= if self.k { Self::K } else { 0 };
+        let pins = &self.pins;
+        let pin_bits = [
+            pins.rst7_5,
+            pins.rst6_5,
+            pins.rst5_5,
+            pins.trap,
+            pins.sid,
+            pins.sod,
+            pins.undocumented,
+        ]
+        .iter()
+        .enumerate()
+        .fold(0, |bits, (bit, set)| bits | (*set as u8) << bit);
+
+        let mut state = SAVED_TAG.to_vec();
+        state.push(self.model as u8);
+        state.extend_from_slice(&[
+            self.a, flags, self.b, self.c, self.d, self.e, self.h, self.l,
+        ]);
+        state.extend_from_slice(&(self.sp as u16).to_le_bytes());
+        state.extend_from_slice(&(self.pc as u16).to_le_bytes());
+        state.extend_from_slice(&[self.int_enable as u8, self.halted as u8]);
+        state.extend_from_slice(&self.cycles.to_le_bytes());
+        state.extend_from_slice(&[pins.masks, pin_bits]);
+        state.push(match pins.trap_enable {
+            None => 0,
+            Some(false) => 1,
+            Some(true) => 2,
+        });
+        state
+    }
 
-            0xFD => {
-                // CALL bytes
-                self.call();
-            }
+    fn load_state(&mut self, state: &[u8]) -> Result<(), Box<dyn error::Error>> {
+        let mut state = StateReader::new(state, SAVED_TAG, SAVED_LEN)?;
+
+        self.model = match state.byte() {
+            0 => Model::I8080,
+            _ => Model::I8085,
+        };
+        let a = state.byte();
+        self.set_psw(Self::extend(a, state.byte()));
+        for register in [
+            &mut self.b,
+            &mut self.c,
+            &mut self.d,
+            &mut self.e,
+            &mut self.h,
+            &mut self.l,
+        ] {
+            *register = state.byte();
+        }
+        self.sp = state.word() as usize;
+        self.pc = state.word() as usize;
+        self.int_enable = state.byte() != 0;
+        self.halted = state.byte() != 0;
+        self.cycles = state.long();
+
+        self.pins.masks = state.byte();
+        let pin_bits = state.byte();
+        let pins = &mut self.pins;
+        for (bit, pin) in (0..).zip([
+            &mut pins.rst7_5,
+            &mut pins.rst6_5,
+            &mut pins.rst5_5,
+            &mut pins.trap,
+            &mut pins.sid,
+            &mut pins.sod,
+            &mut pins.undocumented,
+        ]) {
+            *pin = pin_bits & 1 << bit != 0;
+        }
+        pins.trap_enable = match state.byte() {
+            0 => None,
+            enabled => Some(enabled == 2),
+        };
+        self.bus = None;
+        Ok(())
+    }
+}
 
-            0xFE => {
-                // CPI byte
-                self.cmp(self.mem[self.pc + 1]);
-                self.pc += 1;
-            }
+#[cfg(test)]
+mod opcode_tests;
 
-            0xFF => {
-                // RST 7
-                self.call_jmp();
-                self.pc = 56;
-            }
+#[cfg(test)]
+mod alu_tests;
 
-            op => {
-                let mut friendly_name = String::new();
-                disasm::disasm_single(&mut friendly_name, self.mem, self.pc)
-                    .expect("Faild to write to string");
-                friendly_name.pop();
-                panic!(
-                    "Opcode {:x} (aka. `{}`) not implemented!",
-                    op, friendly_name
-                );
-            }
-        }
+#[cfg(test)]
+mod pair_tests;
 
-        self.pc += 1;
-        true
-    }
-}
+#[cfg(test)]
+mod i8085_tests;
 
 #[cfg(test)]
 mod tests {
@@ -1539,7 +942,7 @@ mod tests {
 
         emu.zero_flag(0x00);
 
-        assert_eq!(emu.cc.z, true);
+        assert!(emu.cc.z);
     }
 
     #[test]
@@ -1548,7 +951,7 @@ mod tests {
 
         emu.zero_flag(0xFF);
 
-        assert_eq!(emu.cc.z, false);
+        assert!(!emu.cc.z);
     }
 
     #[test]
@@ -1557,7 +960,7 @@ mod tests {
 
         emu.sign_flag(0b01101111);
 
-        assert_eq!(emu.cc.s, false);
+        assert!(!emu.cc.s);
     }
 
     #[test]
@@ -1566,7 +969,7 @@ mod tests {
 
         emu.sign_flag(0b11101011);
 
-        assert_eq!(emu.cc.s, true);
+        assert!(emu.cc.s);
     }
 
     #[test]
@@ -1575,7 +978,7 @@ mod tests {
 
         emu.carry_flag(0x0001);
 
-        assert_eq!(emu.cc.cy, false);
+        assert!(!emu.cc.cy);
     }
 
     #[test]
@@ -1584,37 +987,37 @@ mod tests {
 
         emu.carry_flag(0xFF01);
 
-        assert_eq!(emu.cc.cy, true);
+        assert!(emu.cc.cy);
     }
 
     #[test]
     fn parity_true1() {
-        assert_eq!(State::parity(0b1111111111111111), true);
+        assert!(State::parity(0b1111111111111111));
     }
 
     #[test]
     fn parity_true2() {
-        assert_eq!(State::parity(0b1111111111110000), true);
+        assert!(State::parity(0b1111111111110000));
     }
 
     #[test]
     fn parity_true3() {
-        assert_eq!(State::parity(0b1010101010100000), true);
+        assert!(State::parity(0b1010101010100000));
     }
 
     #[test]
     fn parity_false1() {
-        assert_eq!(State::parity(0b1111101111111111), false);
+        assert!(!State::parity(0b1111101111111111));
     }
 
     #[test]
     fn parity_false2() {
-        assert_eq!(State::parity(0b1111101111110000), false);
+        assert!(!State::parity(0b1111101111110000));
     }
 
     #[test]
     fn parity_false3() {
-        assert_eq!(State::parity(0b1010100010100000), false);
+        assert!(!State::parity(0b1010100010100000));
     }
 
     #[test]
@@ -1645,7 +1048,7 @@ mod tests {
 
         emu.start();
         assert_eq!(0b00000001, emu.a);
-        assert_eq!(true, emu.cc.cy);
+        assert!(emu.cc.cy);
     }
 
     #[test]
@@ -1656,7 +1059,7 @@ mod tests {
 
         emu.start();
         assert_eq!(0b10000000, emu.a);
-        assert_eq!(false, emu.cc.cy);
+        assert!(!emu.cc.cy);
     }
 
     #[test]
@@ -1667,7 +1070,7 @@ mod tests {
 
         emu.start();
         assert_eq!(0b01000000, emu.a);
-        assert_eq!(false, emu.cc.cy);
+        assert!(!emu.cc.cy);
     }
 
     #[test]
@@ -1678,7 +1081,7 @@ mod tests {
 
         emu.start();
         assert_eq!(0b10000000, emu.a);
-        assert_eq!(true, emu.cc.cy);
+        assert!(emu.cc.cy);
     }
 
     #[test]
@@ -1690,7 +1093,7 @@ mod tests {
 
         emu.start();
         assert_eq!(0b00000000, emu.a);
-        assert_eq!(true, emu.cc.cy);
+        assert!(emu.cc.cy);
     }
 
     #[test]
@@ -1702,7 +1105,7 @@ mod tests {
 
         emu.start();
         assert_eq!(0b00000011, emu.a);
-        assert_eq!(false, emu.cc.cy);
+        assert!(!emu.cc.cy);
     }
 
     #[test]
@@ -1714,7 +1117,7 @@ mod tests {
 
         emu.start();
         assert_eq!(0b01000000, emu.a);
-        assert_eq!(false, emu.cc.cy);
+        assert!(!emu.cc.cy);
     }
 
     #[test]
@@ -1726,6 +1129,139 @@ mod tests {
 
         emu.start();
         assert_eq!(0b10000000, emu.a);
-        assert_eq!(true, emu.cc.cy);
+        assert!(emu.cc.cy);
+    }
+
+    #[test]
+    fn psw_layout() {
+        let mut emu = State::new(&mut []);
+        emu.set_psw(0xA5FF);
+
+        assert_eq!(emu.a(), 0xA5);
+        assert_eq!(
+            emu.flags(),
+            ConditionCodes {
+                z: true,
+                s: true,
+                p: true,
+                cy: true,
+                ac: true,
+            }
+        );
+        // Bits 3 and 5 read as clear and bit 1 as set
+        assert_eq!(emu.psw(), 0xA5D7);
+
+        emu.set_flags(ConditionCodes::default());
+        assert_eq!(emu.psw(), 0xA502);
+    }
+
+    #[test]
+    fn halt_waits_for_interrupt() {
+        // EI, HLT, then INR A after the interrupt returns
+        let mut mem = vec![0; 0x100];
+        mem[..3].copy_from_slice(&[0xFB, 0x76, 0x3C]);
+        mem[0x08] = 0xC9;
+        let mut emu = State::new(&mut mem);
+        emu.set_sp(0x80);
+
+        assert_eq!(emu.start(), Status::Halted);
+        let cycles = emu.cycles();
+        assert_eq!(emu.step(), Status::Halted);
+        assert_eq!(emu.pc(), 0x0002);
+        assert!(emu.cycles() > cycles);
+
+        emu.interrupt(1);
+        assert_eq!(emu.status(), Status::Running);
+        assert_eq!(emu.pc(), 0x0008);
+
+        emu.step();
+        emu.step();
+        assert_eq!(emu.a(), 0x01);
+    }
+
+    #[test]
+    fn halt_with_interrupts_disabled_deadlocks() {
+        let mut mem = [0xF3, 0x76];
+        let mut emu = State::new(&mut mem);
+
+        assert_eq!(emu.start(), Status::Deadlock);
+
+        emu.interrupt(1);
+        assert_eq!(emu.step(), Status::Deadlock);
+    }
+
+    #[test]
+    fn interrupt_with_call() {
+        let mut mem = vec![0; 0x10000];
+        let mut emu = State::new(&mut mem);
+        emu.set_pc(0x1234);
+        emu.set_sp(0x8000);
+
+        emu.interrupt_with(&[0xCD, 0x00, 0x40]);
+
+        assert_eq!(emu.pc(), 0x4000);
+        assert_eq!(emu.sp(), 0x7FFE);
+        assert_eq!(&emu.memory()[0x7FFE..0x8000], &[0x34, 0x12]);
+        assert_eq!(emu.cycles(), 17);
+    }
+
+    #[test]
+    fn interrupt_with_plain_instruction() {
+        // An INR A on the bus runs without moving PC, even from address 0
+        let mut mem = vec![0; 0x10000];
+        let mut emu = State::new(&mut mem);
+
+        emu.interrupt_with(&[0x3C]);
+
+        assert_eq!(emu.pc(), 0x0000);
+        assert_eq!(emu.a(), 0x01);
+
+        // Interrupts are now disabled
+        emu.interrupt_with(&[0x3C]);
+        assert_eq!(emu.a(), 0x01);
+    }
+
+    #[test]
+    fn xchg_cycles() {
+        let mut mem = vec![0xEB; 0x10];
+        let mut emu = State::new(&mut mem);
+
+        emu.step();
+        assert_eq!(emu.cycles(), 4);
+
+        emu.set_model(Model::I8085);
+        emu.step();
+        assert_eq!(emu.cycles(), 8);
+    }
+
+    #[test]
+    fn interrupt_rst() {
+        let mut mem = vec![0; 0x10000];
+        let mut emu = State::new(&mut mem);
+        emu.set_pc(0x0100);
+        emu.set_sp(0x8000);
+
+        emu.interrupt(2);
+
+        assert_eq!(emu.pc(), 0x0010);
+        assert_eq!(&emu.memory()[0x7FFE..0x8000], &[0x00, 0x01]);
+        assert_eq!(emu.cycles(), 11);
+    }
+
+    #[test]
+    fn reset_jumps_to_zero_with_interrupts_off() {
+        let mut mem = vec![0; 0x10000];
+        // HLT
+        mem[0x0100] = 0x76;
+        let mut emu = State::new(&mut mem);
+        emu.set_pc(0x0100);
+        emu.step();
+
+        emu.reset();
+
+        assert_eq!(emu.pc(), 0);
+        assert_eq!(emu.status(), Status::Running);
+        emu.interrupt(1);
+        assert_eq!(emu.pc(), 0);
     }
 }
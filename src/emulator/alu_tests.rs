@@ -0,0 +1,291 @@
+// Exhaustive checks of the 8-bit ALU instructions against a straightforward model
+// written with signed arithmetic.
+
+use super::*;
+
+#[derive(Clone, Copy, Debug)]
+enum Op {
+    Add,
+    Adc,
+    Sub,
+    Sbb,
+    Ana,
+    Xra,
+    Ora,
+    Cmp,
+}
+
+#[derive(Debug, PartialEq)]
+struct Flags {
+    z: bool,
+    s: bool,
+    p: bool,
+    cy: bool,
+    ac: bool,
+}
+
+impl Flags {
+    fn of(result: i32, cy: bool, ac: bool) -> Flags {
+        let result = result as u8;
+        Flags {
+            z: result == 0,
+            s: result & 0x80 != 0,
+            p: result.count_ones().is_multiple_of(2),
+            cy,
+            ac,
+        }
+    }
+
+    fn read(state: &State) -> Flags {
+        Flags {
+            z: state.cc.z,
+            s: state.cc.s,
+            p: state.cc.p,
+            cy: state.cc.cy,
+            ac: state.cc.ac,
+        }
+    }
+}
+
+// Returns the new accumulator and flags
+fn reference(op: Op, a: u8, value: u8, carry: bool) -> (u8, Flags) {
+    let (a, value, carry) = (a as i32, value as i32, carry as i32);
+
+    let (result, cy, ac) = match op {
+        Op::Add | Op::Adc => {
+            let c = if let Op::Adc = op { carry } else { 0 };
+            let result = a + value + c;
+            (
+                result,
+                result > 0xFF,
+                (a & 0x0F) + (value & 0x0F) + c > 0x0F,
+            )
+        }
+        Op::Sub | Op::Sbb | Op::Cmp => {
+            let b = if let Op::Sbb = op { carry } else { 0 };
+            let result = a - value - b;
+            // AC is set when the low nibble needs no borrow
+            (result, result < 0, (a & 0x0F) - (value & 0x0F) - b >= 0)
+        }
+        Op::Ana => (a & value, false, (a | value) & 0x08 != 0),
+        Op::Xra => (a ^ value, false, false),
+        Op::Ora => (a | value, false, false),
+    };
+
+    let flags = Flags::of(result, cy, ac);
+    match op {
+        Op::Cmp => (a as u8, flags),
+        _ => (result as u8, flags),
+    }
+}
+
+// Runs `opcode` for every A, operand and carry in, with the operand in B and in
+// the immediate byte.
+fn check(opcode: u8, op: Op) {
+    let mut mem = vec![0; 0x10];
+    let mut state = State::new(&mut mem);
+    state.mem[0] = opcode;
+
+    for a in 0..=255 {
+        for value in 0..=255 {
+            for carry in [false, true].iter().copied() {
+                state.pc = 0;
+                state.a = a;
+                state.b = value;
+                state.mem[1] = value;
+                // Start every flag opposite to the carry so stale flags show up
+                state.cc.z = !carry;
+                state.cc.s = !carry;
+                state.cc.p = !carry;
+                state.cc.ac = !carry;
+                state.cc.cy = carry;
+
+                state.step();
+
+                let (result, flags) = reference(op, a, value, carry);
+                assert_eq!(
+                    (state.a, Flags::read(&state)),
+                    (result, flags),
+                    "{:?} (opcode {:02X}) with A={:02X}, value={:02X}, carry={}",
+                    op,
+                    opcode,
+                    a,
+                    value,
+                    carry
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn add() {
+    check(0x80, Op::Add);
+    check(0xC6, Op::Add);
+}
+
+#[test]
+fn adc() {
+    check(0x88, Op::Adc);
+    check(0xCE, Op::Adc);
+}
+
+#[test]
+fn sub() {
+    check(0x90, Op::Sub);
+    check(0xD6, Op::Sub);
+}
+
+#[test]
+fn sbb() {
+    check(0x98, Op::Sbb);
+    check(0xDE, Op::Sbb);
+}
+
+#[test]
+fn ana() {
+    check(0xA0, Op::Ana);
+    check(0xE6, Op::Ana);
+}
+
+#[test]
+fn xra() {
+    check(0xA8, Op::Xra);
+    check(0xEE, Op::Xra);
+}
+
+#[test]
+fn ora() {
+    check(0xB0, Op::Ora);
+    check(0xF6, Op::Ora);
+}
+
+#[test]
+fn cmp() {
+    check(0xB8, Op::Cmp);
+    check(0xFE, Op::Cmp);
+}
+
+// INR and DCR on B, M and A for every value, checking CY is left alone
+fn check_step(opcode: u8, increment: bool) {
+    let mut mem = vec![0; 0x10];
+    let mut state = State::new(&mut mem);
+    state.mem[0] = opcode;
+    state.h = 0x00;
+    state.l = 0x08;
+
+    for value in 0..=255 {
+        for carry in [false, true].iter().copied() {
+            state.pc = 0;
+            state.a = value;
+            state.b = value;
+            state.mem[0x08] = value;
+            state.cc.ac = !carry;
+            state.cc.cy = carry;
+
+            state.step();
+
+            let result = match opcode {
+                0x04 | 0x05 => state.b,
+                0x34 | 0x35 => state.mem[0x08],
+                _ => state.a,
+            };
+            let (expected, ac) = if increment {
+                (value as i32 + 1, value & 0x0F == 0x0F)
+            } else {
+                (value as i32 - 1, value & 0x0F != 0)
+            };
+
+            assert_eq!(
+                (result, Flags::read(&state)),
+                (expected as u8, Flags::of(expected, carry, ac)),
+                "opcode {:02X} with {:02X}, carry={}",
+                opcode,
+                value,
+                carry
+            );
+        }
+    }
+}
+
+#[test]
+fn inr() {
+    check_step(0x04, true);
+    check_step(0x34, true);
+    check_step(0x3C, true);
+}
+
+#[test]
+fn dcr() {
+    check_step(0x05, false);
+    check_step(0x35, false);
+    check_step(0x3D, false);
+}
+
+#[test]
+fn daa() {
+    let mut mem = vec![0x27; 0x10];
+    let mut state = State::new(&mut mem);
+
+    for a in 0..=255 {
+        for (cy, ac) in [(false, false), (false, true), (true, false), (true, true)] {
+            state.pc = 0;
+            state.a = a;
+            state.set_flags(ConditionCodes {
+                cy,
+                ac,
+                ..ConditionCodes::default()
+            });
+
+            state.step();
+
+            let low = if a & 0x0F > 9 || ac { 0x06 } else { 0 };
+            let high = if a > 0x99 || cy { 0x60 } else { 0 };
+            let expected = a as i32 + low + high;
+            let flags = Flags::of(expected, high != 0, (a & 0x0F) as i32 + low > 0x0F);
+            assert_eq!(
+                (state.a, Flags::read(&state)),
+                (expected as u8, flags),
+                "DAA with A={:02X}, cy={}, ac={}",
+                a,
+                cy,
+                ac
+            );
+        }
+    }
+}
+
+// Flags from POP PSW, even combinations no result could give, come back out of
+// PUSH PSW and last until an instruction sets them
+#[test]
+fn popped_flags() {
+    let mut mem = vec![0; 0x10];
+    // POP PSW CMC PUSH PSW POP B INR B
+    mem[..5].copy_from_slice(&[0xF1, 0x3F, 0xF5, 0xC1, 0x04]);
+    let mut state = State::new(&mut mem);
+
+    for flags in 0..=255 {
+        state.pc = 0;
+        state.sp = 0x0E;
+        state.mem[0x0E] = flags;
+        state.mem[0x0F] = flags;
+        for _ in 0..4 {
+            state.step();
+        }
+
+        assert_eq!(state.c, (flags ^ 0x01) & 0xD5 | 0x02, "flags {:02X}", flags);
+        let cy = flags & 0x01 == 0;
+        assert_eq!(state.flags().cy, cy);
+
+        state.step();
+
+        let b = state.b;
+        assert_eq!(
+            Flags::read(&state),
+            Flags::of(b as i32, cy, b & 0x0F == 0),
+            "INR B to {:02X} after flags {:02X}",
+            b,
+            flags
+        );
+    }
+}
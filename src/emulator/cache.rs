@@ -0,0 +1,532 @@
+// 8080 code translated a basic block at a time into runs of pre-decoded
+// instructions, and a lockstep mode that checks them against the interpreter.
+
+use std::error;
+use std::ops::Range;
+
+use super::opcodes::{Opcode, OPCODES};
+use super::{Io, Model, State, Status};
+use crate::cpu::{Cpu, Registers};
+use crate::disasm::{self, Flow};
+
+// Longest block translated, in instructions
+const MAX_BLOCK: usize = 64;
+
+// Blocks are found for invalidation by the 256-byte pages they cover
+const PAGE_SHIFT: usize = 8;
+const PAGES: usize = 0x10000 >> PAGE_SHIFT;
+
+// Steps between full comparisons of memory in lockstep, which cost far more than
+// running a block
+const MEMORY_CHECK_STEPS: u64 = 64;
+
+struct Block {
+    // Bytes of code, from the start address
+    len: usize,
+    ops: Vec<&'static Opcode>,
+}
+
+impl Block {
+    fn covers(&self, start: usize, address: usize) -> bool {
+        address.wrapping_sub(start) & 0xFFFF < self.len
+    }
+}
+
+/// An 8080 that runs a basic block per step, from translations cached until the
+/// code they came from is written.
+pub struct Cached<'a> {
+    cpu: State<'a>,
+    blocks: Vec<Option<Block>>,
+    // Start addresses of blocks covering each page, some of them stale
+    pages: Vec<Vec<usize>>,
+}
+
+impl<'a> Cached<'a> {
+    pub fn new(mem: &'a mut [u8]) -> Cached<'a> {
+        let len = mem.len();
+        let mut cpu = State::new(mem);
+        cpu.watched = vec![false; len];
+        Cached {
+            cpu,
+            blocks: (0..0x10000).map(|_| None).collect(),
+            pages: vec![Vec::new(); PAGES],
+        }
+    }
+
+    /// Drops every translation, as after the host writes memory.
+    pub fn flush(&mut self) {
+        for block in self.blocks.iter_mut() {
+            *block = None;
+        }
+        for page in self.pages.iter_mut() {
+            page.clear();
+        }
+        for watched in self.cpu.watched.iter_mut() {
+            *watched = false;
+        }
+        self.cpu.written.clear();
+    }
+
+    fn translate(&mut self, start: usize) -> Block {
+        let mut ops = Vec::new();
+        let mut len = 0;
+        while ops.len() < MAX_BLOCK {
+            let address = (start + len) & 0xFFFF;
+            let op = &OPCODES[self.cpu.mem[address] as usize];
+            ops.push(op);
+            len += op.len as usize;
+            if disasm::flow(self.cpu.mem, address) != Flow::Next {
+                break;
+            }
+        }
+
+        self.watch(start, len);
+        for page in (start >> PAGE_SHIFT)..=((start + len - 1) >> PAGE_SHIFT) {
+            let starts = &mut self.pages[page % PAGES];
+            if !starts.contains(&start) {
+                starts.push(start);
+            }
+        }
+        Block { len, ops }
+    }
+
+    fn watch(&mut self, start: usize, len: usize) {
+        for offset in 0..len {
+            if let Some(watched) = self.cpu.watched.get_mut((start + offset) & 0xFFFF) {
+                *watched = true;
+            }
+        }
+    }
+
+    // Drops the blocks covering bytes written since the last step
+    fn invalidate(&mut self) {
+        while let Some(address) = self.cpu.written.pop() {
+            let page = address >> PAGE_SHIFT;
+            let mut dropped = Vec::new();
+            for &start in self.pages[page].iter() {
+                if let Some(block) = &self.blocks[start] {
+                    if block.covers(start, address) {
+                        dropped.push((start, block.len));
+                        self.blocks[start] = None;
+                    }
+                }
+            }
+            let blocks = &self.blocks;
+            self.pages[page].retain(|&start| blocks[start].is_some());
+
+            // Stop watching the dropped code, except where other blocks overlap it
+            for &(start, len) in dropped.iter() {
+                for offset in 0..len {
+                    if let Some(watched) = self.cpu.watched.get_mut((start + offset) & 0xFFFF) {
+                        *watched = false;
+                    }
+                }
+            }
+            for &(start, len) in dropped.iter() {
+                for page in (start >> PAGE_SHIFT)..=((start + len - 1) >> PAGE_SHIFT) {
+                    for other in self.pages[page % PAGES].clone() {
+                        if let Some(block) = &self.blocks[other] {
+                            let len = block.len;
+                            self.watch(other, len);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Cpu for Cached<'_> {
+    fn step_io(&mut self, io: &mut dyn Io) -> Status {
+        self.invalidate();
+        if self.cpu.halted || self.cpu.bus.is_some() {
+            return self.cpu.step_io(io);
+        }
+
+        let pc = self.cpu.pc;
+        if self.blocks[pc].is_none() {
+            self.blocks[pc] = Some(self.translate(pc));
+        }
+        let cpu = &mut self.cpu;
+        for op in self.blocks[pc].as_ref().unwrap().ops.iter() {
+            cpu.cycles += op.cycles as u64;
+            (op.execute)(cpu, io);
+            cpu.pc = cpu.pc.wrapping_add(1) & 0xFFFF;
+            // The rest of the block may have been written
+            if !cpu.written.is_empty() {
+                break;
+            }
+        }
+        cpu.status()
+    }
+
+    fn reset(&mut self) {
+        self.cpu.reset()
+    }
+
+    fn interrupt(&mut self, vector: u8) {
+        self.cpu.interrupt(vector)
+    }
+
+    fn interrupt_with(&mut self, data: &[u8]) {
+        self.cpu.interrupt_with(data)
+    }
+
+    fn cycles(&self) -> u64 {
+        self.cpu.cycles
+    }
+
+    fn status(&self) -> Status {
+        self.cpu.status()
+    }
+
+    fn registers(&self) -> Registers {
+        Cpu::registers(&self.cpu)
+    }
+
+    fn set_registers(&mut self, registers: Registers) {
+        Cpu::set_registers(&mut self.cpu, registers)
+    }
+
+    fn memory(&self) -> &[u8] {
+        self.cpu.mem
+    }
+
+    fn memory_mut(&mut self) -> &mut [u8] {
+        self.flush();
+        self.cpu.mem
+    }
+
+    fn protect(&mut self, range: Range<usize>) {
+        self.cpu.protect(range)
+    }
+
+    fn save_state(&self) -> Vec<u8> {
+        self.cpu.save_state()
+    }
+
+    fn load_state(&mut self, state: &[u8]) -> Result<(), Box<dyn error::Error>> {
+        let saved = self.cpu.save_state();
+        self.cpu.load_state(state)?;
+        if self.cpu.model != Model::I8080 {
+            self.cpu.load_state(&saved)?;
+            return Err("Saved state is for another CPU".into());
+        }
+        Ok(())
+    }
+}
+
+// Passes port accesses through, noting them for the reference to replay
+struct Recorder<'a> {
+    io: &'a mut dyn Io,
+    inputs: Vec<u8>,
+    outputs: Vec<(u8, u8)>,
+}
+
+impl Io for Recorder<'_> {
+    fn input(&mut self, port: u8) -> u8 {
+        let value = self.io.input(port);
+        self.inputs.push(value);
+        value
+    }
+
+    fn output(&mut self, port: u8, value: u8) {
+        self.outputs.push((port, value));
+        self.io.output(port, value);
+    }
+}
+
+struct Replay<'a> {
+    inputs: std::slice::Iter<'a, u8>,
+    outputs: Vec<(u8, u8)>,
+}
+
+impl Io for Replay<'_> {
+    fn input(&mut self, port: u8) -> u8 {
+        *self
+            .inputs
+            .next()
+            .unwrap_or_else(|| panic!("Reference read port {:02X} the core didn't", port))
+    }
+
+    fn output(&mut self, port: u8, value: u8) {
+        self.outputs.push((port, value));
+    }
+}
+
+/// Runs an 8080 core alongside the reference interpreter on its own copy of
+/// memory, and panics as soon as the two disagree.
+pub struct Lockstep<'a> {
+    cpu: Box<dyn Cpu + 'a>,
+    // The reference's memory and saved state
+    memory: Vec<u8>,
+    state: Vec<u8>,
+    read_only: Vec<Range<usize>>,
+    // Whether the host may have written the core's memory since the last step
+    stale: bool,
+    steps: u64,
+}
+
+impl<'a> Lockstep<'a> {
+    pub fn new(cpu: Box<dyn Cpu + 'a>) -> Lockstep<'a> {
+        Lockstep {
+            memory: cpu.memory().to_vec(),
+            state: cpu.save_state(),
+            cpu,
+            read_only: Vec::new(),
+            stale: false,
+            steps: 0,
+        }
+    }
+
+    // Runs `f` on the reference and keeps the state it leaves
+    fn reference<T>(&mut self, f: impl FnOnce(&mut State) -> T) -> T {
+        let mut reference = State::new(&mut self.memory);
+        reference
+            .load_state(&self.state)
+            .expect("Reference state is an 8080's");
+        for range in self.read_only.iter() {
+            reference.protect(range.clone());
+        }
+        let result = f(&mut reference);
+        self.state = reference.save_state();
+        result
+    }
+
+    fn check_memory(&self) {
+        let core = self.cpu.memory();
+        if let Some(address) = (0..core.len()).find(|&i| core[i] != self.memory[i]) {
+            panic!(
+                "Memory differs from the reference at {:04X}: {:02X}, expected {:02X}",
+                address, core[address], self.memory[address]
+            );
+        }
+    }
+}
+
+impl Cpu for Lockstep<'_> {
+    fn step_io(&mut self, io: &mut dyn Io) -> Status {
+        if self.stale {
+            self.memory.copy_from_slice(self.cpu.memory());
+            self.stale = false;
+        }
+        let pc = self.cpu.pc();
+
+        let mut recorder = Recorder {
+            io,
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+        };
+        let status = self.cpu.step_io(&mut recorder);
+        let cycles = self.cpu.cycles();
+
+        let mut replay = Replay {
+            inputs: recorder.inputs.iter(),
+            outputs: Vec::new(),
+        };
+        let expected = self.reference(|reference| {
+            while reference.cycles() < cycles {
+                reference.step_io(&mut replay);
+            }
+            Cpu::registers(reference)
+        });
+
+        if self.cpu.save_state() != self.state {
+            panic!(
+                "Step from {:04X} differs from the reference: {:04X?}, expected {:04X?}",
+                pc,
+                self.cpu.registers(),
+                expected
+            );
+        }
+        assert_eq!(
+            recorder.outputs, replay.outputs,
+            "Step from {:04X} wrote different ports from the reference",
+            pc
+        );
+        self.steps += 1;
+        if status != Status::Running || self.steps.is_multiple_of(MEMORY_CHECK_STEPS) {
+            self.check_memory();
+        }
+        status
+    }
+
+    fn reset(&mut self) {
+        self.cpu.reset();
+        self.reference(|reference| reference.reset());
+    }
+
+    fn interrupt(&mut self, vector: u8) {
+        self.cpu.interrupt(vector);
+        self.reference(|reference| reference.interrupt(vector));
+    }
+
+    fn interrupt_with(&mut self, data: &[u8]) {
+        self.cpu.interrupt_with(data);
+        self.reference(|reference| reference.interrupt_with(data));
+    }
+
+    fn cycles(&self) -> u64 {
+        self.cpu.cycles()
+    }
+
+    fn status(&self) -> Status {
+        self.cpu.status()
+    }
+
+    fn registers(&self) -> Registers {
+        self.cpu.registers()
+    }
+
+    fn set_registers(&mut self, registers: Registers) {
+        self.cpu.set_registers(registers);
+        self.reference(|reference| Cpu::set_registers(reference, registers));
+    }
+
+    fn memory(&self) -> &[u8] {
+        self.cpu.memory()
+    }
+
+    fn memory_mut(&mut self) -> &mut [u8] {
+        self.stale = true;
+        self.cpu.memory_mut()
+    }
+
+    fn protect(&mut self, range: Range<usize>) {
+        self.cpu.protect(range.clone());
+        self.read_only.push(range);
+    }
+
+    fn save_state(&self) -> Vec<u8> {
+        self.cpu.save_state()
+    }
+
+    fn load_state(&mut self, state: &[u8]) -> Result<(), Box<dyn error::Error>> {
+        self.cpu.load_state(state)?;
+        self.state = self.cpu.save_state();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(cpu: &mut dyn Cpu) {
+        while cpu.step() == Status::Running {}
+    }
+
+    #[test]
+    fn runs_a_block_per_step() {
+        let mut mem = vec![0; 0x10000];
+        // MVI B,1 INR B INR B JMP 0100, and HLT at 0100
+        mem[..8].copy_from_slice(&[0x06, 0x01, 0x04, 0x04, 0xC3, 0x00, 0x01, 0x00]);
+        mem[0x100] = 0x76;
+        let mut cpu = Cached::new(&mut mem);
+
+        cpu.step();
+        assert_eq!(cpu.bc(), 0x0300);
+        assert_eq!(cpu.pc(), 0x100);
+        assert_eq!(cpu.cycles(), 7 + 5 + 5 + 10);
+        assert_eq!(cpu.step(), Status::Halted);
+    }
+
+    #[test]
+    fn writes_into_the_running_block() {
+        let mut mem = vec![0; 0x10000];
+        // MVI A,04 STA 0006 NOP, then a NOP that becomes INR B, and HLT
+        mem[..8].copy_from_slice(&[0x3E, 0x04, 0x32, 0x06, 0x00, 0x00, 0x00, 0x76]);
+        let mut cpu = Lockstep::new(Box::new(Cached::new(&mut mem)));
+
+        run(&mut cpu);
+
+        assert_eq!(cpu.bc(), 0x0100);
+    }
+
+    #[test]
+    fn writes_into_other_blocks() {
+        let mut mem = vec![0; 0x10000];
+        #[rustfmt::skip]
+        let program = [
+            0x06, 0x00, // MVI B,0
+            0x0E, 0x03, // MVI C,3
+            0xCD, 0x20, 0x00, // CALL 0020
+            0x3A, 0x20, 0x00, // LDA 0020
+            0xC6, 0x08, // ADI 8: INR B becomes INR C, INR D, then INR E
+            0x32, 0x20, 0x00, // STA 0020
+            0xC3, 0x04, 0x00, // JMP 0004
+        ];
+        mem[..program.len()].copy_from_slice(&program);
+        // INR B DCR C RNZ HLT
+        mem[0x20..0x24].copy_from_slice(&[0x04, 0x0D, 0xC0, 0x76]);
+        let mut cpu = Lockstep::new(Box::new(Cached::new(&mut mem)));
+        cpu.set_sp(0xF000);
+
+        run(&mut cpu);
+
+        assert_eq!(cpu.bc(), 0x0100);
+        assert_eq!(cpu.de(), 0x0101);
+    }
+
+    #[test]
+    fn host_writes_flush_the_cache() {
+        let mut mem = vec![0; 0x10000];
+        // INR A JMP 0000
+        mem[..4].copy_from_slice(&[0x3C, 0xC3, 0x00, 0x00]);
+        let mut cpu = Cached::new(&mut mem);
+
+        cpu.step();
+        cpu.memory_mut()[0] = 0x04;
+        cpu.step();
+
+        assert_eq!(cpu.a(), 1);
+        assert_eq!(cpu.bc(), 0x0100);
+    }
+
+    #[test]
+    fn interrupts_and_ports_in_lockstep() {
+        struct Ports(u8);
+        impl Io for Ports {
+            fn input(&mut self, _port: u8) -> u8 {
+                self.0 = self.0.wrapping_add(3);
+                self.0
+            }
+
+            fn output(&mut self, _port: u8, value: u8) {
+                self.0 ^= value;
+            }
+        }
+
+        let mut mem = vec![0; 0x10000];
+        // EI IN 1 OUT 2 ADD B MOV B,A JMP 0000, and INR C RET at 0038
+        mem[..9].copy_from_slice(&[0xFB, 0xDB, 0x01, 0xD3, 0x02, 0x80, 0x47, 0xC3, 0x00]);
+        mem[0x38..0x3A].copy_from_slice(&[0x0C, 0xC9]);
+        let mut cpu = Lockstep::new(Box::new(Cached::new(&mut mem)));
+        cpu.set_sp(0xF000);
+        let mut ports = Ports(0);
+
+        for step in 0..1000 {
+            cpu.step_io(&mut ports);
+            if step % 7 == 0 {
+                cpu.interrupt(7);
+            }
+        }
+
+        // The loop enables interrupts again each time round, so every one is taken
+        assert_eq!(cpu.bc() & 0xFF, 143);
+    }
+
+    #[test]
+    #[should_panic(expected = "Memory differs from the reference at 2000")]
+    fn lockstep_catches_differences() {
+        let mut mem = vec![0; 0x10000];
+        // MVI A,55 STA 2000 HLT
+        mem[..6].copy_from_slice(&[0x3E, 0x55, 0x32, 0x00, 0x20, 0x76]);
+        // Protected before lockstep sees it, so the reference still writes it
+        let mut cached = Cached::new(&mut mem);
+        cached.protect(0x2000..0x2001);
+        let mut cpu = Lockstep::new(Box::new(cached));
+
+        run(&mut cpu);
+    }
+}
@@ -0,0 +1,230 @@
+// The 8085's interrupt inputs, serial pins, timings and undocumented instructions.
+
+use super::*;
+
+// An 8085 with `program` at 0 and the stack at 0xF000
+fn i8085<'a>(mem: &'a mut [u8], program: &[u8]) -> State<'a> {
+    mem[..program.len()].copy_from_slice(program);
+    let mut cpu = State::new(mem);
+    cpu.set_model(Model::I8085);
+    cpu
+}
+
+#[test]
+fn rim_and_sim_are_nops_on_an_8080() {
+    let mut mem = vec![0; 0x10000];
+    // MVI A,C0 SIM RIM
+    mem[..4].copy_from_slice(&[0x3E, 0xC0, 0x30, 0x20]);
+    let mut cpu = State::new(&mut mem);
+    cpu.set_sid(true);
+
+    for _ in 0..3 {
+        cpu.step();
+    }
+
+    assert_eq!(cpu.a(), 0xC0);
+    assert!(!cpu.sod());
+}
+
+#[test]
+fn serial_pins_and_masks() {
+    let mut mem = vec![0; 0x10000];
+    // MVI A,CF SIM: SOD high, everything masked. EI RIM
+    let mut cpu = i8085(&mut mem, &[0x3E, 0xCF, 0x30, 0xFB, 0x20]);
+    cpu.set_sid(true);
+    cpu.set_rst6_5(true);
+
+    for _ in 0..4 {
+        cpu.step();
+    }
+
+    assert!(cpu.sod());
+    // SID, RST 6.5 pending but masked, interrupts enabled, masks
+    assert_eq!(cpu.a(), 0b1010_1111);
+}
+
+#[test]
+fn interrupts_wait_for_their_masks() {
+    let mut mem = vec![0; 0x10000];
+    // EI NOP MVI A,0D SIM: unmask 6.5 only
+    let mut cpu = i8085(&mut mem, &[0xFB, 0x00, 0x3E, 0x0D, 0x30, 0x00]);
+    cpu.set_rst5_5(true);
+    cpu.set_rst6_5(true);
+
+    for _ in 0..4 {
+        cpu.step();
+    }
+    assert_eq!(cpu.pc(), 5);
+
+    let cycles = cpu.cycles();
+    cpu.step();
+    assert_eq!(cpu.pc(), 0x35);
+    assert_eq!(cpu.read_word(0xEFFE), 5);
+    assert_eq!(cpu.cycles() - cycles, 12 + 4);
+}
+
+#[test]
+fn rst7_5_is_latched_until_cleared() {
+    let mut mem = vec![0; 0x10000];
+    // MVI A,18 SIM: unmask everything and clear the latch. EI NOP
+    let mut cpu = i8085(&mut mem, &[0x3E, 0x18, 0x30, 0xFB, 0x00, 0x00]);
+    cpu.rst7_5();
+
+    for _ in 0..3 {
+        cpu.step();
+    }
+    cpu.step();
+    assert_eq!(cpu.pc(), 5);
+
+    cpu.rst7_5();
+    cpu.step();
+    assert_eq!(cpu.pc(), 0x3D);
+}
+
+#[test]
+fn trap_wakes_a_deadlocked_halt() {
+    let mut mem = vec![0; 0x10000];
+    // DI HLT, then RIM at the TRAP vector
+    let mut cpu = i8085(&mut mem, &[0xF3, 0x76]);
+    cpu.memory_mut()[0x24] = 0x20;
+
+    cpu.step();
+    assert_eq!(cpu.step(), Status::Deadlock);
+
+    cpu.trap();
+    assert_eq!(cpu.step(), Status::Running);
+    assert_eq!(cpu.pc(), 0x25);
+    assert_eq!(cpu.a() & 0x08, 0);
+}
+
+#[test]
+fn rim_after_trap_reports_the_old_enable() {
+    let mut mem = vec![0; 0x10000];
+    // EI NOP, RIM RIM at the TRAP vector
+    let mut cpu = i8085(&mut mem, &[0xFB, 0x00]);
+    cpu.memory_mut()[0x24..0x26].copy_from_slice(&[0x20, 0x20]);
+
+    cpu.step();
+    cpu.trap();
+    cpu.step();
+    assert_eq!(cpu.a() & 0x08, 0x08);
+    cpu.step();
+    assert_eq!(cpu.a() & 0x08, 0);
+}
+
+#[test]
+fn timings() {
+    let mut mem = vec![0; 0x10000];
+    // MOV A,B JZ 0 (not taken) CALL 0100 PUSH B INX B, and JNZ 0 at 0100
+    let mut cpu = i8085(
+        &mut mem,
+        &[0x78, 0xCA, 0x00, 0x00, 0xCD, 0x00, 0x01, 0xC5, 0x03],
+    );
+    cpu.memory_mut()[0x100..0x103].copy_from_slice(&[0xC2, 0x00, 0x00]);
+    cpu.set_psw(0);
+
+    let mut cycles = Vec::new();
+    for _ in 0..4 {
+        let before = cpu.cycles();
+        cpu.step();
+        cycles.push(cpu.cycles() - before);
+    }
+
+    assert_eq!(cycles, vec![4, 7, 18, 10]);
+    assert_eq!(cpu.pc(), 0);
+}
+
+#[test]
+fn overflow_and_k_flags() {
+    let mut mem = vec![0; 0x10000];
+    // MVI A,7F ADI 1 PUSH PSW, LXI B,FFFF INX B PUSH PSW
+    let mut cpu = i8085(
+        &mut mem,
+        &[0x3E, 0x7F, 0xC6, 0x01, 0xF5, 0x01, 0xFF, 0xFF, 0x03, 0xF5],
+    );
+    cpu.set_sp(0xF000);
+
+    for _ in 0..3 {
+        cpu.step();
+    }
+    // 7F + 1 overflows into a negative result, so V is set and S xor V is clear
+    assert_eq!(cpu.memory()[0xEFFE] & 0x22, 0x02);
+
+    for _ in 0..3 {
+        cpu.step();
+    }
+    assert_eq!(cpu.memory()[0xEFFC] & 0x20, 0x20);
+}
+
+#[test]
+fn undocumented_instructions() {
+    let mut mem = vec![0; 0x10000];
+    let mut cpu = i8085(
+        &mut mem,
+        &[
+            0x21, 0x00, 0x90, // LXI H,9000
+            0x01, 0x01, 0x10, // LXI B,1001
+            0x08, // DSUB: HL = 7FFF
+            0x10, // ARHL: HL = 3FFF, CY set
+            0x28, 0x01, // LDHI 1: DE = 4000
+            0x18, // RDEL: DE = 8001
+            0xD9, // SHLX
+            0x38, 0x10, // LDSI 10: DE = F010
+            0xED, // LHLX
+        ],
+    );
+    cpu.set_undocumented(true);
+    cpu.set_sp(0xF000);
+    cpu.memory_mut()[0xF010..0xF012].copy_from_slice(&[0x34, 0x12]);
+
+    for _ in 0..3 {
+        cpu.step();
+    }
+    assert_eq!(cpu.hl(), 0x7FFF);
+    cpu.step();
+    assert_eq!(cpu.hl(), 0x3FFF);
+    assert!(cpu.flags().cy);
+    cpu.step();
+    assert_eq!(cpu.de(), 0x4000);
+    cpu.step();
+    assert_eq!(cpu.de(), 0x8001);
+    cpu.step();
+    assert_eq!(&cpu.memory()[0x8001..0x8003], &[0xFF, 0x3F]);
+    cpu.step();
+    assert_eq!(cpu.de(), 0xF010);
+    cpu.step();
+    assert_eq!(cpu.hl(), 0x1234);
+}
+
+#[test]
+fn undocumented_jumps() {
+    let mut mem = vec![0; 0x10000];
+    // LXI B,FFFF INX B JK 0100, and MVI A,7F ADI 1 RSTV at 0100
+    let mut cpu = i8085(&mut mem, &[0x01, 0xFF, 0xFF, 0x03, 0xFD, 0x00, 0x01]);
+    cpu.memory_mut()[0x100..0x105].copy_from_slice(&[0x3E, 0x7F, 0xC6, 0x01, 0xCB]);
+    cpu.set_undocumented(true);
+
+    for _ in 0..3 {
+        cpu.step();
+    }
+    assert_eq!(cpu.pc(), 0x100);
+
+    for _ in 0..3 {
+        cpu.step();
+    }
+    assert_eq!(cpu.pc(), 0x40);
+}
+
+#[test]
+fn undocumented_instructions_off_by_default() {
+    let mut mem = vec![0; 0x10000];
+    // DSUB is a NOP and RSTV a JMP
+    let mut cpu = i8085(&mut mem, &[0x08, 0xCB, 0x00, 0x02]);
+    cpu.set_hl(5);
+
+    cpu.step();
+    cpu.step();
+
+    assert_eq!(cpu.hl(), 5);
+    assert_eq!(cpu.pc(), 0x200);
+}
@@ -0,0 +1,250 @@
+// Runs 8080 code recompiled to Rust ahead of time by `recompiler`, falling back
+// to the interpreter wherever the recompiled code doesn't reach or no longer
+// matches memory. Recompiled modules are children of this one, so they can work
+// on `State` directly.
+
+use std::error;
+use std::ops::Range;
+
+use super::{Io, Model, State, Status};
+use crate::cpu::{Cpu, Registers};
+
+pub mod invaders;
+
+/// Runs a block, returning the address to carry on from.
+pub type BlockFn = fn(&mut State, &mut dyn Io) -> usize;
+
+/// A recompiled basic block: `len` bytes of code from `start`, with the checksum
+/// they had when recompiled.
+pub struct Block {
+    pub start: u16,
+    pub len: u16,
+    pub checksum: u32,
+    pub run: BlockFn,
+}
+
+pub const fn block(start: u16, len: u16, checksum: u32, run: BlockFn) -> Block {
+    Block {
+        start,
+        len,
+        checksum,
+        run,
+    }
+}
+
+/// FNV-1a, to tell whether memory still holds the code a block came from.
+pub fn checksum(code: &[u8]) -> u32 {
+    code.iter().fold(0x811C_9DC5, |hash, &byte| {
+        (hash ^ byte as u32).wrapping_mul(0x0100_0193)
+    })
+}
+
+/// An 8080 running recompiled blocks.
+pub struct Native<'a> {
+    cpu: State<'a>,
+    compiled: &'static [Block],
+    // The block starting at each address, if it still matches memory
+    blocks: Vec<Option<&'static Block>>,
+    // Whether the host may have written memory since the blocks were checked
+    unchecked: bool,
+}
+
+impl<'a> Native<'a> {
+    pub fn new(mem: &'a mut [u8], compiled: &'static [Block]) -> Native<'a> {
+        let len = mem.len();
+        let mut cpu = State::new(mem);
+        cpu.watched = vec![false; len];
+        Native {
+            cpu,
+            compiled,
+            blocks: vec![None; 0x10000],
+            unchecked: true,
+        }
+    }
+
+    // Uses each block whose code is in memory, watching it for writes
+    fn check(&mut self) {
+        for watched in self.cpu.watched.iter_mut() {
+            *watched = false;
+        }
+        for block in self.compiled.iter() {
+            let code = self.code(block);
+            let matches = code.is_some_and(|code| checksum(code) == block.checksum);
+            if matches {
+                self.watch(block, true);
+            }
+            self.blocks[block.start as usize] = if matches { Some(block) } else { None };
+        }
+        self.cpu.written.clear();
+        self.unchecked = false;
+    }
+
+    fn code(&self, block: &Block) -> Option<&[u8]> {
+        let start = block.start as usize;
+        self.cpu.mem.get(start..start + block.len as usize)
+    }
+
+    fn watch(&mut self, block: &Block, watched: bool) {
+        let start = block.start as usize;
+        for byte in self.cpu.watched[start..start + block.len as usize].iter_mut() {
+            *byte = watched;
+        }
+    }
+
+    // Interprets the blocks covering bytes written since the last step from now on
+    fn invalidate(&mut self) {
+        while let Some(address) = self.cpu.written.pop() {
+            for block in self.compiled.iter() {
+                let start = block.start as usize;
+                if (start..start + block.len as usize).contains(&address) {
+                    self.blocks[start] = None;
+                    self.watch(block, false);
+                }
+            }
+        }
+    }
+}
+
+impl Cpu for Native<'_> {
+    fn step_io(&mut self, io: &mut dyn Io) -> Status {
+        if self.unchecked {
+            self.check();
+        }
+        self.invalidate();
+        if self.cpu.halted || self.cpu.bus.is_some() {
+            return self.cpu.step_io(io);
+        }
+
+        match self.blocks[self.cpu.pc] {
+            Some(block) => {
+                self.cpu.pc = (block.run)(&mut self.cpu, io) & 0xFFFF;
+                self.cpu.status()
+            }
+            None => self.cpu.step_io(io),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.cpu.reset()
+    }
+
+    fn interrupt(&mut self, vector: u8) {
+        self.cpu.interrupt(vector)
+    }
+
+    fn interrupt_with(&mut self, data: &[u8]) {
+        self.cpu.interrupt_with(data)
+    }
+
+    fn cycles(&self) -> u64 {
+        self.cpu.cycles
+    }
+
+    fn status(&self) -> Status {
+        self.cpu.status()
+    }
+
+    fn registers(&self) -> Registers {
+        Cpu::registers(&self.cpu)
+    }
+
+    fn set_registers(&mut self, registers: Registers) {
+        Cpu::set_registers(&mut self.cpu, registers)
+    }
+
+    fn memory(&self) -> &[u8] {
+        self.cpu.mem
+    }
+
+    fn memory_mut(&mut self) -> &mut [u8] {
+        self.unchecked = true;
+        self.cpu.mem
+    }
+
+    fn protect(&mut self, range: Range<usize>) {
+        self.cpu.protect(range)
+    }
+
+    fn save_state(&self) -> Vec<u8> {
+        self.cpu.save_state()
+    }
+
+    fn load_state(&mut self, state: &[u8]) -> Result<(), Box<dyn error::Error>> {
+        let saved = self.cpu.save_state();
+        self.cpu.load_state(state)?;
+        if self.cpu.model != Model::I8080 {
+            self.cpu.load_state(&saved)?;
+            return Err("Saved state is for another CPU".into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::emulator::Unconnected;
+    use std::path::Path;
+
+    // MVI A,1 HLT, as the recompiler would write it
+    fn load_one(cpu: &mut State, _io: &mut dyn Io) -> usize {
+        cpu.cycles += 7;
+        cpu.a = 0x01;
+        cpu.cycles += 7;
+        cpu.halted = true;
+        0x0003
+    }
+
+    static LOAD_ONE: [Block; 1] = [block(0x0000, 3, 0x1F57_DAA2, load_one)];
+
+    #[test]
+    fn runs_blocks_matching_memory() {
+        assert_eq!(checksum(&[0x3E, 0x01, 0x76]), LOAD_ONE[0].checksum);
+
+        let mut mem = vec![0; 0x10000];
+        mem[..3].copy_from_slice(&[0x3E, 0x01, 0x76]);
+        let mut cpu = Native::new(&mut mem, &LOAD_ONE);
+
+        assert_eq!(cpu.step(), Status::Halted);
+        assert_eq!(cpu.registers().a, 0x01);
+        assert_eq!(cpu.cycles(), 14);
+    }
+
+    #[test]
+    fn interprets_changed_code() {
+        let mut mem = vec![0; 0x10000];
+        mem[..3].copy_from_slice(&[0x3E, 0x01, 0x76]);
+        let mut cpu = Native::new(&mut mem, &LOAD_ONE);
+        cpu.memory_mut()[1] = 0x02;
+
+        while cpu.step() == Status::Running {}
+        assert_eq!(cpu.registers().a, 0x02);
+    }
+
+    #[test]
+    fn interprets_code_the_program_wrote() {
+        let mut mem = vec![0; 0x10000];
+        // MVI A,1 HLT, then at 0x10 MVI A,5 STA 0001 JMP 0000
+        mem[..3].copy_from_slice(&[0x3E, 0x01, 0x76]);
+        mem[0x10..0x18].copy_from_slice(&[0x3E, 0x05, 0x32, 0x01, 0x00, 0xC3, 0x00, 0x00]);
+        let mut cpu = Native::new(&mut mem, &LOAD_ONE);
+        cpu.set_pc(0x10);
+
+        while cpu.step() == Status::Running {}
+        assert_eq!(cpu.registers().a, 0x05);
+    }
+
+    #[test]
+    fn invaders_blocks_match_the_roms() {
+        let rom_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("games/invaders");
+        let mut mem = vec![0; 0x10000];
+        for (offset, name) in (0..).zip(["invaders.h", "invaders.g", "invaders.f", "invaders.e"]) {
+            let rom = std::fs::read(rom_dir.join(name)).unwrap();
+            mem[offset * 0x800..(offset + 1) * 0x800].copy_from_slice(&rom);
+        }
+        let mut cpu = Native::new(&mut mem, &invaders::BLOCKS);
+        cpu.step_io(&mut Unconnected);
+
+        assert_eq!(cpu.blocks.iter().flatten().count(), invaders::BLOCKS.len());
+    }
+}
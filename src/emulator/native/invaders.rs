@@ -0,0 +1,8560 @@
+// Recompiled from 8080 code by `emurs recompile`. Each basic block is a function
+// returning the address to carry on from.
+
+use super::{block, Block};
+use crate::emulator::opcodes;
+use crate::emulator::{Io, State};
+
+pub static BLOCKS: [Block; 582] = [
+    block(0x0000, 6, 0x78464AC0, block_0000),
+    block(0x0008, 7, 0x2C89C9EA, block_0008),
+    block(0x0010, 16, 0xF295581E, block_0010),
+    block(0x0018, 8, 0xA5D2EFE0, block_0018),
+    block(0x0020, 6, 0x45C6AC8F, block_0020),
+    block(0x0026, 7, 0xD8738A04, block_0026),
+    block(0x0028, 5, 0xAD566AC4, block_0028),
+    block(0x002D, 3, 0x21335896, block_002d),
+    block(0x0030, 5, 0xDFCB7F78, block_0030),
+    block(0x0035, 3, 0x60DA771D, block_0035),
+    block(0x0038, 6, 0x67D931F9, block_0038),
+    block(0x003E, 1, 0xAA0BCDDE, block_003e),
+    block(0x003F, 3, 0x2E4C1077, block_003f),
+    block(0x0042, 7, 0x42405523, block_0042),
+    block(0x0049, 7, 0x0EC3F2D8, block_0049),
+    block(0x0050, 7, 0xC154321E, block_0050),
+    block(0x0057, 3, 0x1D46A0BD, block_0057),
+    block(0x005A, 3, 0x11C97270, block_005a),
+    block(0x005D, 7, 0xBCEAB511, block_005d),
+    block(0x0064, 3, 0xD6AFAAB6, block_0064),
+    block(0x0067, 8, 0x4F8FE562, block_0067),
+    block(0x006F, 3, 0x71FD57CD, block_006f),
+    block(0x0072, 9, 0x3A48B951, block_0072),
+    block(0x007B, 3, 0x2CE96376, block_007b),
+    block(0x007E, 3, 0xF33E6D08, block_007e),
+    block(0x0081, 1, 0x050C5D1F, block_0081),
+    block(0x0082, 6, 0x30FAB87D, block_0082),
+    block(0x008C, 11, 0x8551ACD6, block_008c),
+    block(0x0097, 7, 0xAC90E322, block_0097),
+    block(0x009E, 7, 0xD1DB190B, block_009e),
+    block(0x00A5, 6, 0x79DDD43E, block_00a5),
+    block(0x00AB, 3, 0x7FFFAC6E, block_00ab),
+    block(0x00AE, 3, 0x11C97270, block_00ae),
+    block(0x00B1, 3, 0xB53036B6, block_00b1),
+    block(0x00B4, 19, 0x396F2611, block_00b4),
+    block(0x00C7, 1, 0x380CAD68, block_00c7),
+    block(0x00C8, 10, 0xBD6609D8, block_00c8),
+    block(0x00D2, 1, 0x390CAEFB, block_00d2),
+    block(0x00D3, 4, 0xAA3C7423, block_00d3),
+    block(0x00D7, 11, 0xFBEAB2BD, block_00d7),
+    block(0x0100, 8, 0xFC4470B6, block_0100),
+    block(0x0108, 15, 0x5C1B64DE, block_0108),
+    block(0x0117, 23, 0x75BD8876, block_0117),
+    block(0x012E, 8, 0x366B564B, block_012e),
+    block(0x0136, 5, 0xE054B737, block_0136),
+    block(0x013B, 6, 0xD522C62F, block_013b),
+    block(0x0141, 5, 0x65B91A02, block_0141),
+    block(0x0146, 5, 0x0053BC42, block_0146),
+    block(0x014B, 9, 0xF19C8DBD, block_014b),
+    block(0x0154, 6, 0xC5B7CDF2, block_0154),
+    block(0x015A, 6, 0xF7886B20, block_015a),
+    block(0x0160, 6, 0xEDC59D3B, block_0160),
+    block(0x0166, 10, 0x88FC959E, block_0166),
+    block(0x0170, 10, 0xC71B1C31, block_0170),
+    block(0x017A, 9, 0xA4415A07, block_017a),
+    block(0x0183, 5, 0xCAE2520B, block_0183),
+    block(0x0188, 12, 0x70C81214, block_0188),
+    block(0x0194, 1, 0xED0C3757, block_0194),
+    block(0x0195, 2, 0xA681B28A, block_0195),
+    block(0x0197, 10, 0x6F08BF17, block_0197),
+    block(0x01A1, 4, 0xC7356BA8, block_01a1),
+    block(0x01A5, 12, 0xB01E727C, block_01a5),
+    block(0x01B1, 14, 0xFAB494A5, block_01b1),
+    block(0x01C0, 3, 0x09A0E1BF, block_01c0),
+    block(0x01C3, 2, 0x9A715F4A, block_01c3),
+    block(0x01C5, 7, 0xD900B134, block_01c5),
+    block(0x01CC, 1, 0x4C0B39E4, block_01cc),
+    block(0x01CD, 2, 0xE2E72BFF, block_01cd),
+    block(0x01CF, 10, 0x8438D7F0, block_01cf),
+    block(0x01D9, 11, 0x4B6078D2, block_01d9),
+    block(0x01E4, 2, 0x057074BB, block_01e4),
+    block(0x01E6, 9, 0xFF1CF1CD, block_01e6),
+    block(0x01EF, 6, 0xD452B531, block_01ef),
+    block(0x01F5, 3, 0x9206D2D8, block_01f5),
+    block(0x01F8, 5, 0xEEE2B709, block_01f8),
+    block(0x01FD, 6, 0x690BB7BD, block_01fd),
+    block(0x0203, 5, 0x1C761F51, block_0203),
+    block(0x0208, 1, 0x4C0B39E4, block_0208),
+    block(0x0209, 5, 0xB3559E2C, block_0209),
+    block(0x020E, 5, 0x5570E0AF, block_020e),
+    block(0x0213, 1, 0xAA0BCDDE, block_0213),
+    block(0x0214, 6, 0x112CEE6D, block_0214),
+    block(0x021A, 1, 0xAA0BCDDE, block_021a),
+    block(0x021B, 3, 0xE7D93C21, block_021b),
+    block(0x021E, 11, 0x28AEBAF2, block_021e),
+    block(0x0229, 9, 0x2766FC5B, block_0229),
+    block(0x0232, 3, 0x273B4A7B, block_0232),
+    block(0x0235, 4, 0x688CB572, block_0235),
+    block(0x0239, 9, 0xB2F48B73, block_0239),
+    block(0x0242, 3, 0x2F1B0368, block_0242),
+    block(0x0245, 3, 0xFC26A645, block_0245),
+    block(0x0248, 3, 0x28C821FC, block_0248),
+    block(0x024B, 4, 0x2CB670DA, block_024b),
+    block(0x024F, 5, 0x7128674A, block_024f),
+    block(0x0254, 8, 0x6FC0A0F9, block_0254),
+    block(0x025C, 6, 0x9C487D4B, block_025c),
+    block(0x0262, 13, 0x3BC21429, block_0262),
+    block(0x0277, 5, 0xCC2231B5, block_0277),
+    block(0x027C, 1, 0x380CAD68, block_027c),
+    block(0x027D, 4, 0xE3B79EA2, block_027d),
+    block(0x0281, 7, 0x11B29A2D, block_0281),
+    block(0x0288, 6, 0x6A345990, block_0288),
+    block(0x02ED, 8, 0xA53E1A73, block_02ed),
+    block(0x02F5, 3, 0x8C59A160, block_02f5),
+    block(0x02F8, 3, 0x93111378, block_02f8),
+    block(0x02FB, 10, 0xB55131A3, block_02fb),
+    block(0x0305, 9, 0x2CB0B017, block_0305),
+    block(0x030E, 4, 0x287FA027, block_030e),
+    block(0x0312, 6, 0x2E2C7F41, block_0312),
+    block(0x0318, 14, 0x137A8E31, block_0318),
+    block(0x0326, 3, 0xAF2288AD, block_0326),
+    block(0x0329, 3, 0x17CF2EE2, block_0329),
+    block(0x0332, 3, 0xCE4D3D53, block_0332),
+    block(0x0335, 3, 0xEACCA974, block_0335),
+    block(0x0707, 5, 0x17335849, block_0707),
+    block(0x0765, 12, 0xE776A242, block_0765),
+    block(0x0771, 3, 0xD6A72BF9, block_0771),
+    block(0x0774, 11, 0x43C1ABF2, block_0774),
+    block(0x077F, 12, 0x088D3D47, block_077f),
+    block(0x078B, 6, 0x6641385F, block_078b),
+    block(0x0791, 7, 0x491CFB71, block_0791),
+    block(0x0798, 3, 0x8469171D, block_0798),
+    block(0x079B, 14, 0xDD8CC639, block_079b),
+    block(0x07A9, 12, 0xB50417D1, block_07a9),
+    block(0x07B5, 3, 0xE3A18D70, block_07b5),
+    block(0x07B8, 3, 0xEAA98A0C, block_07b8),
+    block(0x07BB, 16, 0x27704E2F, block_07bb),
+    block(0x07CB, 3, 0xF30CD03C, block_07cb),
+    block(0x07CE, 3, 0x76F40822, block_07ce),
+    block(0x07D1, 3, 0x179AC659, block_07d1),
+    block(0x07D4, 9, 0x954B0314, block_07d4),
+    block(0x07DD, 10, 0xB49E41B7, block_07dd),
+    block(0x07E7, 3, 0x6B6877F7, block_07e7),
+    block(0x07EA, 12, 0x016155B5, block_07ea),
+    block(0x07F6, 3, 0xAF2288AD, block_07f6),
+    block(0x07F9, 3, 0x5F19E905, block_07f9),
+    block(0x07FC, 3, 0xD6A72BF9, block_07fc),
+    block(0x07FF, 5, 0xACBD367F, block_07ff),
+    block(0x0804, 3, 0x32BD831C, block_0804),
+    block(0x0807, 7, 0x4E13067D, block_0807),
+    block(0x080E, 3, 0xEE3E6529, block_080e),
+    block(0x0811, 3, 0x32BD831C, block_0811),
+    block(0x0814, 3, 0x5F4B85D1, block_0814),
+    block(0x0817, 3, 0x269ADDF6, block_0817),
+    block(0x081A, 5, 0x9791E9F0, block_081a),
+    block(0x081F, 3, 0x60234222, block_081f),
+    block(0x0822, 3, 0xEF4FAFDD, block_0822),
+    block(0x0825, 3, 0x1EEF006C, block_0825),
+    block(0x0828, 3, 0xB20D9FBB, block_0828),
+    block(0x082B, 7, 0x9C218DFE, block_082b),
+    block(0x0832, 3, 0xA159C26F, block_0832),
+    block(0x0835, 3, 0xFF92AEFA, block_0835),
+    block(0x0838, 3, 0xD18491D8, block_0838),
+    block(0x083B, 3, 0xF1A3E211, block_083b),
+    block(0x083E, 3, 0xF6C43D9B, block_083e),
+    block(0x0841, 3, 0xB535C05C, block_0841),
+    block(0x0844, 5, 0x292048FC, block_0844),
+    block(0x0849, 3, 0x62311A00, block_0849),
+    block(0x084C, 5, 0x6F35E0F7, block_084c),
+    block(0x0851, 3, 0xA684DB4D, block_0851),
+    block(0x0857, 6, 0x2D256CD4, block_0857),
+    block(0x085D, 9, 0x18A100EC, block_085d),
+    block(0x0866, 4, 0x79D51FC1, block_0866),
+    block(0x086A, 3, 0x5696DC50, block_086a),
+    block(0x086D, 5, 0xAF1864F3, block_086d),
+    block(0x0872, 3, 0x9428112C, block_0872),
+    block(0x0875, 3, 0x9C73826E, block_0875),
+    block(0x0878, 11, 0x15D4B2C5, block_0878),
+    block(0x0886, 7, 0x9DC9FC34, block_0886),
+    block(0x088D, 11, 0xAF164A52, block_088d),
+    block(0x0898, 12, 0x64941CDB, block_0898),
+    block(0x08A4, 5, 0xF84E25BB, block_08a4),
+    block(0x08A9, 5, 0x9361309A, block_08a9),
+    block(0x08AE, 5, 0x739A37E3, block_08ae),
+    block(0x08B3, 3, 0x7EB12ECD, block_08b3),
+    block(0x08B6, 3, 0x6788C556, block_08b6),
+    block(0x08B9, 3, 0xFB95746B, block_08b9),
+    block(0x08BC, 12, 0xFFB7008A, block_08bc),
+    block(0x08C8, 3, 0x99AABECB, block_08c8),
+    block(0x08CB, 3, 0xAFB3BA87, block_08cb),
+    block(0x08CE, 3, 0xFB95746B, block_08ce),
+    block(0x08D1, 7, 0x41DA3D2F, block_08d1),
+    block(0x08D8, 6, 0x35FBA1F2, block_08d8),
+    block(0x08DE, 6, 0x402E91E9, block_08de),
+    block(0x08E4, 5, 0x0112C63C, block_08e4),
+    block(0x08E9, 8, 0x099CD92D, block_08e9),
+    block(0x08F3, 5, 0xF254D078, block_08f3),
+    block(0x08F8, 6, 0xBBA39FD5, block_08f8),
+    block(0x08FE, 1, 0x4C0B39E4, block_08fe),
+    block(0x08FF, 20, 0x98648B89, block_08ff),
+    block(0x0913, 6, 0x3B3A85B6, block_0913),
+    block(0x0919, 8, 0x0ECC3AA7, block_0919),
+    block(0x0921, 8, 0xA5894C38, block_0921),
+    block(0x0929, 5, 0x077FA1C8, block_0929),
+    block(0x092E, 3, 0xB2398987, block_092e),
+    block(0x0931, 4, 0xE19C95CF, block_0931),
+    block(0x0935, 3, 0x6336CE93, block_0935),
+    block(0x0938, 5, 0x25E0A488, block_0938),
+    block(0x093D, 9, 0x7EEF4F18, block_093d),
+    block(0x0946, 2, 0xB57189CB, block_0946),
+    block(0x0948, 3, 0x7EB12ECD, block_0948),
+    block(0x094B, 4, 0x482BDF44, block_094b),
+    block(0x094F, 3, 0x57A8FFD1, block_094f),
+    block(0x0952, 6, 0xC08A019B, block_0952),
+    block(0x0958, 6, 0xC49EC711, block_0958),
+    block(0x095E, 8, 0x95F1CC48, block_095e),
+    block(0x0966, 5, 0x7CDB950A, block_0966),
+    block(0x096B, 3, 0x6336CE93, block_096b),
+    block(0x096E, 14, 0xE729C4B6, block_096e),
+    block(0x097C, 6, 0xED4C53ED, block_097c),
+    block(0x0982, 4, 0x71B49B88, block_0982),
+    block(0x0986, 2, 0xC0C99BC1, block_0986),
+    block(0x0988, 3, 0x7EB12ECD, block_0988),
+    block(0x098B, 5, 0x0C7851CB, block_098b),
+    block(0x0990, 29, 0x1DDECD1A, block_0990),
+    block(0x09AD, 4, 0x75431E0D, block_09ad),
+    block(0x09B1, 1, 0xFE0C521A, block_09b1),
+    block(0x09B2, 11, 0xD5677C45, block_09b2),
+    block(0x09BD, 6, 0x6CAFB8EC, block_09bd),
+    block(0x09C3, 2, 0x02C04EEF, block_09c3),
+    block(0x09C5, 5, 0x61D277D1, block_09c5),
+    block(0x09CA, 8, 0xEA9FCD0E, block_09ca),
+    block(0x09D2, 4, 0xFB6172FB, block_09d2),
+    block(0x09D6, 3, 0x18A5768A, block_09d6),
+    block(0x09D9, 11, 0x06A170BF, block_09d9),
+    block(0x09E4, 4, 0x89604EA9, block_09e4),
+    block(0x09E8, 6, 0xB9107B37, block_09e8),
+    block(0x09EE, 1, 0x4C0B39E4, block_09ee),
+    block(0x09EF, 3, 0x9C7C4CD2, block_09ef),
+    block(0x09F2, 7, 0xCDD34909, block_09f2),
+    block(0x09F9, 7, 0x14ED0F07, block_09f9),
+    block(0x0A00, 19, 0x16C84B70, block_0a00),
+    block(0x0A13, 5, 0x0F9534B0, block_0a13),
+    block(0x0A18, 13, 0x84AF4752, block_0a18),
+    block(0x0A25, 8, 0x6134A72F, block_0a25),
+    block(0x0A2D, 3, 0x6B6877F7, block_0a2d),
+    block(0x0A30, 3, 0xFC9B28FE, block_0a30),
+    block(0x0A33, 3, 0xF30CD03C, block_0a33),
+    block(0x0A36, 3, 0x5AC000AB, block_0a36),
+    block(0x0A39, 3, 0xFC9B28FE, block_0a39),
+    block(0x0A3C, 3, 0xF6C43D9B, block_0a3c),
+    block(0x0A3F, 3, 0xD2432B6D, block_0a3f),
+    block(0x0A42, 5, 0xE4A9063B, block_0a42),
+    block(0x0A47, 5, 0x9361309A, block_0a47),
+    block(0x0A4C, 3, 0xF6C43D9B, block_0a4c),
+    block(0x0A4F, 3, 0x97449BAC, block_0a4f),
+    block(0x0A52, 3, 0xF6C43D9B, block_0a52),
+    block(0x0A55, 3, 0xD2432B6D, block_0a55),
+    block(0x0A58, 1, 0x4C0B39E4, block_0a58),
+    block(0x0A59, 6, 0xB997363A, block_0a59),
+    block(0x0A5F, 7, 0x43E50B69, block_0a5f),
+    block(0x0A66, 6, 0x757C2C56, block_0a66),
+    block(0x0A6C, 5, 0x022AD4AC, block_0a6c),
+    block(0x0A71, 11, 0xC38A2A75, block_0a71),
+    block(0x0A7C, 4, 0x618A9FF5, block_0a7c),
+    block(0x0A80, 5, 0x7F4A2A50, block_0a80),
+    block(0x0A85, 9, 0x9A00F4B7, block_0a85),
+    block(0x0A8E, 5, 0x538E7B6C, block_0a8e),
+    block(0x0A93, 5, 0x6D8B225E, block_0a93),
+    block(0x0A98, 6, 0x8B95C5AB, block_0a98),
+    block(0x0A9E, 7, 0x1A93FECE, block_0a9e),
+    block(0x0AA5, 5, 0xFDA6EC70, block_0aa5),
+    block(0x0AAA, 1, 0x4C0B39E4, block_0aaa),
+    block(0x0AAB, 6, 0x3F5288F0, block_0aab),
+    block(0x0AB1, 5, 0xC291271D, block_0ab1),
+    block(0x0AB6, 5, 0x4237C55D, block_0ab6),
+    block(0x0ABB, 4, 0x8EF62DE7, block_0abb),
+    block(0x0ABF, 7, 0x6C92AFC4, block_0abf),
+    block(0x0AC6, 4, 0xE43A08FC, block_0ac6),
+    block(0x0ACA, 4, 0xD665AC9D, block_0aca),
+    block(0x0ACE, 1, 0x4C0B39E4, block_0ace),
+    block(0x0ACF, 8, 0x17CE4D32, block_0acf),
+    block(0x0AD7, 3, 0x31E45FFD, block_0ad7),
+    block(0x0ADA, 7, 0xCC2507E8, block_0ada),
+    block(0x0AE1, 1, 0x4C0B39E4, block_0ae1),
+    block(0x0AE2, 8, 0x4530CA45, block_0ae2),
+    block(0x0AEA, 8, 0x3DC10D1A, block_0aea),
+    block(0x0AF2, 4, 0x40291A72, block_0af2),
+    block(0x0AF6, 12, 0x5CFDF18F, block_0af6),
+    block(0x0B02, 6, 0xFE9AADD8, block_0b02),
+    block(0x0B08, 3, 0xED92BB1C, block_0b08),
+    block(0x0B0B, 3, 0x3DBD946D, block_0b0b),
+    block(0x0B0E, 3, 0x554B7613, block_0b0e),
+    block(0x0B11, 3, 0x30434A3D, block_0b11),
+    block(0x0B14, 3, 0x1357DA20, block_0b14),
+    block(0x0B17, 7, 0xC5BC1847, block_0b17),
+    block(0x0B1E, 6, 0xF2BE9036, block_0b1e),
+    block(0x0B24, 3, 0xD3215B66, block_0b24),
+    block(0x0B27, 6, 0xC1F9FA44, block_0b27),
+    block(0x0B2D, 3, 0xD3215B66, block_0b2d),
+    block(0x0B30, 3, 0x554B7613, block_0b30),
+    block(0x0B33, 6, 0xE29CF7CF, block_0b33),
+    block(0x0B39, 3, 0xD3215B66, block_0b39),
+    block(0x0B3C, 3, 0x554B7613, block_0b3c),
+    block(0x0B3F, 8, 0xFF11BCE6, block_0b3f),
+    block(0x0B47, 3, 0x1357DA20, block_0b47),
+    block(0x0B4A, 3, 0xD6A72BF9, block_0b4a),
+    block(0x0B4D, 7, 0x72603148, block_0b4d),
+    block(0x0B54, 3, 0x179AC659, block_0b54),
+    block(0x0B57, 6, 0x4C24B535, block_0b57),
+    block(0x0B5D, 3, 0xB319376F, block_0b5d),
+    block(0x0B60, 3, 0x5AC000AB, block_0b60),
+    block(0x0B63, 3, 0xF30CD03C, block_0b63),
+    block(0x0B66, 3, 0x9428112C, block_0b66),
+    block(0x0B69, 8, 0x201D76FE, block_0b69),
+    block(0x0B71, 3, 0x60234222, block_0b71),
+    block(0x0B74, 3, 0xD4EA0EC0, block_0b74),
+    block(0x0B77, 5, 0xFFB440BC, block_0b77),
+    block(0x0B7C, 3, 0x5871581D, block_0b7c),
+    block(0x0B7F, 4, 0x6AC103A9, block_0b7f),
+    block(0x0B83, 3, 0xF6C43D9B, block_0b83),
+    block(0x0B86, 3, 0xC9B79FFB, block_0b86),
+    block(0x0B89, 7, 0xFC975BEB, block_0b89),
+    block(0x0B90, 3, 0xA20D868B, block_0b90),
+    block(0x0B93, 11, 0xFE76B279, block_0b93),
+    block(0x0B9E, 8, 0xEFED8AF0, block_0b9e),
+    block(0x0BA6, 8, 0x0CE3D864, block_0ba6),
+    block(0x0BAE, 6, 0x97B6C09E, block_0bae),
+    block(0x0BB4, 3, 0xAAF3242C, block_0bb4),
+    block(0x0BB7, 6, 0xA0112D1B, block_0bb7),
+    block(0x0BBD, 6, 0xA9E7D3C6, block_0bbd),
+    block(0x0BC3, 3, 0x1357DA20, block_0bc3),
+    block(0x0BC6, 8, 0x78BDCE94, block_0bc6),
+    block(0x0BCE, 6, 0x253A285B, block_0bce),
+    block(0x0BD4, 3, 0xD3215B66, block_0bd4),
+    block(0x0BD7, 3, 0x24F4BCDE, block_0bd7),
+    block(0x0BDA, 11, 0x7CDE6B9E, block_0bda),
+    block(0x0BE5, 3, 0x17235C5D, block_0be5),
+    block(0x0BE8, 6, 0xBC13841A, block_0be8),
+    block(0x0BEE, 3, 0x5BA27A50, block_0bee),
+    block(0x0BF1, 3, 0xEF4FAFDD, block_0bf1),
+    block(0x0BF4, 3, 0xCAB57AF3, block_0bf4),
+    block(0x1424, 3, 0x5F2ED6B0, block_1424),
+    block(0x1427, 17, 0xE6953CF4, block_1427),
+    block(0x1438, 1, 0x4C0B39E4, block_1438),
+    block(0x1439, 13, 0x4DB720C3, block_1439),
+    block(0x1446, 1, 0x4C0B39E4, block_1446),
+    block(0x1474, 8, 0xD28EDC5A, block_1474),
+    block(0x147C, 2, 0xB68FCD7F, block_147c),
+    block(0x147E, 8, 0x9F330A0E, block_147e),
+    block(0x1486, 10, 0x1B23A8DA, block_1486),
+    block(0x1490, 1, 0x4C0B39E4, block_1490),
+    block(0x14CB, 1, 0xAA0BCDDE, block_14cb),
+    block(0x14CC, 11, 0xBB9E98E5, block_14cc),
+    block(0x14D7, 1, 0x4C0B39E4, block_14d7),
+    block(0x14D8, 6, 0xE71867D7, block_14d8),
+    block(0x14DE, 3, 0xEE59AADB, block_14de),
+    block(0x14E1, 9, 0x2509C590, block_14e1),
+    block(0x14EA, 5, 0xD6555D58, block_14ea),
+    block(0x14EF, 6, 0xCD66505D, block_14ef),
+    block(0x14F5, 11, 0x4221B7B8, block_14f5),
+    block(0x1500, 4, 0x2E329328, block_1500),
+    block(0x1504, 4, 0xBDDE3063, block_1504),
+    block(0x1508, 7, 0x50AB39DF, block_1508),
+    block(0x150F, 11, 0x8BBD24E4, block_150f),
+    block(0x151A, 5, 0x6590BFA7, block_151a),
+    block(0x151F, 5, 0x9883A187, block_151f),
+    block(0x1524, 3, 0x6679B939, block_1524),
+    block(0x1527, 3, 0x5E9FB34C, block_1527),
+    block(0x152A, 6, 0x4D8E52CB, block_152a),
+    block(0x1530, 8, 0x3FDFC043, block_1530),
+    block(0x1538, 5, 0xD5CB9BA2, block_1538),
+    block(0x153D, 8, 0xB227BA56, block_153d),
+    block(0x1545, 5, 0x92A06C02, block_1545),
+    block(0x154A, 9, 0x66AB4789, block_154a),
+    block(0x1554, 6, 0x8A8C5BAC, block_1554),
+    block(0x155A, 2, 0xF8BA8C31, block_155a),
+    block(0x155C, 6, 0x009F10D7, block_155c),
+    block(0x1562, 7, 0xD6FA3C2D, block_1562),
+    block(0x1569, 6, 0x9ADB3C3D, block_1569),
+    block(0x156F, 6, 0x32F07C49, block_156f),
+    block(0x1575, 4, 0x1B5205FF, block_1575),
+    block(0x1579, 8, 0x263C06D6, block_1579),
+    block(0x1581, 15, 0xE1D6B746, block_1581),
+    block(0x1590, 6, 0x24CF09D6, block_1590),
+    block(0x1596, 1, 0x4C0B39E4, block_1596),
+    block(0x1597, 7, 0x35ED1039, block_1597),
+    block(0x159E, 6, 0x5B814137, block_159e),
+    block(0x15A4, 1, 0x550B480F, block_15a4),
+    block(0x15A5, 4, 0x38ADCAA0, block_15a5),
+    block(0x15A9, 14, 0xC5BB9F72, block_15a9),
+    block(0x15B7, 6, 0x4DF4FEDC, block_15b7),
+    block(0x15BD, 1, 0x550B480F, block_15bd),
+    block(0x15BE, 3, 0xE7EA2CA9, block_15be),
+    block(0x15C1, 4, 0xBD7356CD, block_15c1),
+    block(0x15C5, 2, 0xBA7191AA, block_15c5),
+    block(0x15C7, 5, 0xB14EC2D5, block_15c7),
+    block(0x15CC, 5, 0x626ECE79, block_15cc),
+    block(0x15D1, 1, 0x4C0B39E4, block_15d1),
+    block(0x15D3, 3, 0x5F2ED6B0, block_15d3),
+    block(0x15D6, 1, 0x600B5960, block_15d6),
+    block(0x15D7, 26, 0x3F31D657, block_15d7),
+    block(0x15F1, 2, 0xE2E72BFF, block_15f1),
+    block(0x15F3, 3, 0xB2398987, block_15f3),
+    block(0x15F6, 3, 0xFACA29A9, block_15f6),
+    block(0x15F9, 5, 0x93B23460, block_15f9),
+    block(0x15FE, 1, 0x090C636B, block_15fe),
+    block(0x15FF, 5, 0x5EAF6F83, block_15ff),
+    block(0x1604, 7, 0xAE603D63, block_1604),
+    block(0x160B, 6, 0x81BC64F5, block_160b),
+    block(0x1611, 7, 0x2C4A3C58, block_1611),
+    block(0x1618, 6, 0xB2972B35, block_1618),
+    block(0x161E, 8, 0x12251923, block_161e),
+    block(0x1626, 5, 0xE2ED9A27, block_1626),
+    block(0x162B, 7, 0xEFAE6D33, block_162b),
+    block(0x1632, 7, 0xF52532B3, block_1632),
+    block(0x1639, 3, 0x70C0234D, block_1639),
+    block(0x163C, 3, 0xD48CFD59, block_163c),
+    block(0x163F, 9, 0xD822CDFD, block_163f),
+    block(0x1648, 3, 0x70C0234D, block_1648),
+    block(0x164B, 3, 0xDC8D09F1, block_164b),
+    block(0x164E, 4, 0x0675D643, block_164e),
+    block(0x1652, 15, 0xF388A6E2, block_1652),
+    block(0x1661, 2, 0x49D4195F, block_1661),
+    block(0x1663, 8, 0x9074F014, block_1663),
+    block(0x166B, 2, 0x08E4FC2D, block_166b),
+    block(0x1671, 3, 0x6336CE93, block_1671),
+    block(0x1674, 5, 0x2C619B13, block_1674),
+    block(0x1679, 12, 0xA5680E13, block_1679),
+    block(0x1685, 3, 0x450C5953, block_1685),
+    block(0x1688, 3, 0x09E9B983, block_1688),
+    block(0x168B, 4, 0x14A8A6CF, block_168b),
+    block(0x168F, 9, 0xB29CE51D, block_168f),
+    block(0x1698, 7, 0xD6B9E28D, block_1698),
+    block(0x169F, 11, 0xC4A05F2A, block_169f),
+    block(0x16AA, 11, 0x45B50779, block_16aa),
+    block(0x16B5, 2, 0xB171837F, block_16b5),
+    block(0x16B7, 4, 0x4742BF81, block_16b7),
+    block(0x16BB, 3, 0x554B7613, block_16bb),
+    block(0x16BE, 3, 0x2C20B1AF, block_16be),
+    block(0x16C1, 5, 0x16CB077F, block_16c1),
+    block(0x16C6, 3, 0x0D00BA5D, block_16c6),
+    block(0x16C9, 11, 0xB97CFCFC, block_16c9),
+    block(0x16D4, 3, 0x1357DA20, block_16d4),
+    block(0x16D7, 3, 0xD6A72BF9, block_16d7),
+    block(0x16DA, 9, 0xF835BAC4, block_16da),
+    block(0x16E3, 3, 0xBCE4C31E, block_16e3),
+    block(0x16E6, 8, 0x3BA80935, block_16e6),
+    block(0x16EE, 3, 0xDD84A4BC, block_16ee),
+    block(0x16F1, 5, 0x292048FC, block_16f1),
+    block(0x16F6, 3, 0xF6C43D9B, block_16f6),
+    block(0x16F9, 3, 0xB7B1D0A5, block_16f9),
+    block(0x16FC, 3, 0xEAA98A0C, block_16fc),
+    block(0x16FF, 6, 0xBC6A3D9A, block_16ff),
+    block(0x1705, 4, 0xF59CF2D4, block_1705),
+    block(0x1709, 5, 0x1E27DDFF, block_1709),
+    block(0x170E, 3, 0x7EB12ECD, block_170e),
+    block(0x1711, 11, 0x8D894168, block_1711),
+    block(0x171C, 5, 0xECECEB55, block_171c),
+    block(0x1721, 6, 0x46FAD951, block_1721),
+    block(0x1727, 5, 0x000C2C6D, block_1727),
+    block(0x172C, 8, 0x97BF6A68, block_172c),
+    block(0x1734, 5, 0x91612EFE, block_1734),
+    block(0x1739, 5, 0x0BB1E104, block_1739),
+    block(0x1740, 7, 0x5EA19012, block_1740),
+    block(0x1747, 7, 0xA01CC35C, block_1747),
+    block(0x174E, 5, 0xE7592D37, block_174e),
+    block(0x1753, 13, 0xBF2B249F, block_1753),
+    block(0x1760, 13, 0x96FFF3E8, block_1760),
+    block(0x176D, 8, 0x42950C52, block_176d),
+    block(0x1775, 7, 0x83F4247E, block_1775),
+    block(0x177C, 9, 0x6CE6AF93, block_177c),
+    block(0x1785, 4, 0x938FD6D6, block_1785),
+    block(0x1789, 5, 0x2A1F3128, block_1789),
+    block(0x178E, 20, 0xA34B2E53, block_178e),
+    block(0x17A2, 2, 0x14FC6860, block_17a2),
+    block(0x17A4, 6, 0xE4916300, block_17a4),
+    block(0x17AA, 5, 0x61066DA8, block_17aa),
+    block(0x17AF, 5, 0x434A232C, block_17af),
+    block(0x17C0, 7, 0xB8FE52E0, block_17c0),
+    block(0x17C7, 3, 0xAF3358F8, block_17c7),
+    block(0x17CA, 3, 0xAF2E3033, block_17ca),
+    block(0x17CD, 5, 0xF4397326, block_17cd),
+    block(0x17D2, 5, 0x1D093B88, block_17d2),
+    block(0x17D7, 5, 0xD4117DD2, block_17d7),
+    block(0x17DC, 3, 0xD6A72BF9, block_17dc),
+    block(0x17DF, 4, 0x74C5FA5B, block_17df),
+    block(0x17E3, 8, 0x9F119ECF, block_17e3),
+    block(0x17EB, 12, 0xF9D12C36, block_17eb),
+    block(0x17F7, 3, 0x554B7613, block_17f7),
+    block(0x17FA, 10, 0xD62A0543, block_17fa),
+    block(0x1804, 8, 0x4691006D, block_1804),
+    block(0x180C, 4, 0x19EEC11B, block_180c),
+    block(0x1810, 5, 0x807484AB, block_1810),
+    block(0x1815, 11, 0xDEBD84EB, block_1815),
+    block(0x1820, 8, 0x2D109259, block_1820),
+    block(0x1828, 3, 0xE6E47816, block_1828),
+    block(0x182B, 3, 0x37884622, block_182b),
+    block(0x182E, 3, 0xEB0710A4, block_182e),
+    block(0x1831, 3, 0xD455C582, block_1831),
+    block(0x1837, 3, 0x20153B6C, block_1837),
+    block(0x183A, 3, 0xE6E47816, block_183a),
+    block(0x183D, 1, 0x5D0B54A7, block_183d),
+    block(0x183E, 3, 0xAAF3242C, block_183e),
+    block(0x1841, 3, 0x0828F7C0, block_1841),
+    block(0x1844, 6, 0x48DEB244, block_1844),
+    block(0x184A, 2, 0xA298A85F, block_184a),
+    block(0x184C, 8, 0xF9E39407, block_184c),
+    block(0x1854, 2, 0xA298A85F, block_1854),
+    block(0x1856, 5, 0x7D6195D5, block_1856),
+    block(0x185B, 13, 0x2449A44D, block_185b),
+    block(0x1868, 9, 0xB2A2E1D0, block_1868),
+    block(0x1871, 8, 0xD26ECE68, block_1871),
+    block(0x1879, 11, 0x5239EA39, block_1879),
+    block(0x1884, 4, 0x3BEC172F, block_1884),
+    block(0x1888, 9, 0x41695706, block_1888),
+    block(0x1891, 4, 0x013F08A7, block_1891),
+    block(0x1898, 6, 0x7C8D9D64, block_1898),
+    block(0x189E, 11, 0xA034029B, block_189e),
+    block(0x18A9, 15, 0x221FD897, block_18a9),
+    block(0x18B8, 8, 0x4C62DAA9, block_18b8),
+    block(0x18C0, 8, 0x280A9759, block_18c0),
+    block(0x18C8, 9, 0xACF21890, block_18c8),
+    block(0x18D1, 3, 0x835C5EF2, block_18d1),
+    block(0x18D4, 8, 0xA8235CBC, block_18d4),
+    block(0x18DC, 3, 0xE7E479A9, block_18dc),
+    block(0x18DF, 8, 0x8B987E25, block_18df),
+    block(0x18E7, 8, 0x2460B1F1, block_18e7),
+    block(0x18EF, 2, 0xC0C99BC1, block_18ef),
+    block(0x18F1, 7, 0x3DBF0096, block_18f1),
+    block(0x18F8, 2, 0xC06B8AEE, block_18f8),
+    block(0x18FA, 10, 0xC231B3F4, block_18fa),
+    block(0x1904, 6, 0x3E1E3B13, block_1904),
+    block(0x190A, 3, 0xDD84A4BC, block_190a),
+    block(0x190D, 3, 0x5AAE0EDE, block_190d),
+    block(0x1910, 8, 0xA9B24583, block_1910),
+    block(0x1918, 2, 0xC0C99BC1, block_1918),
+    block(0x191A, 11, 0x71A3C7F9, block_191a),
+    block(0x1925, 6, 0xBD6861CF, block_1925),
+    block(0x192B, 6, 0x3DC61AA3, block_192b),
+    block(0x1931, 11, 0x51B6BD90, block_1931),
+    block(0x193C, 11, 0x42090F20, block_193c),
+    block(0x1947, 9, 0x1E454488, block_1947),
+    block(0x1950, 6, 0xED71698B, block_1950),
+    block(0x1956, 3, 0x6CCBB322, block_1956),
+    block(0x1959, 3, 0xAF283BAD, block_1959),
+    block(0x195C, 3, 0x6FBA6EBA, block_195c),
+    block(0x195F, 3, 0xE3A18D70, block_195f),
+    block(0x1962, 3, 0xE3D568D3, block_1962),
+    block(0x1965, 3, 0xAB7C646F, block_1965),
+    block(0x1968, 3, 0x54FA12C2, block_1968),
+    block(0x196B, 3, 0x6A8E7D0F, block_196b),
+    block(0x196E, 3, 0xD77E0F7D, block_196e),
+    block(0x1971, 8, 0x4E8AA9DC, block_1971),
+    block(0x1979, 3, 0xEAA98A0C, block_1979),
+    block(0x197C, 3, 0x6C0E977C, block_197c),
+    block(0x197F, 3, 0x0D380A29, block_197f),
+    block(0x1982, 4, 0x10FCADA7, block_1982),
+    block(0x1988, 3, 0xC20D103F, block_1988),
+    block(0x199A, 7, 0x470E3E59, block_199a),
+    block(0x19A1, 7, 0x2953FED7, block_19a1),
+    block(0x19A8, 4, 0xA79C559D, block_19a8),
+    block(0x19AC, 7, 0x69FA6489, block_19ac),
+    block(0x19B3, 11, 0x5F847DF7, block_19b3),
+    block(0x19D1, 2, 0x14FC6860, block_19d1),
+    block(0x19D3, 4, 0x550AF4AF, block_19d3),
+    block(0x19D7, 4, 0xB66FD75F, block_19d7),
+    block(0x19DC, 10, 0xCB6E1124, block_19dc),
+    block(0x19E6, 6, 0x7051003F, block_19e6),
+    block(0x19EC, 9, 0x07DDAA67, block_19ec),
+    block(0x19F5, 5, 0x1BC174F4, block_19f5),
+    block(0x19FA, 5, 0x992636FD, block_19fa),
+    block(0x19FF, 6, 0x489B7589, block_19ff),
+    block(0x1A05, 1, 0x4C0B39E4, block_1a05),
+    block(0x1A32, 8, 0x428F44B3, block_1a32),
+    block(0x1A3A, 1, 0x4C0B39E4, block_1a3a),
+    block(0x1A3B, 12, 0xCC2D1C64, block_1a3b),
+    block(0x1A47, 3, 0xC637DBE3, block_1a47),
+    block(0x1A4A, 10, 0x5EAED62D, block_1a4a),
+    block(0x1A54, 8, 0xE7B62555, block_1a54),
+    block(0x1A5C, 3, 0x04A0D9E0, block_1a5c),
+    block(0x1A5F, 9, 0xCBBB8655, block_1a5f),
+    block(0x1A68, 1, 0x4C0B39E4, block_1a68),
+    block(0x1A69, 2, 0xB68FCD7F, block_1a69),
+    block(0x1A6B, 9, 0xFC027862, block_1a6b),
+    block(0x1A74, 10, 0x0F43E9A1, block_1a74),
+    block(0x1A7E, 1, 0x4C0B39E4, block_1a7e),
+    block(0x1A7F, 3, 0x57A8FFD1, block_1a7f),
+    block(0x1A82, 2, 0xA681B28A, block_1a82),
+    block(0x1A84, 6, 0xC822E2CE, block_1a84),
+    block(0x1A8A, 1, 0x740B78DC, block_1a8a),
+    block(0x1A8B, 8, 0x73C7A21C, block_1a8b),
+];
+
+fn block_0000(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // NOP
+    cpu.cycles += 4;
+    // NOP
+    cpu.cycles += 4;
+    // NOP
+    cpu.cycles += 4;
+    // JMP $18d4
+    cpu.cycles += 10;
+    0x18D4
+}
+
+fn block_0008(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // PUSH PSW
+    cpu.cycles += 11;
+    opcodes::push_psw(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x0009;
+    }
+    // PUSH B
+    cpu.cycles += 11;
+    opcodes::push_b(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x000A;
+    }
+    // PUSH D
+    cpu.cycles += 11;
+    opcodes::push_d(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x000B;
+    }
+    // PUSH H
+    cpu.cycles += 11;
+    opcodes::push_h(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x000C;
+    }
+    // JMP $008c
+    cpu.cycles += 10;
+    0x008C
+}
+
+fn block_0010(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // PUSH PSW
+    cpu.cycles += 11;
+    opcodes::push_psw(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x0011;
+    }
+    // PUSH B
+    cpu.cycles += 11;
+    opcodes::push_b(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x0012;
+    }
+    // PUSH D
+    cpu.cycles += 11;
+    opcodes::push_d(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x0013;
+    }
+    // PUSH H
+    cpu.cycles += 11;
+    opcodes::push_h(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x0014;
+    }
+    // MVI A,#$80
+    cpu.cycles += 7;
+    cpu.a = 0x80;
+    // STA $2072
+    cpu.cycles += 13;
+    cpu.write_byte(0x2072, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x0019;
+    }
+    // LXI H,#$20c0
+    cpu.cycles += 10;
+    cpu.set_hl(0x20C0);
+    // DCR M
+    cpu.cycles += 10;
+    opcodes::dcr_m(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x001D;
+    }
+    // CALL $17cd
+    cpu.cycles += 17;
+    cpu.push(0x0020);
+    0x17CD
+}
+
+fn block_0018(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // NOP
+    cpu.cycles += 4;
+    // LXI H,#$20c0
+    cpu.cycles += 10;
+    cpu.set_hl(0x20C0);
+    // DCR M
+    cpu.cycles += 10;
+    opcodes::dcr_m(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x001D;
+    }
+    // CALL $17cd
+    cpu.cycles += 17;
+    cpu.push(0x0020);
+    0x17CD
+}
+
+fn block_0020(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // IN #$01
+    cpu.cycles += 10;
+    cpu.a = io.input(0x01);
+    // RRC
+    cpu.cycles += 4;
+    opcodes::rrc(cpu, io);
+    // JC $0067
+    cpu.cycles += 10;
+    if cpu.cc.cy {
+        return 0x0067;
+    }
+    0x0026
+}
+
+fn block_0026(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $20ea
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x20EA];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JZ $0042
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x0042;
+    }
+    0x002D
+}
+
+fn block_0028(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // NOP
+    cpu.cycles += 4;
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JZ $0042
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x0042;
+    }
+    0x002D
+}
+
+fn block_002d(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LDA $20eb
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x20EB];
+    0x0030
+}
+
+fn block_0030(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CPI #$99
+    cpu.cycles += 7;
+    cpu.cmp(0x99);
+    // JZ $003e
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x003E;
+    }
+    0x0035
+}
+
+fn block_0035(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // ADI #$01
+    cpu.cycles += 7;
+    cpu.add(0x01);
+    // DAA
+    cpu.cycles += 4;
+    opcodes::daa(cpu, io);
+    0x0038
+}
+
+fn block_0038(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // STA $20eb
+    cpu.cycles += 13;
+    cpu.write_byte(0x20EB, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x003B;
+    }
+    // CALL $1947
+    cpu.cycles += 17;
+    cpu.push(0x003E);
+    0x1947
+}
+
+fn block_003e(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // XRA A
+    cpu.cycles += 4;
+    opcodes::xra_a(cpu, io);
+    0x003F
+}
+
+fn block_003f(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // STA $20ea
+    cpu.cycles += 13;
+    cpu.write_byte(0x20EA, cpu.a);
+    0x0042
+}
+
+fn block_0042(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $20e9
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x20E9];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JZ $0082
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x0082;
+    }
+    0x0049
+}
+
+fn block_0049(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $20ef
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x20EF];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JNZ $006f
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x006F;
+    }
+    0x0050
+}
+
+fn block_0050(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $20eb
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x20EB];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JNZ $005d
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x005D;
+    }
+    0x0057
+}
+
+fn block_0057(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0abf
+    cpu.cycles += 17;
+    cpu.push(0x005A);
+    0x0ABF
+}
+
+fn block_005a(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JMP $0082
+    cpu.cycles += 10;
+    0x0082
+}
+
+fn block_005d(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $2093
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2093];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JNZ $0082
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x0082;
+    }
+    0x0064
+}
+
+fn block_0064(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JMP $0765
+    cpu.cycles += 10;
+    0x0765
+}
+
+fn block_0067(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI A,#$01
+    cpu.cycles += 7;
+    cpu.a = 0x01;
+    // STA $20ea
+    cpu.cycles += 13;
+    cpu.write_byte(0x20EA, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x006C;
+    }
+    // JMP $003f
+    cpu.cycles += 10;
+    0x003F
+}
+
+fn block_006f(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $1740
+    cpu.cycles += 17;
+    cpu.push(0x0072);
+    0x1740
+}
+
+fn block_0072(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LDA $2032
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2032];
+    // STA $2080
+    cpu.cycles += 13;
+    cpu.write_byte(0x2080, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x0078;
+    }
+    // CALL $0100
+    cpu.cycles += 17;
+    cpu.push(0x007B);
+    0x0100
+}
+
+fn block_007b(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0248
+    cpu.cycles += 17;
+    cpu.push(0x007E);
+    0x0248
+}
+
+fn block_007e(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0913
+    cpu.cycles += 17;
+    cpu.push(0x0081);
+    0x0913
+}
+
+fn block_0081(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // NOP
+    cpu.cycles += 4;
+    0x0082
+}
+
+fn block_0082(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // POP H
+    cpu.cycles += 10;
+    opcodes::pop_h(cpu, io);
+    // POP D
+    cpu.cycles += 10;
+    opcodes::pop_d(cpu, io);
+    // POP B
+    cpu.cycles += 10;
+    opcodes::pop_b(cpu, io);
+    // POP PSW
+    cpu.cycles += 10;
+    opcodes::pop_psw(cpu, io);
+    // EI
+    cpu.cycles += 4;
+    opcodes::ei(cpu, io);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_008c(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // XRA A
+    cpu.cycles += 4;
+    opcodes::xra_a(cpu, io);
+    // STA $2072
+    cpu.cycles += 13;
+    cpu.write_byte(0x2072, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x0090;
+    }
+    // LDA $20e9
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x20E9];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JZ $0082
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x0082;
+    }
+    0x0097
+}
+
+fn block_0097(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $20ef
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x20EF];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JNZ $00a5
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x00A5;
+    }
+    0x009E
+}
+
+fn block_009e(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $20c1
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x20C1];
+    // RRC
+    cpu.cycles += 4;
+    opcodes::rrc(cpu, io);
+    // JNC $0082
+    cpu.cycles += 10;
+    if !cpu.cc.cy {
+        return 0x0082;
+    }
+    0x00A5
+}
+
+fn block_00a5(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$2020
+    cpu.cycles += 10;
+    cpu.set_hl(0x2020);
+    // CALL $024b
+    cpu.cycles += 17;
+    cpu.push(0x00AB);
+    0x024B
+}
+
+fn block_00ab(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0141
+    cpu.cycles += 17;
+    cpu.push(0x00AE);
+    0x0141
+}
+
+fn block_00ae(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JMP $0082
+    cpu.cycles += 10;
+    0x0082
+}
+
+fn block_00b1(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0886
+    cpu.cycles += 17;
+    cpu.push(0x00B4);
+    0x0886
+}
+
+fn block_00b4(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // PUSH H
+    cpu.cycles += 11;
+    opcodes::push_h(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x00B5;
+    }
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV H,M
+    cpu.cycles += 7;
+    opcodes::mov_h_m(cpu, io);
+    // MOV L,A
+    cpu.cycles += 5;
+    opcodes::mov_l_a(cpu, io);
+    // SHLD $2009
+    cpu.cycles += 16;
+    cpu.write_byte(0x2009, cpu.l);
+    cpu.write_byte(0x200A, cpu.h);
+    if !cpu.written.is_empty() {
+        return 0x00BC;
+    }
+    // SHLD $200b
+    cpu.cycles += 16;
+    cpu.write_byte(0x200B, cpu.l);
+    cpu.write_byte(0x200C, cpu.h);
+    if !cpu.written.is_empty() {
+        return 0x00BF;
+    }
+    // POP H
+    cpu.cycles += 10;
+    opcodes::pop_h(cpu, io);
+    // DCX H
+    cpu.cycles += 5;
+    opcodes::dcx_h(cpu, io);
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // CPI #$03
+    cpu.cycles += 7;
+    cpu.cmp(0x03);
+    // JNZ $00c8
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x00C8;
+    }
+    0x00C7
+}
+
+fn block_00c7(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // DCR A
+    cpu.cycles += 5;
+    opcodes::dcr_a(cpu, io);
+    0x00C8
+}
+
+fn block_00c8(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // STA $2008
+    cpu.cycles += 13;
+    cpu.write_byte(0x2008, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x00CB;
+    }
+    // CPI #$fe
+    cpu.cycles += 7;
+    cpu.cmp(0xFE);
+    // MVI A,#$00
+    cpu.cycles += 7;
+    cpu.a = 0x00;
+    // JNZ $00d3
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x00D3;
+    }
+    0x00D2
+}
+
+fn block_00d2(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // INR A
+    cpu.cycles += 5;
+    opcodes::inr_a(cpu, io);
+    0x00D3
+}
+
+fn block_00d3(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // STA $200d
+    cpu.cycles += 13;
+    cpu.write_byte(0x200D, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x00D6;
+    }
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_00d7(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI A,#$02
+    cpu.cycles += 7;
+    cpu.a = 0x02;
+    // STA $21fb
+    cpu.cycles += 13;
+    cpu.write_byte(0x21FB, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x00DC;
+    }
+    // STA $22fb
+    cpu.cycles += 13;
+    cpu.write_byte(0x22FB, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x00DF;
+    }
+    // JMP $08e4
+    cpu.cycles += 10;
+    0x08E4
+}
+
+fn block_0100(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LXI H,#$2002
+    cpu.cycles += 10;
+    cpu.set_hl(0x2002);
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JNZ $1538
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x1538;
+    }
+    0x0108
+}
+
+fn block_0108(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // PUSH H
+    cpu.cycles += 11;
+    opcodes::push_h(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x0109;
+    }
+    // LDA $2006
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2006];
+    // MOV L,A
+    cpu.cycles += 5;
+    opcodes::mov_l_a(cpu, io);
+    // LDA $2067
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2067];
+    // MOV H,A
+    cpu.cycles += 5;
+    opcodes::mov_h_a(cpu, io);
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // POP H
+    cpu.cycles += 10;
+    opcodes::pop_h(cpu, io);
+    // JZ $0136
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x0136;
+    }
+    0x0117
+}
+
+fn block_0117(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV B,M
+    cpu.cycles += 7;
+    opcodes::mov_b_m(cpu, io);
+    // ANI #$fe
+    cpu.cycles += 7;
+    cpu.and(0xFE);
+    // RLC
+    cpu.cycles += 4;
+    opcodes::rlc(cpu, io);
+    // RLC
+    cpu.cycles += 4;
+    opcodes::rlc(cpu, io);
+    // RLC
+    cpu.cycles += 4;
+    opcodes::rlc(cpu, io);
+    // MOV E,A
+    cpu.cycles += 5;
+    opcodes::mov_e_a(cpu, io);
+    // MVI D,#$00
+    cpu.cycles += 7;
+    cpu.d = 0x00;
+    // LXI H,#$1c00
+    cpu.cycles += 10;
+    cpu.set_hl(0x1C00);
+    // DAD D
+    cpu.cycles += 10;
+    opcodes::dad_d(cpu, io);
+    // XCHG
+    cpu.cycles += 4;
+    opcodes::xchg(cpu, io);
+    // MOV A,B
+    cpu.cycles += 5;
+    opcodes::mov_a_b(cpu, io);
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // CNZ $013b
+    cpu.cycles += 11;
+    if !cpu.cc.z {
+        cpu.cycles += 6;
+        cpu.push(0x012E);
+        return 0x013B;
+    }
+    0x012E
+}
+
+fn block_012e(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LDHD $200b
+    cpu.cycles += 16;
+    cpu.l = cpu.mem[0x200B];
+    cpu.h = cpu.mem[0x200C];
+    // MVI B,#$10
+    cpu.cycles += 7;
+    cpu.b = 0x10;
+    // CALL $15d3
+    cpu.cycles += 17;
+    cpu.push(0x0136);
+    0x15D3
+}
+
+fn block_0136(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // XRA A
+    cpu.cycles += 4;
+    opcodes::xra_a(cpu, io);
+    // STA $2000
+    cpu.cycles += 13;
+    cpu.write_byte(0x2000, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x013A;
+    }
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_013b(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LXI H,#$0030
+    cpu.cycles += 10;
+    cpu.set_hl(0x0030);
+    // DAD D
+    cpu.cycles += 10;
+    opcodes::dad_d(cpu, io);
+    // XCHG
+    cpu.cycles += 4;
+    opcodes::xchg(cpu, io);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_0141(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $2068
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2068];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // RZ
+    cpu.cycles += 5;
+    if cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x0146
+}
+
+fn block_0146(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $2000
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2000];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // RNZ
+    cpu.cycles += 5;
+    if !cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x014B
+}
+
+fn block_014b(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $2067
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2067];
+    // MOV H,A
+    cpu.cycles += 5;
+    opcodes::mov_h_a(cpu, io);
+    // LDA $2006
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2006];
+    // MVI D,#$02
+    cpu.cycles += 7;
+    cpu.d = 0x02;
+    0x0154
+}
+
+fn block_0154(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // INR A
+    cpu.cycles += 5;
+    opcodes::inr_a(cpu, io);
+    // CPI #$37
+    cpu.cycles += 7;
+    cpu.cmp(0x37);
+    // CZ $01a1
+    cpu.cycles += 11;
+    if cpu.cc.z {
+        cpu.cycles += 6;
+        cpu.push(0x015A);
+        return 0x01A1;
+    }
+    0x015A
+}
+
+fn block_015a(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV L,A
+    cpu.cycles += 5;
+    opcodes::mov_l_a(cpu, io);
+    // MOV B,M
+    cpu.cycles += 7;
+    opcodes::mov_b_m(cpu, io);
+    // DCR B
+    cpu.cycles += 5;
+    opcodes::dcr_b(cpu, io);
+    // JNZ $0154
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x0154;
+    }
+    0x0160
+}
+
+fn block_0160(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // STA $2006
+    cpu.cycles += 13;
+    cpu.write_byte(0x2006, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x0163;
+    }
+    // CALL $017a
+    cpu.cycles += 17;
+    cpu.push(0x0166);
+    0x017A
+}
+
+fn block_0166(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV H,C
+    cpu.cycles += 5;
+    opcodes::mov_h_c(cpu, io);
+    // SHLD $200b
+    cpu.cycles += 16;
+    cpu.write_byte(0x200B, cpu.l);
+    cpu.write_byte(0x200C, cpu.h);
+    if !cpu.written.is_empty() {
+        return 0x016A;
+    }
+    // MOV A,L
+    cpu.cycles += 5;
+    opcodes::mov_a_l(cpu, io);
+    // CPI #$28
+    cpu.cycles += 7;
+    cpu.cmp(0x28);
+    // JC $1971
+    cpu.cycles += 10;
+    if cpu.cc.cy {
+        return 0x1971;
+    }
+    0x0170
+}
+
+fn block_0170(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV A,D
+    cpu.cycles += 5;
+    opcodes::mov_a_d(cpu, io);
+    // STA $2004
+    cpu.cycles += 13;
+    cpu.write_byte(0x2004, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x0174;
+    }
+    // MVI A,#$01
+    cpu.cycles += 7;
+    cpu.a = 0x01;
+    // STA $2000
+    cpu.cycles += 13;
+    cpu.write_byte(0x2000, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x0179;
+    }
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_017a(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MVI D,#$00
+    cpu.cycles += 7;
+    cpu.d = 0x00;
+    // MOV A,L
+    cpu.cycles += 5;
+    opcodes::mov_a_l(cpu, io);
+    // LXI H,#$2009
+    cpu.cycles += 10;
+    cpu.set_hl(0x2009);
+    // MOV B,M
+    cpu.cycles += 7;
+    opcodes::mov_b_m(cpu, io);
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV C,M
+    cpu.cycles += 7;
+    opcodes::mov_c_m(cpu, io);
+    0x0183
+}
+
+fn block_0183(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CPI #$0b
+    cpu.cycles += 7;
+    cpu.cmp(0x0B);
+    // JM $0194
+    cpu.cycles += 10;
+    if cpu.cc.s {
+        return 0x0194;
+    }
+    0x0188
+}
+
+fn block_0188(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // SBI #$0b
+    cpu.cycles += 7;
+    cpu.sub_cy(0x0B);
+    // MOV E,A
+    cpu.cycles += 5;
+    opcodes::mov_e_a(cpu, io);
+    // MOV A,B
+    cpu.cycles += 5;
+    opcodes::mov_a_b(cpu, io);
+    // ADI #$10
+    cpu.cycles += 7;
+    cpu.add(0x10);
+    // MOV B,A
+    cpu.cycles += 5;
+    opcodes::mov_b_a(cpu, io);
+    // MOV A,E
+    cpu.cycles += 5;
+    opcodes::mov_a_e(cpu, io);
+    // INR D
+    cpu.cycles += 5;
+    opcodes::inr_d(cpu, io);
+    // JMP $0183
+    cpu.cycles += 10;
+    0x0183
+}
+
+fn block_0194(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV L,B
+    cpu.cycles += 5;
+    opcodes::mov_l_b(cpu, io);
+    0x0195
+}
+
+fn block_0195(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // RZ
+    cpu.cycles += 5;
+    if cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x0197
+}
+
+fn block_0197(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV E,A
+    cpu.cycles += 5;
+    opcodes::mov_e_a(cpu, io);
+    // MOV A,C
+    cpu.cycles += 5;
+    opcodes::mov_a_c(cpu, io);
+    // ADI #$10
+    cpu.cycles += 7;
+    cpu.add(0x10);
+    // MOV C,A
+    cpu.cycles += 5;
+    opcodes::mov_c_a(cpu, io);
+    // MOV A,E
+    cpu.cycles += 5;
+    opcodes::mov_a_e(cpu, io);
+    // DCR A
+    cpu.cycles += 5;
+    opcodes::dcr_a(cpu, io);
+    // JMP $0195
+    cpu.cycles += 10;
+    0x0195
+}
+
+fn block_01a1(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // DCR D
+    cpu.cycles += 5;
+    opcodes::dcr_d(cpu, io);
+    // JZ $01cd
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x01CD;
+    }
+    0x01A5
+}
+
+fn block_01a5(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LXI H,#$2006
+    cpu.cycles += 10;
+    cpu.set_hl(0x2006);
+    // MVI M,#$00
+    cpu.cycles += 10;
+    cpu.write_byte(cpu.hl() as usize, 0x00);
+    if !cpu.written.is_empty() {
+        return 0x01AA;
+    }
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV C,M
+    cpu.cycles += 7;
+    opcodes::mov_c_m(cpu, io);
+    // MVI M,#$00
+    cpu.cycles += 10;
+    cpu.write_byte(cpu.hl() as usize, 0x00);
+    if !cpu.written.is_empty() {
+        return 0x01AE;
+    }
+    // CALL $01d9
+    cpu.cycles += 17;
+    cpu.push(0x01B1);
+    0x01D9
+}
+
+fn block_01b1(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LXI H,#$2005
+    cpu.cycles += 10;
+    cpu.set_hl(0x2005);
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // INR A
+    cpu.cycles += 5;
+    opcodes::inr_a(cpu, io);
+    // ANI #$01
+    cpu.cycles += 7;
+    cpu.and(0x01);
+    // MOV M,A
+    cpu.cycles += 7;
+    opcodes::mov_m_a(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x01B9;
+    }
+    // XRA A
+    cpu.cycles += 4;
+    opcodes::xra_a(cpu, io);
+    // LXI H,#$2067
+    cpu.cycles += 10;
+    cpu.set_hl(0x2067);
+    // MOV H,M
+    cpu.cycles += 7;
+    opcodes::mov_h_m(cpu, io);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_01c0(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$2100
+    cpu.cycles += 10;
+    cpu.set_hl(0x2100);
+    0x01C3
+}
+
+fn block_01c3(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI B,#$37
+    cpu.cycles += 7;
+    cpu.b = 0x37;
+    0x01C5
+}
+
+fn block_01c5(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MVI M,#$01
+    cpu.cycles += 10;
+    cpu.write_byte(cpu.hl() as usize, 0x01);
+    if !cpu.written.is_empty() {
+        return 0x01C7;
+    }
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // DCR B
+    cpu.cycles += 5;
+    opcodes::dcr_b(cpu, io);
+    // JNZ $01c5
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x01C5;
+    }
+    0x01CC
+}
+
+fn block_01cc(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_01cd(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // POP H
+    cpu.cycles += 10;
+    opcodes::pop_h(cpu, io);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_01cf(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI A,#$01
+    cpu.cycles += 7;
+    cpu.a = 0x01;
+    // MVI B,#$e0
+    cpu.cycles += 7;
+    cpu.b = 0xE0;
+    // LXI H,#$2402
+    cpu.cycles += 10;
+    cpu.set_hl(0x2402);
+    // JMP $14cc
+    cpu.cycles += 10;
+    0x14CC
+}
+
+fn block_01d9(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV B,M
+    cpu.cycles += 7;
+    opcodes::mov_b_m(cpu, io);
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV A,C
+    cpu.cycles += 5;
+    opcodes::mov_a_c(cpu, io);
+    // ADD M
+    cpu.cycles += 7;
+    opcodes::add_m(cpu, io);
+    // MOV M,A
+    cpu.cycles += 7;
+    opcodes::mov_m_a(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x01DF;
+    }
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV A,B
+    cpu.cycles += 5;
+    opcodes::mov_a_b(cpu, io);
+    // ADD M
+    cpu.cycles += 7;
+    opcodes::add_m(cpu, io);
+    // MOV M,A
+    cpu.cycles += 7;
+    opcodes::mov_m_a(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x01E3;
+    }
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_01e4(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI B,#$c0
+    cpu.cycles += 7;
+    cpu.b = 0xC0;
+    0x01E6
+}
+
+fn block_01e6(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI D,#$1b00
+    cpu.cycles += 10;
+    cpu.set_de(0x1B00);
+    // LXI H,#$2000
+    cpu.cycles += 10;
+    cpu.set_hl(0x2000);
+    // JMP $1a32
+    cpu.cycles += 10;
+    0x1A32
+}
+
+fn block_01ef(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$2142
+    cpu.cycles += 10;
+    cpu.set_hl(0x2142);
+    // JMP $01f8
+    cpu.cycles += 10;
+    0x01F8
+}
+
+fn block_01f5(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$2242
+    cpu.cycles += 10;
+    cpu.set_hl(0x2242);
+    0x01F8
+}
+
+fn block_01f8(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI C,#$04
+    cpu.cycles += 7;
+    cpu.c = 0x04;
+    // LXI D,#$1d20
+    cpu.cycles += 10;
+    cpu.set_de(0x1D20);
+    0x01FD
+}
+
+fn block_01fd(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // PUSH D
+    cpu.cycles += 11;
+    opcodes::push_d(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x01FE;
+    }
+    // MVI B,#$2c
+    cpu.cycles += 7;
+    cpu.b = 0x2C;
+    // CALL $1a32
+    cpu.cycles += 17;
+    cpu.push(0x0203);
+    0x1A32
+}
+
+fn block_0203(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // POP D
+    cpu.cycles += 10;
+    opcodes::pop_d(cpu, io);
+    // DCR C
+    cpu.cycles += 5;
+    opcodes::dcr_c(cpu, io);
+    // JNZ $01fd
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x01FD;
+    }
+    0x0208
+}
+
+fn block_0208(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_0209(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI A,#$01
+    cpu.cycles += 7;
+    cpu.a = 0x01;
+    // JMP $021b
+    cpu.cycles += 10;
+    0x021B
+}
+
+fn block_020e(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI A,#$01
+    cpu.cycles += 7;
+    cpu.a = 0x01;
+    // JMP $0214
+    cpu.cycles += 10;
+    0x0214
+}
+
+fn block_0213(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // XRA A
+    cpu.cycles += 4;
+    opcodes::xra_a(cpu, io);
+    0x0214
+}
+
+fn block_0214(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI D,#$2242
+    cpu.cycles += 10;
+    cpu.set_de(0x2242);
+    // JMP $021e
+    cpu.cycles += 10;
+    0x021E
+}
+
+fn block_021a(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // XRA A
+    cpu.cycles += 4;
+    opcodes::xra_a(cpu, io);
+    0x021B
+}
+
+fn block_021b(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI D,#$2142
+    cpu.cycles += 10;
+    cpu.set_de(0x2142);
+    0x021E
+}
+
+fn block_021e(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // STA $2081
+    cpu.cycles += 13;
+    cpu.write_byte(0x2081, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x0221;
+    }
+    // LXI B,#$1602
+    cpu.cycles += 10;
+    cpu.set_bc(0x1602);
+    // LXI H,#$2806
+    cpu.cycles += 10;
+    cpu.set_hl(0x2806);
+    // MVI A,#$04
+    cpu.cycles += 7;
+    cpu.a = 0x04;
+    0x0229
+}
+
+fn block_0229(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // PUSH PSW
+    cpu.cycles += 11;
+    opcodes::push_psw(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x022A;
+    }
+    // PUSH B
+    cpu.cycles += 11;
+    opcodes::push_b(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x022B;
+    }
+    // LDA $2081
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2081];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JNZ $0242
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x0242;
+    }
+    0x0232
+}
+
+fn block_0232(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $1a69
+    cpu.cycles += 17;
+    cpu.push(0x0235);
+    0x1A69
+}
+
+fn block_0235(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // POP B
+    cpu.cycles += 10;
+    opcodes::pop_b(cpu, io);
+    // POP PSW
+    cpu.cycles += 10;
+    opcodes::pop_psw(cpu, io);
+    // DCR A
+    cpu.cycles += 5;
+    opcodes::dcr_a(cpu, io);
+    // RZ
+    cpu.cycles += 5;
+    if cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x0239
+}
+
+fn block_0239(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // PUSH D
+    cpu.cycles += 11;
+    opcodes::push_d(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x023A;
+    }
+    // LXI D,#$02e0
+    cpu.cycles += 10;
+    cpu.set_de(0x02E0);
+    // DAD D
+    cpu.cycles += 10;
+    opcodes::dad_d(cpu, io);
+    // POP D
+    cpu.cycles += 10;
+    opcodes::pop_d(cpu, io);
+    // JMP $0229
+    cpu.cycles += 10;
+    0x0229
+}
+
+fn block_0242(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $147c
+    cpu.cycles += 17;
+    cpu.push(0x0245);
+    0x147C
+}
+
+fn block_0245(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JMP $0235
+    cpu.cycles += 10;
+    0x0235
+}
+
+fn block_0248(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$2010
+    cpu.cycles += 10;
+    cpu.set_hl(0x2010);
+    0x024B
+}
+
+fn block_024b(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // CPI #$ff
+    cpu.cycles += 7;
+    cpu.cmp(0xFF);
+    // RZ
+    cpu.cycles += 5;
+    if cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x024F
+}
+
+fn block_024f(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CPI #$fe
+    cpu.cycles += 7;
+    cpu.cmp(0xFE);
+    // JZ $0281
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x0281;
+    }
+    0x0254
+}
+
+fn block_0254(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV B,M
+    cpu.cycles += 7;
+    opcodes::mov_b_m(cpu, io);
+    // MOV C,A
+    cpu.cycles += 5;
+    opcodes::mov_c_a(cpu, io);
+    // ORA B
+    cpu.cycles += 4;
+    opcodes::ora_b(cpu, io);
+    // MOV A,C
+    cpu.cycles += 5;
+    opcodes::mov_a_c(cpu, io);
+    // JNZ $0277
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x0277;
+    }
+    0x025C
+}
+
+fn block_025c(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JNZ $0288
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x0288;
+    }
+    0x0262
+}
+
+fn block_0262(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV E,M
+    cpu.cycles += 7;
+    opcodes::mov_e_m(cpu, io);
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV D,M
+    cpu.cycles += 7;
+    opcodes::mov_d_m(cpu, io);
+    // PUSH H
+    cpu.cycles += 11;
+    opcodes::push_h(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x0267;
+    }
+    // XCHG
+    cpu.cycles += 4;
+    opcodes::xchg(cpu, io);
+    // PUSH H
+    cpu.cycles += 11;
+    opcodes::push_h(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x0269;
+    }
+    // LXI H,#$026f
+    cpu.cycles += 10;
+    cpu.set_hl(0x026F);
+    // XTHL
+    cpu.cycles += 18;
+    opcodes::xthl(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x026D;
+    }
+    // PUSH D
+    cpu.cycles += 11;
+    opcodes::push_d(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x026E;
+    }
+    // PCHL
+    cpu.cycles += 5;
+    cpu.hl() as usize
+}
+
+fn block_0277(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // DCR B
+    cpu.cycles += 5;
+    opcodes::dcr_b(cpu, io);
+    // INR B
+    cpu.cycles += 5;
+    opcodes::inr_b(cpu, io);
+    // JNZ $027d
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x027D;
+    }
+    0x027C
+}
+
+fn block_027c(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // DCR A
+    cpu.cycles += 5;
+    opcodes::dcr_a(cpu, io);
+    0x027D
+}
+
+fn block_027d(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // DCR B
+    cpu.cycles += 5;
+    opcodes::dcr_b(cpu, io);
+    // MOV M,B
+    cpu.cycles += 7;
+    opcodes::mov_m_b(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x027F;
+    }
+    // DCX H
+    cpu.cycles += 5;
+    opcodes::dcx_h(cpu, io);
+    // MOV M,A
+    cpu.cycles += 7;
+    opcodes::mov_m_a(cpu, io);
+    0x0281
+}
+
+fn block_0281(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LXI D,#$0010
+    cpu.cycles += 10;
+    cpu.set_de(0x0010);
+    // DAD D
+    cpu.cycles += 10;
+    opcodes::dad_d(cpu, io);
+    // JMP $024b
+    cpu.cycles += 10;
+    0x024B
+}
+
+fn block_0288(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // DCR M
+    cpu.cycles += 10;
+    opcodes::dcr_m(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x0289;
+    }
+    // DCX H
+    cpu.cycles += 5;
+    opcodes::dcx_h(cpu, io);
+    // DCX H
+    cpu.cycles += 5;
+    opcodes::dcx_h(cpu, io);
+    // JMP $0281
+    cpu.cycles += 10;
+    0x0281
+}
+
+fn block_02ed(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $2067
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2067];
+    // PUSH PSW
+    cpu.cycles += 11;
+    opcodes::push_psw(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x02F1;
+    }
+    // RRC
+    cpu.cycles += 4;
+    opcodes::rrc(cpu, io);
+    // JC $0332
+    cpu.cycles += 10;
+    if cpu.cc.cy {
+        return 0x0332;
+    }
+    0x02F5
+}
+
+fn block_02f5(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $020e
+    cpu.cycles += 17;
+    cpu.push(0x02F8);
+    0x020E
+}
+
+fn block_02f8(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0878
+    cpu.cycles += 17;
+    cpu.push(0x02FB);
+    0x0878
+}
+
+fn block_02fb(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV M,E
+    cpu.cycles += 7;
+    opcodes::mov_m_e(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x02FC;
+    }
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV M,D
+    cpu.cycles += 7;
+    opcodes::mov_m_d(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x02FE;
+    }
+    // DCX H
+    cpu.cycles += 5;
+    opcodes::dcx_h(cpu, io);
+    // DCX H
+    cpu.cycles += 5;
+    opcodes::dcx_h(cpu, io);
+    // MOV M,B
+    cpu.cycles += 7;
+    opcodes::mov_m_b(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x0301;
+    }
+    // NOP
+    cpu.cycles += 4;
+    // CALL $01e4
+    cpu.cycles += 17;
+    cpu.push(0x0305);
+    0x01E4
+}
+
+fn block_0305(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // POP PSW
+    cpu.cycles += 10;
+    opcodes::pop_psw(cpu, io);
+    // RRC
+    cpu.cycles += 4;
+    opcodes::rrc(cpu, io);
+    // MVI A,#$21
+    cpu.cycles += 7;
+    cpu.a = 0x21;
+    // MVI B,#$00
+    cpu.cycles += 7;
+    cpu.b = 0x00;
+    // JNC $0312
+    cpu.cycles += 10;
+    if !cpu.cc.cy {
+        return 0x0312;
+    }
+    0x030E
+}
+
+fn block_030e(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI B,#$20
+    cpu.cycles += 7;
+    cpu.b = 0x20;
+    // MVI A,#$22
+    cpu.cycles += 7;
+    cpu.a = 0x22;
+    0x0312
+}
+
+fn block_0312(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // STA $2067
+    cpu.cycles += 13;
+    cpu.write_byte(0x2067, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x0315;
+    }
+    // CALL $0ab6
+    cpu.cycles += 17;
+    cpu.push(0x0318);
+    0x0AB6
+}
+
+fn block_0318(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // XRA A
+    cpu.cycles += 4;
+    opcodes::xra_a(cpu, io);
+    // STA $2011
+    cpu.cycles += 13;
+    cpu.write_byte(0x2011, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x031C;
+    }
+    // MOV A,B
+    cpu.cycles += 5;
+    opcodes::mov_a_b(cpu, io);
+    // OUT #$05
+    cpu.cycles += 10;
+    io.output(0x05, cpu.a);
+    // INR A
+    cpu.cycles += 5;
+    opcodes::inr_a(cpu, io);
+    // STA $2098
+    cpu.cycles += 13;
+    cpu.write_byte(0x2098, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x0323;
+    }
+    // CALL $09d6
+    cpu.cycles += 17;
+    cpu.push(0x0326);
+    0x09D6
+}
+
+fn block_0326(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $1a7f
+    cpu.cycles += 17;
+    cpu.push(0x0329);
+    0x1A7F
+}
+
+fn block_0329(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JMP $07f9
+    cpu.cycles += 10;
+    0x07F9
+}
+
+fn block_0332(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0209
+    cpu.cycles += 17;
+    cpu.push(0x0335);
+    0x0209
+}
+
+fn block_0335(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JMP $02f8
+    cpu.cycles += 10;
+    0x02F8
+}
+
+fn block_0707(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI B,#$fe
+    cpu.cycles += 7;
+    cpu.b = 0xFE;
+    // JMP $19dc
+    cpu.cycles += 10;
+    0x19DC
+}
+
+fn block_0765(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MVI A,#$01
+    cpu.cycles += 7;
+    cpu.a = 0x01;
+    // STA $2093
+    cpu.cycles += 13;
+    cpu.write_byte(0x2093, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x076A;
+    }
+    // LXI SP,#$2400
+    cpu.cycles += 10;
+    cpu.sp = 0x2400;
+    // EI
+    cpu.cycles += 4;
+    opcodes::ei(cpu, io);
+    // CALL $1979
+    cpu.cycles += 17;
+    cpu.push(0x0771);
+    0x1979
+}
+
+fn block_0771(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $09d6
+    cpu.cycles += 17;
+    cpu.push(0x0774);
+    0x09D6
+}
+
+fn block_0774(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$3013
+    cpu.cycles += 10;
+    cpu.set_hl(0x3013);
+    // LXI D,#$1ff3
+    cpu.cycles += 10;
+    cpu.set_de(0x1FF3);
+    // MVI C,#$04
+    cpu.cycles += 7;
+    cpu.c = 0x04;
+    // CALL $08f3
+    cpu.cycles += 17;
+    cpu.push(0x077F);
+    0x08F3
+}
+
+fn block_077f(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $20eb
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x20EB];
+    // DCR A
+    cpu.cycles += 5;
+    opcodes::dcr_a(cpu, io);
+    // LXI H,#$2810
+    cpu.cycles += 10;
+    cpu.set_hl(0x2810);
+    // MVI C,#$14
+    cpu.cycles += 7;
+    cpu.c = 0x14;
+    // JNZ $0857
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x0857;
+    }
+    0x078B
+}
+
+fn block_078b(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI D,#$1acf
+    cpu.cycles += 10;
+    cpu.set_de(0x1ACF);
+    // CALL $08f3
+    cpu.cycles += 17;
+    cpu.push(0x0791);
+    0x08F3
+}
+
+fn block_0791(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // IN #$01
+    cpu.cycles += 10;
+    cpu.a = io.input(0x01);
+    // ANI #$04
+    cpu.cycles += 7;
+    cpu.and(0x04);
+    // JZ $077f
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x077F;
+    }
+    0x0798
+}
+
+fn block_0798(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MVI B,#$99
+    cpu.cycles += 7;
+    cpu.b = 0x99;
+    // XRA A
+    cpu.cycles += 4;
+    opcodes::xra_a(cpu, io);
+    0x079B
+}
+
+fn block_079b(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // STA $20ce
+    cpu.cycles += 13;
+    cpu.write_byte(0x20CE, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x079E;
+    }
+    // LDA $20eb
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x20EB];
+    // ADD B
+    cpu.cycles += 4;
+    opcodes::add_b(cpu, io);
+    // DAA
+    cpu.cycles += 4;
+    opcodes::daa(cpu, io);
+    // STA $20eb
+    cpu.cycles += 13;
+    cpu.write_byte(0x20EB, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x07A6;
+    }
+    // CALL $1947
+    cpu.cycles += 17;
+    cpu.push(0x07A9);
+    0x1947
+}
+
+fn block_07a9(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$0000
+    cpu.cycles += 10;
+    cpu.set_hl(0x0000);
+    // SHLD $20f8
+    cpu.cycles += 16;
+    cpu.write_byte(0x20F8, cpu.l);
+    cpu.write_byte(0x20F9, cpu.h);
+    if !cpu.written.is_empty() {
+        return 0x07AF;
+    }
+    // SHLD $20fc
+    cpu.cycles += 16;
+    cpu.write_byte(0x20FC, cpu.l);
+    cpu.write_byte(0x20FD, cpu.h);
+    if !cpu.written.is_empty() {
+        return 0x07B2;
+    }
+    // CALL $1925
+    cpu.cycles += 17;
+    cpu.push(0x07B5);
+    0x1925
+}
+
+fn block_07b5(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $192b
+    cpu.cycles += 17;
+    cpu.push(0x07B8);
+    0x192B
+}
+
+fn block_07b8(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $19d7
+    cpu.cycles += 17;
+    cpu.push(0x07BB);
+    0x19D7
+}
+
+fn block_07bb(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LXI H,#$0101
+    cpu.cycles += 10;
+    cpu.set_hl(0x0101);
+    // MOV A,H
+    cpu.cycles += 5;
+    opcodes::mov_a_h(cpu, io);
+    // STA $20ef
+    cpu.cycles += 13;
+    cpu.write_byte(0x20EF, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x07C2;
+    }
+    // SHLD $20e7
+    cpu.cycles += 16;
+    cpu.write_byte(0x20E7, cpu.l);
+    cpu.write_byte(0x20E8, cpu.h);
+    if !cpu.written.is_empty() {
+        return 0x07C5;
+    }
+    // SHLD $20e5
+    cpu.cycles += 16;
+    cpu.write_byte(0x20E5, cpu.l);
+    cpu.write_byte(0x20E6, cpu.h);
+    if !cpu.written.is_empty() {
+        return 0x07C8;
+    }
+    // CALL $1956
+    cpu.cycles += 17;
+    cpu.push(0x07CB);
+    0x1956
+}
+
+fn block_07cb(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $01ef
+    cpu.cycles += 17;
+    cpu.push(0x07CE);
+    0x01EF
+}
+
+fn block_07ce(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $01f5
+    cpu.cycles += 17;
+    cpu.push(0x07D1);
+    0x01F5
+}
+
+fn block_07d1(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $08d1
+    cpu.cycles += 17;
+    cpu.push(0x07D4);
+    0x08D1
+}
+
+fn block_07d4(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // STA $21ff
+    cpu.cycles += 13;
+    cpu.write_byte(0x21FF, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x07D7;
+    }
+    // STA $22ff
+    cpu.cycles += 13;
+    cpu.write_byte(0x22FF, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x07DA;
+    }
+    // CALL $00d7
+    cpu.cycles += 17;
+    cpu.push(0x07DD);
+    0x00D7
+}
+
+fn block_07dd(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // XRA A
+    cpu.cycles += 4;
+    opcodes::xra_a(cpu, io);
+    // STA $21fe
+    cpu.cycles += 13;
+    cpu.write_byte(0x21FE, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x07E1;
+    }
+    // STA $22fe
+    cpu.cycles += 13;
+    cpu.write_byte(0x22FE, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x07E4;
+    }
+    // CALL $01c0
+    cpu.cycles += 17;
+    cpu.push(0x07E7);
+    0x01C0
+}
+
+fn block_07e7(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $1904
+    cpu.cycles += 17;
+    cpu.push(0x07EA);
+    0x1904
+}
+
+fn block_07ea(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$3878
+    cpu.cycles += 10;
+    cpu.set_hl(0x3878);
+    // SHLD $21fc
+    cpu.cycles += 16;
+    cpu.write_byte(0x21FC, cpu.l);
+    cpu.write_byte(0x21FD, cpu.h);
+    if !cpu.written.is_empty() {
+        return 0x07F0;
+    }
+    // SHLD $22fc
+    cpu.cycles += 16;
+    cpu.write_byte(0x22FC, cpu.l);
+    cpu.write_byte(0x22FD, cpu.h);
+    if !cpu.written.is_empty() {
+        return 0x07F3;
+    }
+    // CALL $01e4
+    cpu.cycles += 17;
+    cpu.push(0x07F6);
+    0x01E4
+}
+
+fn block_07f6(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $1a7f
+    cpu.cycles += 17;
+    cpu.push(0x07F9);
+    0x1A7F
+}
+
+fn block_07f9(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $088d
+    cpu.cycles += 17;
+    cpu.push(0x07FC);
+    0x088D
+}
+
+fn block_07fc(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $09d6
+    cpu.cycles += 17;
+    cpu.push(0x07FF);
+    0x09D6
+}
+
+fn block_07ff(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // NOP
+    cpu.cycles += 4;
+    // XRA A
+    cpu.cycles += 4;
+    opcodes::xra_a(cpu, io);
+    // STA $20c1
+    cpu.cycles += 13;
+    cpu.write_byte(0x20C1, cpu.a);
+    0x0804
+}
+
+fn block_0804(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $01cf
+    cpu.cycles += 17;
+    cpu.push(0x0807);
+    0x01CF
+}
+
+fn block_0807(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $2067
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2067];
+    // RRC
+    cpu.cycles += 4;
+    opcodes::rrc(cpu, io);
+    // JC $0872
+    cpu.cycles += 10;
+    if cpu.cc.cy {
+        return 0x0872;
+    }
+    0x080E
+}
+
+fn block_080e(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0213
+    cpu.cycles += 17;
+    cpu.push(0x0811);
+    0x0213
+}
+
+fn block_0811(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $01cf
+    cpu.cycles += 17;
+    cpu.push(0x0814);
+    0x01CF
+}
+
+fn block_0814(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $00b1
+    cpu.cycles += 17;
+    cpu.push(0x0817);
+    0x00B1
+}
+
+fn block_0817(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $19d1
+    cpu.cycles += 17;
+    cpu.push(0x081A);
+    0x19D1
+}
+
+fn block_081a(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI B,#$20
+    cpu.cycles += 7;
+    cpu.b = 0x20;
+    // CALL $18fa
+    cpu.cycles += 17;
+    cpu.push(0x081F);
+    0x18FA
+}
+
+fn block_081f(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $1618
+    cpu.cycles += 17;
+    cpu.push(0x0822);
+    0x1618
+}
+
+fn block_0822(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $190a
+    cpu.cycles += 17;
+    cpu.push(0x0825);
+    0x190A
+}
+
+fn block_0825(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $15f3
+    cpu.cycles += 17;
+    cpu.push(0x0828);
+    0x15F3
+}
+
+fn block_0828(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0988
+    cpu.cycles += 17;
+    cpu.push(0x082B);
+    0x0988
+}
+
+fn block_082b(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $2082
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2082];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JZ $09ef
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x09EF;
+    }
+    0x0832
+}
+
+fn block_0832(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $170e
+    cpu.cycles += 17;
+    cpu.push(0x0835);
+    0x170E
+}
+
+fn block_0835(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0935
+    cpu.cycles += 17;
+    cpu.push(0x0838);
+    0x0935
+}
+
+fn block_0838(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $08d8
+    cpu.cycles += 17;
+    cpu.push(0x083B);
+    0x08D8
+}
+
+fn block_083b(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $172c
+    cpu.cycles += 17;
+    cpu.push(0x083E);
+    0x172C
+}
+
+fn block_083e(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0a59
+    cpu.cycles += 17;
+    cpu.push(0x0841);
+    0x0A59
+}
+
+fn block_0841(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JZ $0849
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x0849;
+    }
+    0x0844
+}
+
+fn block_0844(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI B,#$04
+    cpu.cycles += 7;
+    cpu.b = 0x04;
+    // CALL $18fa
+    cpu.cycles += 17;
+    cpu.push(0x0849);
+    0x18FA
+}
+
+fn block_0849(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $1775
+    cpu.cycles += 17;
+    cpu.push(0x084C);
+    0x1775
+}
+
+fn block_084c(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // OUT #$06
+    cpu.cycles += 10;
+    io.output(0x06, cpu.a);
+    // CALL $1804
+    cpu.cycles += 17;
+    cpu.push(0x0851);
+    0x1804
+}
+
+fn block_0851(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JMP $081f
+    cpu.cycles += 10;
+    0x081F
+}
+
+fn block_0857(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI D,#$1aba
+    cpu.cycles += 10;
+    cpu.set_de(0x1ABA);
+    // CALL $08f3
+    cpu.cycles += 17;
+    cpu.push(0x085D);
+    0x08F3
+}
+
+fn block_085d(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MVI B,#$98
+    cpu.cycles += 7;
+    cpu.b = 0x98;
+    // IN #$01
+    cpu.cycles += 10;
+    cpu.a = io.input(0x01);
+    // RRC
+    cpu.cycles += 4;
+    opcodes::rrc(cpu, io);
+    // RRC
+    cpu.cycles += 4;
+    opcodes::rrc(cpu, io);
+    // JC $086d
+    cpu.cycles += 10;
+    if cpu.cc.cy {
+        return 0x086D;
+    }
+    0x0866
+}
+
+fn block_0866(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // RRC
+    cpu.cycles += 4;
+    opcodes::rrc(cpu, io);
+    // JC $0798
+    cpu.cycles += 10;
+    if cpu.cc.cy {
+        return 0x0798;
+    }
+    0x086A
+}
+
+fn block_086a(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JMP $077f
+    cpu.cycles += 10;
+    0x077F
+}
+
+fn block_086d(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI A,#$01
+    cpu.cycles += 7;
+    cpu.a = 0x01;
+    // JMP $079b
+    cpu.cycles += 10;
+    0x079B
+}
+
+fn block_0872(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $021a
+    cpu.cycles += 17;
+    cpu.push(0x0875);
+    0x021A
+}
+
+fn block_0875(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JMP $0814
+    cpu.cycles += 10;
+    0x0814
+}
+
+fn block_0878(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $2008
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2008];
+    // MOV B,A
+    cpu.cycles += 5;
+    opcodes::mov_b_a(cpu, io);
+    // LDHD $2009
+    cpu.cycles += 16;
+    cpu.l = cpu.mem[0x2009];
+    cpu.h = cpu.mem[0x200A];
+    // XCHG
+    cpu.cycles += 4;
+    opcodes::xchg(cpu, io);
+    // JMP $0886
+    cpu.cycles += 10;
+    0x0886
+}
+
+fn block_0886(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $2067
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2067];
+    // MOV H,A
+    cpu.cycles += 5;
+    opcodes::mov_h_a(cpu, io);
+    // MVI L,#$fc
+    cpu.cycles += 7;
+    cpu.l = 0xFC;
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_088d(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$2b11
+    cpu.cycles += 10;
+    cpu.set_hl(0x2B11);
+    // LXI D,#$1b70
+    cpu.cycles += 10;
+    cpu.set_de(0x1B70);
+    // MVI C,#$0e
+    cpu.cycles += 7;
+    cpu.c = 0x0E;
+    // CALL $08f3
+    cpu.cycles += 17;
+    cpu.push(0x0898);
+    0x08F3
+}
+
+fn block_0898(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $2067
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2067];
+    // RRC
+    cpu.cycles += 4;
+    opcodes::rrc(cpu, io);
+    // MVI A,#$1c
+    cpu.cycles += 7;
+    cpu.a = 0x1C;
+    // LXI H,#$3711
+    cpu.cycles += 10;
+    cpu.set_hl(0x3711);
+    // CNC $08ff
+    cpu.cycles += 11;
+    if !cpu.cc.cy {
+        cpu.cycles += 6;
+        cpu.push(0x08A4);
+        return 0x08FF;
+    }
+    0x08A4
+}
+
+fn block_08a4(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI A,#$b0
+    cpu.cycles += 7;
+    cpu.a = 0xB0;
+    // STA $20c0
+    cpu.cycles += 13;
+    cpu.write_byte(0x20C0, cpu.a);
+    0x08A9
+}
+
+fn block_08a9(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $20c0
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x20C0];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // RZ
+    cpu.cycles += 5;
+    if cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x08AE
+}
+
+fn block_08ae(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // ANI #$04
+    cpu.cycles += 7;
+    cpu.and(0x04);
+    // JNZ $08bc
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x08BC;
+    }
+    0x08B3
+}
+
+fn block_08b3(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $09ca
+    cpu.cycles += 17;
+    cpu.push(0x08B6);
+    0x09CA
+}
+
+fn block_08b6(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $1931
+    cpu.cycles += 17;
+    cpu.push(0x08B9);
+    0x1931
+}
+
+fn block_08b9(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JMP $08a9
+    cpu.cycles += 10;
+    0x08A9
+}
+
+fn block_08bc(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MVI B,#$20
+    cpu.cycles += 7;
+    cpu.b = 0x20;
+    // LXI H,#$271c
+    cpu.cycles += 10;
+    cpu.set_hl(0x271C);
+    // LDA $2067
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2067];
+    // RRC
+    cpu.cycles += 4;
+    opcodes::rrc(cpu, io);
+    // JC $08cb
+    cpu.cycles += 10;
+    if cpu.cc.cy {
+        return 0x08CB;
+    }
+    0x08C8
+}
+
+fn block_08c8(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$391c
+    cpu.cycles += 10;
+    cpu.set_hl(0x391C);
+    0x08CB
+}
+
+fn block_08cb(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $14cb
+    cpu.cycles += 17;
+    cpu.push(0x08CE);
+    0x14CB
+}
+
+fn block_08ce(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JMP $08a9
+    cpu.cycles += 10;
+    0x08A9
+}
+
+fn block_08d1(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // IN #$02
+    cpu.cycles += 10;
+    cpu.a = io.input(0x02);
+    // ANI #$03
+    cpu.cycles += 7;
+    cpu.and(0x03);
+    // ADI #$03
+    cpu.cycles += 7;
+    cpu.add(0x03);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_08d8(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LDA $2082
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2082];
+    // CPI #$09
+    cpu.cycles += 7;
+    cpu.cmp(0x09);
+    // RNC
+    cpu.cycles += 5;
+    if !cpu.cc.cy {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x08DE
+}
+
+fn block_08de(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI A,#$fb
+    cpu.cycles += 7;
+    cpu.a = 0xFB;
+    // STA $207e
+    cpu.cycles += 13;
+    cpu.write_byte(0x207E, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x08E3;
+    }
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_08e4(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $20ce
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x20CE];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // RNZ
+    cpu.cycles += 5;
+    if !cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x08E9
+}
+
+fn block_08e9(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$391c
+    cpu.cycles += 10;
+    cpu.set_hl(0x391C);
+    // MVI B,#$20
+    cpu.cycles += 7;
+    cpu.b = 0x20;
+    // JMP $14cb
+    cpu.cycles += 10;
+    0x14CB
+}
+
+fn block_08f3(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDAX D
+    cpu.cycles += 7;
+    opcodes::ldax_d(cpu, io);
+    // PUSH D
+    cpu.cycles += 11;
+    opcodes::push_d(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x08F5;
+    }
+    // CALL $08ff
+    cpu.cycles += 17;
+    cpu.push(0x08F8);
+    0x08FF
+}
+
+fn block_08f8(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // POP D
+    cpu.cycles += 10;
+    opcodes::pop_d(cpu, io);
+    // INX D
+    cpu.cycles += 5;
+    opcodes::inx_d(cpu, io);
+    // DCR C
+    cpu.cycles += 5;
+    opcodes::dcr_c(cpu, io);
+    // JNZ $08f3
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x08F3;
+    }
+    0x08FE
+}
+
+fn block_08fe(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_08ff(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LXI D,#$1e00
+    cpu.cycles += 10;
+    cpu.set_de(0x1E00);
+    // PUSH H
+    cpu.cycles += 11;
+    opcodes::push_h(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x0903;
+    }
+    // MVI H,#$00
+    cpu.cycles += 7;
+    cpu.h = 0x00;
+    // MOV L,A
+    cpu.cycles += 5;
+    opcodes::mov_l_a(cpu, io);
+    // DAD H
+    cpu.cycles += 10;
+    opcodes::dad_h(cpu, io);
+    // DAD H
+    cpu.cycles += 10;
+    opcodes::dad_h(cpu, io);
+    // DAD H
+    cpu.cycles += 10;
+    opcodes::dad_h(cpu, io);
+    // DAD D
+    cpu.cycles += 10;
+    opcodes::dad_d(cpu, io);
+    // XCHG
+    cpu.cycles += 4;
+    opcodes::xchg(cpu, io);
+    // POP H
+    cpu.cycles += 10;
+    opcodes::pop_h(cpu, io);
+    // MVI B,#$08
+    cpu.cycles += 7;
+    cpu.b = 0x08;
+    // OUT #$06
+    cpu.cycles += 10;
+    io.output(0x06, cpu.a);
+    // JMP $1439
+    cpu.cycles += 10;
+    0x1439
+}
+
+fn block_0913(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LDA $2009
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2009];
+    // CPI #$78
+    cpu.cycles += 7;
+    cpu.cmp(0x78);
+    // RNC
+    cpu.cycles += 5;
+    if !cpu.cc.cy {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x0919
+}
+
+fn block_0919(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDHD $2091
+    cpu.cycles += 16;
+    cpu.l = cpu.mem[0x2091];
+    cpu.h = cpu.mem[0x2092];
+    // MOV A,L
+    cpu.cycles += 5;
+    opcodes::mov_a_l(cpu, io);
+    // ORA H
+    cpu.cycles += 4;
+    opcodes::ora_h(cpu, io);
+    // JNZ $0929
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x0929;
+    }
+    0x0921
+}
+
+fn block_0921(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$0600
+    cpu.cycles += 10;
+    cpu.set_hl(0x0600);
+    // MVI A,#$01
+    cpu.cycles += 7;
+    cpu.a = 0x01;
+    // STA $2083
+    cpu.cycles += 13;
+    cpu.write_byte(0x2083, cpu.a);
+    0x0929
+}
+
+fn block_0929(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // DCX H
+    cpu.cycles += 5;
+    opcodes::dcx_h(cpu, io);
+    // SHLD $2091
+    cpu.cycles += 16;
+    cpu.write_byte(0x2091, cpu.l);
+    cpu.write_byte(0x2092, cpu.h);
+    if !cpu.written.is_empty() {
+        return 0x092D;
+    }
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_092e(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $1611
+    cpu.cycles += 17;
+    cpu.push(0x0931);
+    0x1611
+}
+
+fn block_0931(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MVI L,#$ff
+    cpu.cycles += 7;
+    cpu.l = 0xFF;
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_0935(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $1910
+    cpu.cycles += 17;
+    cpu.push(0x0938);
+    0x1910
+}
+
+fn block_0938(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // DCX H
+    cpu.cycles += 5;
+    opcodes::dcx_h(cpu, io);
+    // DCX H
+    cpu.cycles += 5;
+    opcodes::dcx_h(cpu, io);
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // RZ
+    cpu.cycles += 5;
+    if cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x093D
+}
+
+fn block_093d(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MVI B,#$15
+    cpu.cycles += 7;
+    cpu.b = 0x15;
+    // IN #$02
+    cpu.cycles += 10;
+    cpu.a = io.input(0x02);
+    // ANI #$08
+    cpu.cycles += 7;
+    cpu.and(0x08);
+    // JZ $0948
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x0948;
+    }
+    0x0946
+}
+
+fn block_0946(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI B,#$10
+    cpu.cycles += 7;
+    cpu.b = 0x10;
+    0x0948
+}
+
+fn block_0948(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $09ca
+    cpu.cycles += 17;
+    cpu.push(0x094B);
+    0x09CA
+}
+
+fn block_094b(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // CMP B
+    cpu.cycles += 4;
+    opcodes::cmp_b(cpu, io);
+    // RC
+    cpu.cycles += 5;
+    if cpu.cc.cy {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x094F
+}
+
+fn block_094f(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $092e
+    cpu.cycles += 17;
+    cpu.push(0x0952);
+    0x092E
+}
+
+fn block_0952(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // INR M
+    cpu.cycles += 10;
+    opcodes::inr_m(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x0953;
+    }
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // PUSH PSW
+    cpu.cycles += 11;
+    opcodes::push_psw(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x0955;
+    }
+    // LXI H,#$2501
+    cpu.cycles += 10;
+    cpu.set_hl(0x2501);
+    0x0958
+}
+
+fn block_0958(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // INR H
+    cpu.cycles += 5;
+    opcodes::inr_h(cpu, io);
+    // INR H
+    cpu.cycles += 5;
+    opcodes::inr_h(cpu, io);
+    // DCR A
+    cpu.cycles += 5;
+    opcodes::dcr_a(cpu, io);
+    // JNZ $0958
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x0958;
+    }
+    0x095E
+}
+
+fn block_095e(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI B,#$10
+    cpu.cycles += 7;
+    cpu.b = 0x10;
+    // LXI D,#$1c60
+    cpu.cycles += 10;
+    cpu.set_de(0x1C60);
+    // CALL $1439
+    cpu.cycles += 17;
+    cpu.push(0x0966);
+    0x1439
+}
+
+fn block_0966(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // POP PSW
+    cpu.cycles += 10;
+    opcodes::pop_psw(cpu, io);
+    // INR A
+    cpu.cycles += 5;
+    opcodes::inr_a(cpu, io);
+    // CALL $1a8b
+    cpu.cycles += 17;
+    cpu.push(0x096B);
+    0x1A8B
+}
+
+fn block_096b(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $1910
+    cpu.cycles += 17;
+    cpu.push(0x096E);
+    0x1910
+}
+
+fn block_096e(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // DCX H
+    cpu.cycles += 5;
+    opcodes::dcx_h(cpu, io);
+    // DCX H
+    cpu.cycles += 5;
+    opcodes::dcx_h(cpu, io);
+    // MVI M,#$00
+    cpu.cycles += 10;
+    cpu.write_byte(cpu.hl() as usize, 0x00);
+    if !cpu.written.is_empty() {
+        return 0x0972;
+    }
+    // MVI A,#$ff
+    cpu.cycles += 7;
+    cpu.a = 0xFF;
+    // STA $2099
+    cpu.cycles += 13;
+    cpu.write_byte(0x2099, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x0977;
+    }
+    // MVI B,#$10
+    cpu.cycles += 7;
+    cpu.b = 0x10;
+    // JMP $18fa
+    cpu.cycles += 10;
+    0x18FA
+}
+
+fn block_097c(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$1da0
+    cpu.cycles += 10;
+    cpu.set_hl(0x1DA0);
+    // CPI #$02
+    cpu.cycles += 7;
+    cpu.cmp(0x02);
+    // RC
+    cpu.cycles += 5;
+    if cpu.cc.cy {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x0982
+}
+
+fn block_0982(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // CPI #$04
+    cpu.cycles += 7;
+    cpu.cmp(0x04);
+    // RC
+    cpu.cycles += 5;
+    if cpu.cc.cy {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x0986
+}
+
+fn block_0986(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_0988(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $09ca
+    cpu.cycles += 17;
+    cpu.push(0x098B);
+    0x09CA
+}
+
+fn block_098b(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $20f1
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x20F1];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // RZ
+    cpu.cycles += 5;
+    if cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x0990
+}
+
+fn block_0990(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // XRA A
+    cpu.cycles += 4;
+    opcodes::xra_a(cpu, io);
+    // STA $20f1
+    cpu.cycles += 13;
+    cpu.write_byte(0x20F1, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x0994;
+    }
+    // PUSH H
+    cpu.cycles += 11;
+    opcodes::push_h(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x0995;
+    }
+    // LDHD $20f2
+    cpu.cycles += 16;
+    cpu.l = cpu.mem[0x20F2];
+    cpu.h = cpu.mem[0x20F3];
+    // XCHG
+    cpu.cycles += 4;
+    opcodes::xchg(cpu, io);
+    // POP H
+    cpu.cycles += 10;
+    opcodes::pop_h(cpu, io);
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // ADD E
+    cpu.cycles += 4;
+    opcodes::add_e(cpu, io);
+    // DAA
+    cpu.cycles += 4;
+    opcodes::daa(cpu, io);
+    // MOV M,A
+    cpu.cycles += 7;
+    opcodes::mov_m_a(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x099E;
+    }
+    // MOV E,A
+    cpu.cycles += 5;
+    opcodes::mov_e_a(cpu, io);
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // ADC D
+    cpu.cycles += 4;
+    opcodes::adc_d(cpu, io);
+    // DAA
+    cpu.cycles += 4;
+    opcodes::daa(cpu, io);
+    // MOV M,A
+    cpu.cycles += 7;
+    opcodes::mov_m_a(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x09A4;
+    }
+    // MOV D,A
+    cpu.cycles += 5;
+    opcodes::mov_d_a(cpu, io);
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV H,M
+    cpu.cycles += 7;
+    opcodes::mov_h_m(cpu, io);
+    // MOV L,A
+    cpu.cycles += 5;
+    opcodes::mov_l_a(cpu, io);
+    // JMP $09ad
+    cpu.cycles += 10;
+    0x09AD
+}
+
+fn block_09ad(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV A,D
+    cpu.cycles += 5;
+    opcodes::mov_a_d(cpu, io);
+    // CALL $09b2
+    cpu.cycles += 17;
+    cpu.push(0x09B1);
+    0x09B2
+}
+
+fn block_09b1(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV A,E
+    cpu.cycles += 5;
+    opcodes::mov_a_e(cpu, io);
+    0x09B2
+}
+
+fn block_09b2(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // PUSH D
+    cpu.cycles += 11;
+    opcodes::push_d(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x09B3;
+    }
+    // PUSH PSW
+    cpu.cycles += 11;
+    opcodes::push_psw(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x09B4;
+    }
+    // RRC
+    cpu.cycles += 4;
+    opcodes::rrc(cpu, io);
+    // RRC
+    cpu.cycles += 4;
+    opcodes::rrc(cpu, io);
+    // RRC
+    cpu.cycles += 4;
+    opcodes::rrc(cpu, io);
+    // RRC
+    cpu.cycles += 4;
+    opcodes::rrc(cpu, io);
+    // ANI #$0f
+    cpu.cycles += 7;
+    cpu.and(0x0F);
+    // CALL $09c5
+    cpu.cycles += 17;
+    cpu.push(0x09BD);
+    0x09C5
+}
+
+fn block_09bd(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // POP PSW
+    cpu.cycles += 10;
+    opcodes::pop_psw(cpu, io);
+    // ANI #$0f
+    cpu.cycles += 7;
+    cpu.and(0x0F);
+    // CALL $09c5
+    cpu.cycles += 17;
+    cpu.push(0x09C3);
+    0x09C5
+}
+
+fn block_09c3(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // POP D
+    cpu.cycles += 10;
+    opcodes::pop_d(cpu, io);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_09c5(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // ADI #$1a
+    cpu.cycles += 7;
+    cpu.add(0x1A);
+    // JMP $08ff
+    cpu.cycles += 10;
+    0x08FF
+}
+
+fn block_09ca(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $2067
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2067];
+    // RRC
+    cpu.cycles += 4;
+    opcodes::rrc(cpu, io);
+    // LXI H,#$20f8
+    cpu.cycles += 10;
+    cpu.set_hl(0x20F8);
+    // RC
+    cpu.cycles += 5;
+    if cpu.cc.cy {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x09D2
+}
+
+fn block_09d2(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$20fc
+    cpu.cycles += 10;
+    cpu.set_hl(0x20FC);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_09d6(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$2402
+    cpu.cycles += 10;
+    cpu.set_hl(0x2402);
+    0x09D9
+}
+
+fn block_09d9(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MVI M,#$00
+    cpu.cycles += 10;
+    cpu.write_byte(cpu.hl() as usize, 0x00);
+    if !cpu.written.is_empty() {
+        return 0x09DB;
+    }
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV A,L
+    cpu.cycles += 5;
+    opcodes::mov_a_l(cpu, io);
+    // ANI #$1f
+    cpu.cycles += 7;
+    cpu.and(0x1F);
+    // CPI #$1c
+    cpu.cycles += 7;
+    cpu.cmp(0x1C);
+    // JC $09e8
+    cpu.cycles += 10;
+    if cpu.cc.cy {
+        return 0x09E8;
+    }
+    0x09E4
+}
+
+fn block_09e4(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LXI D,#$0006
+    cpu.cycles += 10;
+    cpu.set_de(0x0006);
+    // DAD D
+    cpu.cycles += 10;
+    opcodes::dad_d(cpu, io);
+    0x09E8
+}
+
+fn block_09e8(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV A,H
+    cpu.cycles += 5;
+    opcodes::mov_a_h(cpu, io);
+    // CPI #$40
+    cpu.cycles += 7;
+    cpu.cmp(0x40);
+    // JC $09d9
+    cpu.cycles += 10;
+    if cpu.cc.cy {
+        return 0x09D9;
+    }
+    0x09EE
+}
+
+fn block_09ee(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_09ef(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0a3c
+    cpu.cycles += 17;
+    cpu.push(0x09F2);
+    0x0A3C
+}
+
+fn block_09f2(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // XRA A
+    cpu.cycles += 4;
+    opcodes::xra_a(cpu, io);
+    // STA $20e9
+    cpu.cycles += 13;
+    cpu.write_byte(0x20E9, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x09F6;
+    }
+    // CALL $09d6
+    cpu.cycles += 17;
+    cpu.push(0x09F9);
+    0x09D6
+}
+
+fn block_09f9(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $2067
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2067];
+    // PUSH PSW
+    cpu.cycles += 11;
+    opcodes::push_psw(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x09FD;
+    }
+    // CALL $01e4
+    cpu.cycles += 17;
+    cpu.push(0x0A00);
+    0x01E4
+}
+
+fn block_0a00(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // POP PSW
+    cpu.cycles += 10;
+    opcodes::pop_psw(cpu, io);
+    // STA $2067
+    cpu.cycles += 13;
+    cpu.write_byte(0x2067, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x0A04;
+    }
+    // LDA $2067
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2067];
+    // MOV H,A
+    cpu.cycles += 5;
+    opcodes::mov_h_a(cpu, io);
+    // PUSH H
+    cpu.cycles += 11;
+    opcodes::push_h(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x0A09;
+    }
+    // MVI L,#$fe
+    cpu.cycles += 7;
+    cpu.l = 0xFE;
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // ANI #$07
+    cpu.cycles += 7;
+    cpu.and(0x07);
+    // INR A
+    cpu.cycles += 5;
+    opcodes::inr_a(cpu, io);
+    // MOV M,A
+    cpu.cycles += 7;
+    opcodes::mov_m_a(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x0A10;
+    }
+    // LXI H,#$1da2
+    cpu.cycles += 10;
+    cpu.set_hl(0x1DA2);
+    0x0A13
+}
+
+fn block_0a13(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // DCR A
+    cpu.cycles += 5;
+    opcodes::dcr_a(cpu, io);
+    // JNZ $0a13
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x0A13;
+    }
+    0x0A18
+}
+
+fn block_0a18(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // POP H
+    cpu.cycles += 10;
+    opcodes::pop_h(cpu, io);
+    // MVI L,#$fc
+    cpu.cycles += 7;
+    cpu.l = 0xFC;
+    // MOV M,A
+    cpu.cycles += 7;
+    opcodes::mov_m_a(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x0A1D;
+    }
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MVI M,#$38
+    cpu.cycles += 10;
+    cpu.write_byte(cpu.hl() as usize, 0x38);
+    if !cpu.written.is_empty() {
+        return 0x0A20;
+    }
+    // MOV A,H
+    cpu.cycles += 5;
+    opcodes::mov_a_h(cpu, io);
+    // RRC
+    cpu.cycles += 4;
+    opcodes::rrc(cpu, io);
+    // JC $0a33
+    cpu.cycles += 10;
+    if cpu.cc.cy {
+        return 0x0A33;
+    }
+    0x0A25
+}
+
+fn block_0a25(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI A,#$21
+    cpu.cycles += 7;
+    cpu.a = 0x21;
+    // STA $2098
+    cpu.cycles += 13;
+    cpu.write_byte(0x2098, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x0A2A;
+    }
+    // CALL $01f5
+    cpu.cycles += 17;
+    cpu.push(0x0A2D);
+    0x01F5
+}
+
+fn block_0a2d(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $1904
+    cpu.cycles += 17;
+    cpu.push(0x0A30);
+    0x1904
+}
+
+fn block_0a30(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JMP $0804
+    cpu.cycles += 10;
+    0x0804
+}
+
+fn block_0a33(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $01ef
+    cpu.cycles += 17;
+    cpu.push(0x0A36);
+    0x01EF
+}
+
+fn block_0a36(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $01c0
+    cpu.cycles += 17;
+    cpu.push(0x0A39);
+    0x01C0
+}
+
+fn block_0a39(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JMP $0804
+    cpu.cycles += 10;
+    0x0804
+}
+
+fn block_0a3c(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0a59
+    cpu.cycles += 17;
+    cpu.push(0x0A3F);
+    0x0A59
+}
+
+fn block_0a3f(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JNZ $0a52
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x0A52;
+    }
+    0x0A42
+}
+
+fn block_0a42(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI A,#$30
+    cpu.cycles += 7;
+    cpu.a = 0x30;
+    // STA $20c0
+    cpu.cycles += 13;
+    cpu.write_byte(0x20C0, cpu.a);
+    0x0A47
+}
+
+fn block_0a47(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $20c0
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x20C0];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // RZ
+    cpu.cycles += 5;
+    if cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x0A4C
+}
+
+fn block_0a4c(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0a59
+    cpu.cycles += 17;
+    cpu.push(0x0A4F);
+    0x0A59
+}
+
+fn block_0a4f(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JZ $0a47
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x0A47;
+    }
+    0x0A52
+}
+
+fn block_0a52(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0a59
+    cpu.cycles += 17;
+    cpu.push(0x0A55);
+    0x0A59
+}
+
+fn block_0a55(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JNZ $0a52
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x0A52;
+    }
+    0x0A58
+}
+
+fn block_0a58(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_0a59(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LDA $2015
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2015];
+    // CPI #$ff
+    cpu.cycles += 7;
+    cpu.cmp(0xFF);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_0a5f(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $20ef
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x20EF];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JZ $0a7c
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x0A7C;
+    }
+    0x0A66
+}
+
+fn block_0a66(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV C,B
+    cpu.cycles += 5;
+    opcodes::mov_c_b(cpu, io);
+    // MVI B,#$08
+    cpu.cycles += 7;
+    cpu.b = 0x08;
+    // CALL $18fa
+    cpu.cycles += 17;
+    cpu.push(0x0A6C);
+    0x18FA
+}
+
+fn block_0a6c(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV B,C
+    cpu.cycles += 5;
+    opcodes::mov_b_c(cpu, io);
+    // MOV A,B
+    cpu.cycles += 5;
+    opcodes::mov_a_b(cpu, io);
+    // CALL $097c
+    cpu.cycles += 17;
+    cpu.push(0x0A71);
+    0x097C
+}
+
+fn block_0a71(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // LXI H,#$20f3
+    cpu.cycles += 10;
+    cpu.set_hl(0x20F3);
+    // MVI M,#$00
+    cpu.cycles += 10;
+    cpu.write_byte(cpu.hl() as usize, 0x00);
+    if !cpu.written.is_empty() {
+        return 0x0A77;
+    }
+    // DCX H
+    cpu.cycles += 5;
+    opcodes::dcx_h(cpu, io);
+    // MOV M,A
+    cpu.cycles += 7;
+    opcodes::mov_m_a(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x0A79;
+    }
+    // DCX H
+    cpu.cycles += 5;
+    opcodes::dcx_h(cpu, io);
+    // MVI M,#$01
+    cpu.cycles += 10;
+    cpu.write_byte(cpu.hl() as usize, 0x01);
+    0x0A7C
+}
+
+fn block_0a7c(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$2062
+    cpu.cycles += 10;
+    cpu.set_hl(0x2062);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_0a80(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI A,#$02
+    cpu.cycles += 7;
+    cpu.a = 0x02;
+    // STA $20c1
+    cpu.cycles += 13;
+    cpu.write_byte(0x20C1, cpu.a);
+    0x0A85
+}
+
+fn block_0a85(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // OUT #$06
+    cpu.cycles += 10;
+    io.output(0x06, cpu.a);
+    // LDA $20cb
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x20CB];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JZ $0a85
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x0A85;
+    }
+    0x0A8E
+}
+
+fn block_0a8e(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // XRA A
+    cpu.cycles += 4;
+    opcodes::xra_a(cpu, io);
+    // STA $20c1
+    cpu.cycles += 13;
+    cpu.write_byte(0x20C1, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x0A92;
+    }
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_0a93(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // PUSH D
+    cpu.cycles += 11;
+    opcodes::push_d(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x0A94;
+    }
+    // LDAX D
+    cpu.cycles += 7;
+    opcodes::ldax_d(cpu, io);
+    // CALL $08ff
+    cpu.cycles += 17;
+    cpu.push(0x0A98);
+    0x08FF
+}
+
+fn block_0a98(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // POP D
+    cpu.cycles += 10;
+    opcodes::pop_d(cpu, io);
+    // MVI A,#$07
+    cpu.cycles += 7;
+    cpu.a = 0x07;
+    // STA $20c0
+    cpu.cycles += 13;
+    cpu.write_byte(0x20C0, cpu.a);
+    0x0A9E
+}
+
+fn block_0a9e(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $20c0
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x20C0];
+    // DCR A
+    cpu.cycles += 5;
+    opcodes::dcr_a(cpu, io);
+    // JNZ $0a9e
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x0A9E;
+    }
+    0x0AA5
+}
+
+fn block_0aa5(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // INX D
+    cpu.cycles += 5;
+    opcodes::inx_d(cpu, io);
+    // DCR C
+    cpu.cycles += 5;
+    opcodes::dcr_c(cpu, io);
+    // JNZ $0a93
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x0A93;
+    }
+    0x0AAA
+}
+
+fn block_0aaa(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_0aab(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$2050
+    cpu.cycles += 10;
+    cpu.set_hl(0x2050);
+    // JMP $024b
+    cpu.cycles += 10;
+    0x024B
+}
+
+fn block_0ab1(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI A,#$40
+    cpu.cycles += 7;
+    cpu.a = 0x40;
+    // JMP $0ad7
+    cpu.cycles += 10;
+    0x0AD7
+}
+
+fn block_0ab6(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI A,#$80
+    cpu.cycles += 7;
+    cpu.a = 0x80;
+    // JMP $0ad7
+    cpu.cycles += 10;
+    0x0AD7
+}
+
+fn block_0abb(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // POP H
+    cpu.cycles += 10;
+    opcodes::pop_h(cpu, io);
+    // JMP $0072
+    cpu.cycles += 10;
+    0x0072
+}
+
+fn block_0abf(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $20c1
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x20C1];
+    // RRC
+    cpu.cycles += 4;
+    opcodes::rrc(cpu, io);
+    // JC $0abb
+    cpu.cycles += 10;
+    if cpu.cc.cy {
+        return 0x0ABB;
+    }
+    0x0AC6
+}
+
+fn block_0ac6(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // RRC
+    cpu.cycles += 4;
+    opcodes::rrc(cpu, io);
+    // JC $1868
+    cpu.cycles += 10;
+    if cpu.cc.cy {
+        return 0x1868;
+    }
+    0x0ACA
+}
+
+fn block_0aca(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // RRC
+    cpu.cycles += 4;
+    opcodes::rrc(cpu, io);
+    // JC $0aab
+    cpu.cycles += 10;
+    if cpu.cc.cy {
+        return 0x0AAB;
+    }
+    0x0ACE
+}
+
+fn block_0ace(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_0acf(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$2b14
+    cpu.cycles += 10;
+    cpu.set_hl(0x2B14);
+    // MVI C,#$0f
+    cpu.cycles += 7;
+    cpu.c = 0x0F;
+    // JMP $0a93
+    cpu.cycles += 10;
+    0x0A93
+}
+
+fn block_0ad7(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // STA $20c0
+    cpu.cycles += 13;
+    cpu.write_byte(0x20C0, cpu.a);
+    0x0ADA
+}
+
+fn block_0ada(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $20c0
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x20C0];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JNZ $0ada
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x0ADA;
+    }
+    0x0AE1
+}
+
+fn block_0ae1(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_0ae2(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$20c2
+    cpu.cycles += 10;
+    cpu.set_hl(0x20C2);
+    // MVI B,#$0c
+    cpu.cycles += 7;
+    cpu.b = 0x0C;
+    // JMP $1a32
+    cpu.cycles += 10;
+    0x1A32
+}
+
+fn block_0aea(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // XRA A
+    cpu.cycles += 4;
+    opcodes::xra_a(cpu, io);
+    // OUT #$03
+    cpu.cycles += 10;
+    io.output(0x03, cpu.a);
+    // OUT #$05
+    cpu.cycles += 10;
+    io.output(0x05, cpu.a);
+    // CALL $1982
+    cpu.cycles += 17;
+    cpu.push(0x0AF2);
+    0x1982
+}
+
+fn block_0af2(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // EI
+    cpu.cycles += 4;
+    opcodes::ei(cpu, io);
+    // CALL $0ab1
+    cpu.cycles += 17;
+    cpu.push(0x0AF6);
+    0x0AB1
+}
+
+fn block_0af6(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $20ec
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x20EC];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // LXI H,#$3017
+    cpu.cycles += 10;
+    cpu.set_hl(0x3017);
+    // MVI C,#$04
+    cpu.cycles += 7;
+    cpu.c = 0x04;
+    // JNZ $0be8
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x0BE8;
+    }
+    0x0B02
+}
+
+fn block_0b02(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI D,#$1cfa
+    cpu.cycles += 10;
+    cpu.set_de(0x1CFA);
+    // CALL $0a93
+    cpu.cycles += 17;
+    cpu.push(0x0B08);
+    0x0A93
+}
+
+fn block_0b08(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI D,#$1daf
+    cpu.cycles += 10;
+    cpu.set_de(0x1DAF);
+    0x0B0B
+}
+
+fn block_0b0b(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0acf
+    cpu.cycles += 17;
+    cpu.push(0x0B0E);
+    0x0ACF
+}
+
+fn block_0b0e(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0ab1
+    cpu.cycles += 17;
+    cpu.push(0x0B11);
+    0x0AB1
+}
+
+fn block_0b11(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $1815
+    cpu.cycles += 17;
+    cpu.push(0x0B14);
+    0x1815
+}
+
+fn block_0b14(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0ab6
+    cpu.cycles += 17;
+    cpu.push(0x0B17);
+    0x0AB6
+}
+
+fn block_0b17(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $20ec
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x20EC];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JNZ $0b4a
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x0B4A;
+    }
+    0x0B1E
+}
+
+fn block_0b1e(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI D,#$1a95
+    cpu.cycles += 10;
+    cpu.set_de(0x1A95);
+    // CALL $0ae2
+    cpu.cycles += 17;
+    cpu.push(0x0B24);
+    0x0AE2
+}
+
+fn block_0b24(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0a80
+    cpu.cycles += 17;
+    cpu.push(0x0B27);
+    0x0A80
+}
+
+fn block_0b27(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI D,#$1bb0
+    cpu.cycles += 10;
+    cpu.set_de(0x1BB0);
+    // CALL $0ae2
+    cpu.cycles += 17;
+    cpu.push(0x0B2D);
+    0x0AE2
+}
+
+fn block_0b2d(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0a80
+    cpu.cycles += 17;
+    cpu.push(0x0B30);
+    0x0A80
+}
+
+fn block_0b30(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0ab1
+    cpu.cycles += 17;
+    cpu.push(0x0B33);
+    0x0AB1
+}
+
+fn block_0b33(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI D,#$1fc9
+    cpu.cycles += 10;
+    cpu.set_de(0x1FC9);
+    // CALL $0ae2
+    cpu.cycles += 17;
+    cpu.push(0x0B39);
+    0x0AE2
+}
+
+fn block_0b39(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0a80
+    cpu.cycles += 17;
+    cpu.push(0x0B3C);
+    0x0A80
+}
+
+fn block_0b3c(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0ab1
+    cpu.cycles += 17;
+    cpu.push(0x0B3F);
+    0x0AB1
+}
+
+fn block_0b3f(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$33b7
+    cpu.cycles += 10;
+    cpu.set_hl(0x33B7);
+    // MVI B,#$0a
+    cpu.cycles += 7;
+    cpu.b = 0x0A;
+    // CALL $14cb
+    cpu.cycles += 17;
+    cpu.push(0x0B47);
+    0x14CB
+}
+
+fn block_0b47(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0ab6
+    cpu.cycles += 17;
+    cpu.push(0x0B4A);
+    0x0AB6
+}
+
+fn block_0b4a(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $09d6
+    cpu.cycles += 17;
+    cpu.push(0x0B4D);
+    0x09D6
+}
+
+fn block_0b4d(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $21ff
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x21FF];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JNZ $0b5d
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x0B5D;
+    }
+    0x0B54
+}
+
+fn block_0b54(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $08d1
+    cpu.cycles += 17;
+    cpu.push(0x0B57);
+    0x08D1
+}
+
+fn block_0b57(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // STA $21ff
+    cpu.cycles += 13;
+    cpu.write_byte(0x21FF, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x0B5A;
+    }
+    // CALL $1a7f
+    cpu.cycles += 17;
+    cpu.push(0x0B5D);
+    0x1A7F
+}
+
+fn block_0b5d(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $01e4
+    cpu.cycles += 17;
+    cpu.push(0x0B60);
+    0x01E4
+}
+
+fn block_0b60(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $01c0
+    cpu.cycles += 17;
+    cpu.push(0x0B63);
+    0x01C0
+}
+
+fn block_0b63(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $01ef
+    cpu.cycles += 17;
+    cpu.push(0x0B66);
+    0x01EF
+}
+
+fn block_0b66(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $021a
+    cpu.cycles += 17;
+    cpu.push(0x0B69);
+    0x021A
+}
+
+fn block_0b69(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI A,#$01
+    cpu.cycles += 7;
+    cpu.a = 0x01;
+    // STA $20c1
+    cpu.cycles += 13;
+    cpu.write_byte(0x20C1, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x0B6E;
+    }
+    // CALL $01cf
+    cpu.cycles += 17;
+    cpu.push(0x0B71);
+    0x01CF
+}
+
+fn block_0b71(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $1618
+    cpu.cycles += 17;
+    cpu.push(0x0B74);
+    0x1618
+}
+
+fn block_0b74(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0bf1
+    cpu.cycles += 17;
+    cpu.push(0x0B77);
+    0x0BF1
+}
+
+fn block_0b77(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // OUT #$06
+    cpu.cycles += 10;
+    io.output(0x06, cpu.a);
+    // CALL $0a59
+    cpu.cycles += 17;
+    cpu.push(0x0B7C);
+    0x0A59
+}
+
+fn block_0b7c(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JZ $0b71
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x0B71;
+    }
+    0x0B7F
+}
+
+fn block_0b7f(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // XRA A
+    cpu.cycles += 4;
+    opcodes::xra_a(cpu, io);
+    // STA $2025
+    cpu.cycles += 13;
+    cpu.write_byte(0x2025, cpu.a);
+    0x0B83
+}
+
+fn block_0b83(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0a59
+    cpu.cycles += 17;
+    cpu.push(0x0B86);
+    0x0A59
+}
+
+fn block_0b86(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JNZ $0b83
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x0B83;
+    }
+    0x0B89
+}
+
+fn block_0b89(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // XRA A
+    cpu.cycles += 4;
+    opcodes::xra_a(cpu, io);
+    // STA $20c1
+    cpu.cycles += 13;
+    cpu.write_byte(0x20C1, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x0B8D;
+    }
+    // CALL $0ab1
+    cpu.cycles += 17;
+    cpu.push(0x0B90);
+    0x0AB1
+}
+
+fn block_0b90(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $1988
+    cpu.cycles += 17;
+    cpu.push(0x0B93);
+    0x1988
+}
+
+fn block_0b93(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI C,#$0c
+    cpu.cycles += 7;
+    cpu.c = 0x0C;
+    // LXI H,#$2c11
+    cpu.cycles += 10;
+    cpu.set_hl(0x2C11);
+    // LXI D,#$1f90
+    cpu.cycles += 10;
+    cpu.set_de(0x1F90);
+    // CALL $08f3
+    cpu.cycles += 17;
+    cpu.push(0x0B9E);
+    0x08F3
+}
+
+fn block_0b9e(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LDA $20ec
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x20EC];
+    // CPI #$00
+    cpu.cycles += 7;
+    cpu.cmp(0x00);
+    // JNZ $0bae
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x0BAE;
+    }
+    0x0BA6
+}
+
+fn block_0ba6(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$3311
+    cpu.cycles += 10;
+    cpu.set_hl(0x3311);
+    // MVI A,#$02
+    cpu.cycles += 7;
+    cpu.a = 0x02;
+    // CALL $08ff
+    cpu.cycles += 17;
+    cpu.push(0x0BAE);
+    0x08FF
+}
+
+fn block_0bae(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI B,#$1f9c
+    cpu.cycles += 10;
+    cpu.set_bc(0x1F9C);
+    // CALL $1856
+    cpu.cycles += 17;
+    cpu.push(0x0BB4);
+    0x1856
+}
+
+fn block_0bb4(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $184c
+    cpu.cycles += 17;
+    cpu.push(0x0BB7);
+    0x184C
+}
+
+fn block_0bb7(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // IN #$02
+    cpu.cycles += 10;
+    cpu.a = io.input(0x02);
+    // RLC
+    cpu.cycles += 4;
+    opcodes::rlc(cpu, io);
+    // JC $0bc3
+    cpu.cycles += 10;
+    if cpu.cc.cy {
+        return 0x0BC3;
+    }
+    0x0BBD
+}
+
+fn block_0bbd(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI B,#$1fa0
+    cpu.cycles += 10;
+    cpu.set_bc(0x1FA0);
+    // CALL $183a
+    cpu.cycles += 17;
+    cpu.push(0x0BC3);
+    0x183A
+}
+
+fn block_0bc3(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0ab6
+    cpu.cycles += 17;
+    cpu.push(0x0BC6);
+    0x0AB6
+}
+
+fn block_0bc6(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LDA $20ec
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x20EC];
+    // CPI #$00
+    cpu.cycles += 7;
+    cpu.cmp(0x00);
+    // JNZ $0bda
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x0BDA;
+    }
+    0x0BCE
+}
+
+fn block_0bce(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI D,#$1fd5
+    cpu.cycles += 10;
+    cpu.set_de(0x1FD5);
+    // CALL $0ae2
+    cpu.cycles += 17;
+    cpu.push(0x0BD4);
+    0x0AE2
+}
+
+fn block_0bd4(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0a80
+    cpu.cycles += 17;
+    cpu.push(0x0BD7);
+    0x0A80
+}
+
+fn block_0bd7(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $189e
+    cpu.cycles += 17;
+    cpu.push(0x0BDA);
+    0x189E
+}
+
+fn block_0bda(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LXI H,#$20ec
+    cpu.cycles += 10;
+    cpu.set_hl(0x20EC);
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // INR A
+    cpu.cycles += 5;
+    opcodes::inr_a(cpu, io);
+    // ANI #$01
+    cpu.cycles += 7;
+    cpu.and(0x01);
+    // MOV M,A
+    cpu.cycles += 7;
+    opcodes::mov_m_a(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x0BE2;
+    }
+    // CALL $09d6
+    cpu.cycles += 17;
+    cpu.push(0x0BE5);
+    0x09D6
+}
+
+fn block_0be5(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JMP $18df
+    cpu.cycles += 10;
+    0x18DF
+}
+
+fn block_0be8(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI D,#$1dab
+    cpu.cycles += 10;
+    cpu.set_de(0x1DAB);
+    // CALL $0a93
+    cpu.cycles += 17;
+    cpu.push(0x0BEE);
+    0x0A93
+}
+
+fn block_0bee(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JMP $0b0b
+    cpu.cycles += 10;
+    0x0B0B
+}
+
+fn block_0bf1(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $190a
+    cpu.cycles += 17;
+    cpu.push(0x0BF4);
+    0x190A
+}
+
+fn block_0bf4(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JMP $199a
+    cpu.cycles += 10;
+    0x199A
+}
+
+fn block_1424(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $1474
+    cpu.cycles += 17;
+    cpu.push(0x1427);
+    0x1474
+}
+
+fn block_1427(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // PUSH B
+    cpu.cycles += 11;
+    opcodes::push_b(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x1428;
+    }
+    // PUSH H
+    cpu.cycles += 11;
+    opcodes::push_h(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x1429;
+    }
+    // XRA A
+    cpu.cycles += 4;
+    opcodes::xra_a(cpu, io);
+    // MOV M,A
+    cpu.cycles += 7;
+    opcodes::mov_m_a(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x142B;
+    }
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV M,A
+    cpu.cycles += 7;
+    opcodes::mov_m_a(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x142D;
+    }
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // POP H
+    cpu.cycles += 10;
+    opcodes::pop_h(cpu, io);
+    // LXI B,#$0020
+    cpu.cycles += 10;
+    cpu.set_bc(0x0020);
+    // DAD B
+    cpu.cycles += 10;
+    opcodes::dad_b(cpu, io);
+    // POP B
+    cpu.cycles += 10;
+    opcodes::pop_b(cpu, io);
+    // DCR B
+    cpu.cycles += 5;
+    opcodes::dcr_b(cpu, io);
+    // JNZ $1427
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x1427;
+    }
+    0x1438
+}
+
+fn block_1438(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_1439(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // PUSH B
+    cpu.cycles += 11;
+    opcodes::push_b(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x143A;
+    }
+    // LDAX D
+    cpu.cycles += 7;
+    opcodes::ldax_d(cpu, io);
+    // MOV M,A
+    cpu.cycles += 7;
+    opcodes::mov_m_a(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x143C;
+    }
+    // INX D
+    cpu.cycles += 5;
+    opcodes::inx_d(cpu, io);
+    // LXI B,#$0020
+    cpu.cycles += 10;
+    cpu.set_bc(0x0020);
+    // DAD B
+    cpu.cycles += 10;
+    opcodes::dad_b(cpu, io);
+    // POP B
+    cpu.cycles += 10;
+    opcodes::pop_b(cpu, io);
+    // DCR B
+    cpu.cycles += 5;
+    opcodes::dcr_b(cpu, io);
+    // JNZ $1439
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x1439;
+    }
+    0x1446
+}
+
+fn block_1446(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_1474(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV A,L
+    cpu.cycles += 5;
+    opcodes::mov_a_l(cpu, io);
+    // ANI #$07
+    cpu.cycles += 7;
+    cpu.and(0x07);
+    // OUT #$02
+    cpu.cycles += 10;
+    io.output(0x02, cpu.a);
+    // JMP $1a47
+    cpu.cycles += 10;
+    0x1A47
+}
+
+fn block_147c(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // PUSH B
+    cpu.cycles += 11;
+    opcodes::push_b(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x147D;
+    }
+    // PUSH H
+    cpu.cycles += 11;
+    opcodes::push_h(cpu, io);
+    0x147E
+}
+
+fn block_147e(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // STAX D
+    cpu.cycles += 7;
+    opcodes::stax_d(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x1480;
+    }
+    // INX D
+    cpu.cycles += 5;
+    opcodes::inx_d(cpu, io);
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // DCR C
+    cpu.cycles += 5;
+    opcodes::dcr_c(cpu, io);
+    // JNZ $147e
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x147E;
+    }
+    0x1486
+}
+
+fn block_1486(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // POP H
+    cpu.cycles += 10;
+    opcodes::pop_h(cpu, io);
+    // LXI B,#$0020
+    cpu.cycles += 10;
+    cpu.set_bc(0x0020);
+    // DAD B
+    cpu.cycles += 10;
+    opcodes::dad_b(cpu, io);
+    // POP B
+    cpu.cycles += 10;
+    opcodes::pop_b(cpu, io);
+    // DCR B
+    cpu.cycles += 5;
+    opcodes::dcr_b(cpu, io);
+    // JNZ $147c
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x147C;
+    }
+    0x1490
+}
+
+fn block_1490(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_14cb(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // XRA A
+    cpu.cycles += 4;
+    opcodes::xra_a(cpu, io);
+    0x14CC
+}
+
+fn block_14cc(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // PUSH B
+    cpu.cycles += 11;
+    opcodes::push_b(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x14CD;
+    }
+    // MOV M,A
+    cpu.cycles += 7;
+    opcodes::mov_m_a(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x14CE;
+    }
+    // LXI B,#$0020
+    cpu.cycles += 10;
+    cpu.set_bc(0x0020);
+    // DAD B
+    cpu.cycles += 10;
+    opcodes::dad_b(cpu, io);
+    // POP B
+    cpu.cycles += 10;
+    opcodes::pop_b(cpu, io);
+    // DCR B
+    cpu.cycles += 5;
+    opcodes::dcr_b(cpu, io);
+    // JNZ $14cc
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x14CC;
+    }
+    0x14D7
+}
+
+fn block_14d7(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_14d8(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LDA $2025
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2025];
+    // CPI #$05
+    cpu.cycles += 7;
+    cpu.cmp(0x05);
+    // RZ
+    cpu.cycles += 5;
+    if cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x14DE
+}
+
+fn block_14de(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CPI #$02
+    cpu.cycles += 7;
+    cpu.cmp(0x02);
+    // RNZ
+    cpu.cycles += 5;
+    if !cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x14E1
+}
+
+fn block_14e1(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $2029
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2029];
+    // CPI #$d8
+    cpu.cycles += 7;
+    cpu.cmp(0xD8);
+    // MOV B,A
+    cpu.cycles += 5;
+    opcodes::mov_b_a(cpu, io);
+    // JNC $1530
+    cpu.cycles += 10;
+    if !cpu.cc.cy {
+        return 0x1530;
+    }
+    0x14EA
+}
+
+fn block_14ea(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $2002
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2002];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // RZ
+    cpu.cycles += 5;
+    if cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x14EF
+}
+
+fn block_14ef(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV A,B
+    cpu.cycles += 5;
+    opcodes::mov_a_b(cpu, io);
+    // CPI #$ce
+    cpu.cycles += 7;
+    cpu.cmp(0xCE);
+    // JNC $1579
+    cpu.cycles += 10;
+    if !cpu.cc.cy {
+        return 0x1579;
+    }
+    0x14F5
+}
+
+fn block_14f5(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // ADI #$06
+    cpu.cycles += 7;
+    cpu.add(0x06);
+    // MOV B,A
+    cpu.cycles += 5;
+    opcodes::mov_b_a(cpu, io);
+    // LDA $2009
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2009];
+    // CPI #$90
+    cpu.cycles += 7;
+    cpu.cmp(0x90);
+    // JNC $1504
+    cpu.cycles += 10;
+    if !cpu.cc.cy {
+        return 0x1504;
+    }
+    0x1500
+}
+
+fn block_1500(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // CMP B
+    cpu.cycles += 4;
+    opcodes::cmp_b(cpu, io);
+    // JNC $1530
+    cpu.cycles += 10;
+    if !cpu.cc.cy {
+        return 0x1530;
+    }
+    0x1504
+}
+
+fn block_1504(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV L,B
+    cpu.cycles += 5;
+    opcodes::mov_l_b(cpu, io);
+    // CALL $1562
+    cpu.cycles += 17;
+    cpu.push(0x1508);
+    0x1562
+}
+
+fn block_1508(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $202a
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x202A];
+    // MOV H,A
+    cpu.cycles += 5;
+    opcodes::mov_h_a(cpu, io);
+    // CALL $156f
+    cpu.cycles += 17;
+    cpu.push(0x150F);
+    0x156F
+}
+
+fn block_150f(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // SHLD $2064
+    cpu.cycles += 16;
+    cpu.write_byte(0x2064, cpu.l);
+    cpu.write_byte(0x2065, cpu.h);
+    if !cpu.written.is_empty() {
+        return 0x1512;
+    }
+    // MVI A,#$05
+    cpu.cycles += 7;
+    cpu.a = 0x05;
+    // STA $2025
+    cpu.cycles += 13;
+    cpu.write_byte(0x2025, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x1517;
+    }
+    // CALL $1581
+    cpu.cycles += 17;
+    cpu.push(0x151A);
+    0x1581
+}
+
+fn block_151a(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JZ $1530
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x1530;
+    }
+    0x151F
+}
+
+fn block_151f(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI M,#$00
+    cpu.cycles += 10;
+    cpu.write_byte(cpu.hl() as usize, 0x00);
+    if !cpu.written.is_empty() {
+        return 0x1521;
+    }
+    // CALL $0a5f
+    cpu.cycles += 17;
+    cpu.push(0x1524);
+    0x0A5F
+}
+
+fn block_1524(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $1a3b
+    cpu.cycles += 17;
+    cpu.push(0x1527);
+    0x1A3B
+}
+
+fn block_1527(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $15d3
+    cpu.cycles += 17;
+    cpu.push(0x152A);
+    0x15D3
+}
+
+fn block_152a(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI A,#$10
+    cpu.cycles += 7;
+    cpu.a = 0x10;
+    // STA $2003
+    cpu.cycles += 13;
+    cpu.write_byte(0x2003, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x152F;
+    }
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_1530(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI A,#$03
+    cpu.cycles += 7;
+    cpu.a = 0x03;
+    // STA $2025
+    cpu.cycles += 13;
+    cpu.write_byte(0x2025, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x1535;
+    }
+    // JMP $154a
+    cpu.cycles += 10;
+    0x154A
+}
+
+fn block_1538(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LXI H,#$2003
+    cpu.cycles += 10;
+    cpu.set_hl(0x2003);
+    // DCR M
+    cpu.cycles += 10;
+    opcodes::dcr_m(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x153C;
+    }
+    // RNZ
+    cpu.cycles += 5;
+    if !cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x153D
+}
+
+fn block_153d(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LDHD $2064
+    cpu.cycles += 16;
+    cpu.l = cpu.mem[0x2064];
+    cpu.h = cpu.mem[0x2065];
+    // MVI B,#$10
+    cpu.cycles += 7;
+    cpu.b = 0x10;
+    // CALL $1424
+    cpu.cycles += 17;
+    cpu.push(0x1545);
+    0x1424
+}
+
+fn block_1545(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI A,#$04
+    cpu.cycles += 7;
+    cpu.a = 0x04;
+    // STA $2025
+    cpu.cycles += 13;
+    cpu.write_byte(0x2025, cpu.a);
+    0x154A
+}
+
+fn block_154a(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // XRA A
+    cpu.cycles += 4;
+    opcodes::xra_a(cpu, io);
+    // STA $2002
+    cpu.cycles += 13;
+    cpu.write_byte(0x2002, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x154E;
+    }
+    // MVI B,#$f7
+    cpu.cycles += 7;
+    cpu.b = 0xF7;
+    // JMP $19dc
+    cpu.cycles += 10;
+    0x19DC
+}
+
+fn block_1554(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MVI C,#$00
+    cpu.cycles += 7;
+    cpu.c = 0x00;
+    // CMP H
+    cpu.cycles += 4;
+    opcodes::cmp_h(cpu, io);
+    // CNC $1590
+    cpu.cycles += 11;
+    if !cpu.cc.cy {
+        cpu.cycles += 6;
+        cpu.push(0x155A);
+        return 0x1590;
+    }
+    0x155A
+}
+
+fn block_155a(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // CMP H
+    cpu.cycles += 4;
+    opcodes::cmp_h(cpu, io);
+    // RNC
+    cpu.cycles += 5;
+    if !cpu.cc.cy {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x155C
+}
+
+fn block_155c(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // ADI #$10
+    cpu.cycles += 7;
+    cpu.add(0x10);
+    // INR C
+    cpu.cycles += 5;
+    opcodes::inr_c(cpu, io);
+    // JMP $155a
+    cpu.cycles += 10;
+    0x155A
+}
+
+fn block_1562(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $2009
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2009];
+    // MOV H,L
+    cpu.cycles += 5;
+    opcodes::mov_h_l(cpu, io);
+    // CALL $1554
+    cpu.cycles += 17;
+    cpu.push(0x1569);
+    0x1554
+}
+
+fn block_1569(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV B,C
+    cpu.cycles += 5;
+    opcodes::mov_b_c(cpu, io);
+    // DCR B
+    cpu.cycles += 5;
+    opcodes::dcr_b(cpu, io);
+    // SBI #$10
+    cpu.cycles += 7;
+    cpu.sub_cy(0x10);
+    // MOV L,A
+    cpu.cycles += 5;
+    opcodes::mov_l_a(cpu, io);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_156f(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LDA $200a
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x200A];
+    // CALL $1554
+    cpu.cycles += 17;
+    cpu.push(0x1575);
+    0x1554
+}
+
+fn block_1575(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // SBI #$10
+    cpu.cycles += 7;
+    cpu.sub_cy(0x10);
+    // MOV H,A
+    cpu.cycles += 5;
+    opcodes::mov_h_a(cpu, io);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_1579(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI A,#$01
+    cpu.cycles += 7;
+    cpu.a = 0x01;
+    // STA $2085
+    cpu.cycles += 13;
+    cpu.write_byte(0x2085, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x157E;
+    }
+    // JMP $1545
+    cpu.cycles += 10;
+    0x1545
+}
+
+fn block_1581(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV A,B
+    cpu.cycles += 5;
+    opcodes::mov_a_b(cpu, io);
+    // RLC
+    cpu.cycles += 4;
+    opcodes::rlc(cpu, io);
+    // RLC
+    cpu.cycles += 4;
+    opcodes::rlc(cpu, io);
+    // RLC
+    cpu.cycles += 4;
+    opcodes::rlc(cpu, io);
+    // ADD B
+    cpu.cycles += 4;
+    opcodes::add_b(cpu, io);
+    // ADD B
+    cpu.cycles += 4;
+    opcodes::add_b(cpu, io);
+    // ADD B
+    cpu.cycles += 4;
+    opcodes::add_b(cpu, io);
+    // ADD C
+    cpu.cycles += 4;
+    opcodes::add_c(cpu, io);
+    // DCR A
+    cpu.cycles += 5;
+    opcodes::dcr_a(cpu, io);
+    // MOV L,A
+    cpu.cycles += 5;
+    opcodes::mov_l_a(cpu, io);
+    // LDA $2067
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2067];
+    // MOV H,A
+    cpu.cycles += 5;
+    opcodes::mov_h_a(cpu, io);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_1590(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // INR C
+    cpu.cycles += 5;
+    opcodes::inr_c(cpu, io);
+    // ADI #$10
+    cpu.cycles += 7;
+    cpu.add(0x10);
+    // JM $1590
+    cpu.cycles += 10;
+    if cpu.cc.s {
+        return 0x1590;
+    }
+    0x1596
+}
+
+fn block_1596(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_1597(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $200d
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x200D];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JNZ $15b7
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x15B7;
+    }
+    0x159E
+}
+
+fn block_159e(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$3ea4
+    cpu.cycles += 10;
+    cpu.set_hl(0x3EA4);
+    // CALL $15c5
+    cpu.cycles += 17;
+    cpu.push(0x15A4);
+    0x15C5
+}
+
+fn block_15a4(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // RNC
+    cpu.cycles += 5;
+    if !cpu.cc.cy {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x15A5
+}
+
+fn block_15a5(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI B,#$fe
+    cpu.cycles += 7;
+    cpu.b = 0xFE;
+    // MVI A,#$01
+    cpu.cycles += 7;
+    cpu.a = 0x01;
+    0x15A9
+}
+
+fn block_15a9(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // STA $200d
+    cpu.cycles += 13;
+    cpu.write_byte(0x200D, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x15AC;
+    }
+    // MOV A,B
+    cpu.cycles += 5;
+    opcodes::mov_a_b(cpu, io);
+    // STA $2008
+    cpu.cycles += 13;
+    cpu.write_byte(0x2008, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x15B0;
+    }
+    // LDA $200e
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x200E];
+    // STA $2007
+    cpu.cycles += 13;
+    cpu.write_byte(0x2007, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x15B6;
+    }
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_15b7(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$2524
+    cpu.cycles += 10;
+    cpu.set_hl(0x2524);
+    // CALL $15c5
+    cpu.cycles += 17;
+    cpu.push(0x15BD);
+    0x15C5
+}
+
+fn block_15bd(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // RNC
+    cpu.cycles += 5;
+    if !cpu.cc.cy {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x15BE
+}
+
+fn block_15be(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $18f1
+    cpu.cycles += 17;
+    cpu.push(0x15C1);
+    0x18F1
+}
+
+fn block_15c1(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // XRA A
+    cpu.cycles += 4;
+    opcodes::xra_a(cpu, io);
+    // JMP $15a9
+    cpu.cycles += 10;
+    0x15A9
+}
+
+fn block_15c5(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI B,#$17
+    cpu.cycles += 7;
+    cpu.b = 0x17;
+    0x15C7
+}
+
+fn block_15c7(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JNZ $166b
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x166B;
+    }
+    0x15CC
+}
+
+fn block_15cc(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // DCR B
+    cpu.cycles += 5;
+    opcodes::dcr_b(cpu, io);
+    // JNZ $15c7
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x15C7;
+    }
+    0x15D1
+}
+
+fn block_15d1(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_15d3(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $1474
+    cpu.cycles += 17;
+    cpu.push(0x15D6);
+    0x1474
+}
+
+fn block_15d6(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // PUSH H
+    cpu.cycles += 11;
+    opcodes::push_h(cpu, io);
+    0x15D7
+}
+
+fn block_15d7(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // PUSH B
+    cpu.cycles += 11;
+    opcodes::push_b(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x15D8;
+    }
+    // PUSH H
+    cpu.cycles += 11;
+    opcodes::push_h(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x15D9;
+    }
+    // LDAX D
+    cpu.cycles += 7;
+    opcodes::ldax_d(cpu, io);
+    // OUT #$04
+    cpu.cycles += 10;
+    io.output(0x04, cpu.a);
+    // IN #$03
+    cpu.cycles += 10;
+    cpu.a = io.input(0x03);
+    // MOV M,A
+    cpu.cycles += 7;
+    opcodes::mov_m_a(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x15DF;
+    }
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // INX D
+    cpu.cycles += 5;
+    opcodes::inx_d(cpu, io);
+    // XRA A
+    cpu.cycles += 4;
+    opcodes::xra_a(cpu, io);
+    // OUT #$04
+    cpu.cycles += 10;
+    io.output(0x04, cpu.a);
+    // IN #$03
+    cpu.cycles += 10;
+    cpu.a = io.input(0x03);
+    // MOV M,A
+    cpu.cycles += 7;
+    opcodes::mov_m_a(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x15E7;
+    }
+    // POP H
+    cpu.cycles += 10;
+    opcodes::pop_h(cpu, io);
+    // LXI B,#$0020
+    cpu.cycles += 10;
+    cpu.set_bc(0x0020);
+    // DAD B
+    cpu.cycles += 10;
+    opcodes::dad_b(cpu, io);
+    // POP B
+    cpu.cycles += 10;
+    opcodes::pop_b(cpu, io);
+    // DCR B
+    cpu.cycles += 5;
+    opcodes::dcr_b(cpu, io);
+    // JNZ $15d7
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x15D7;
+    }
+    0x15F1
+}
+
+fn block_15f1(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // POP H
+    cpu.cycles += 10;
+    opcodes::pop_h(cpu, io);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_15f3(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $1611
+    cpu.cycles += 17;
+    cpu.push(0x15F6);
+    0x1611
+}
+
+fn block_15f6(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI B,#$3700
+    cpu.cycles += 10;
+    cpu.set_bc(0x3700);
+    0x15F9
+}
+
+fn block_15f9(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JZ $15ff
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x15FF;
+    }
+    0x15FE
+}
+
+fn block_15fe(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // INR C
+    cpu.cycles += 5;
+    opcodes::inr_c(cpu, io);
+    0x15FF
+}
+
+fn block_15ff(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // DCR B
+    cpu.cycles += 5;
+    opcodes::dcr_b(cpu, io);
+    // JNZ $15f9
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x15F9;
+    }
+    0x1604
+}
+
+fn block_1604(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV A,C
+    cpu.cycles += 5;
+    opcodes::mov_a_c(cpu, io);
+    // STA $2082
+    cpu.cycles += 13;
+    cpu.write_byte(0x2082, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x1608;
+    }
+    // CPI #$01
+    cpu.cycles += 7;
+    cpu.cmp(0x01);
+    // RNZ
+    cpu.cycles += 5;
+    if !cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x160B
+}
+
+fn block_160b(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$206b
+    cpu.cycles += 10;
+    cpu.set_hl(0x206B);
+    // MVI M,#$01
+    cpu.cycles += 10;
+    cpu.write_byte(cpu.hl() as usize, 0x01);
+    if !cpu.written.is_empty() {
+        return 0x1610;
+    }
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_1611(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MVI L,#$00
+    cpu.cycles += 7;
+    cpu.l = 0x00;
+    // LDA $2067
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2067];
+    // MOV H,A
+    cpu.cycles += 5;
+    opcodes::mov_h_a(cpu, io);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_1618(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LDA $2015
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2015];
+    // CPI #$ff
+    cpu.cycles += 7;
+    cpu.cmp(0xFF);
+    // RNZ
+    cpu.cycles += 5;
+    if !cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x161E
+}
+
+fn block_161e(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LXI H,#$2010
+    cpu.cycles += 10;
+    cpu.set_hl(0x2010);
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV B,M
+    cpu.cycles += 7;
+    opcodes::mov_b_m(cpu, io);
+    // ORA B
+    cpu.cycles += 4;
+    opcodes::ora_b(cpu, io);
+    // RNZ
+    cpu.cycles += 5;
+    if !cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x1626
+}
+
+fn block_1626(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $2025
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2025];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // RNZ
+    cpu.cycles += 5;
+    if !cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x162B
+}
+
+fn block_162b(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $20ef
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x20EF];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JZ $1652
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x1652;
+    }
+    0x1632
+}
+
+fn block_1632(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $202d
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x202D];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JNZ $1648
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x1648;
+    }
+    0x1639
+}
+
+fn block_1639(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $17c0
+    cpu.cycles += 17;
+    cpu.push(0x163C);
+    0x17C0
+}
+
+fn block_163c(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // ANI #$10
+    cpu.cycles += 7;
+    cpu.and(0x10);
+    // RZ
+    cpu.cycles += 5;
+    if cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x163F
+}
+
+fn block_163f(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI A,#$01
+    cpu.cycles += 7;
+    cpu.a = 0x01;
+    // STA $2025
+    cpu.cycles += 13;
+    cpu.write_byte(0x2025, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x1644;
+    }
+    // STA $202d
+    cpu.cycles += 13;
+    cpu.write_byte(0x202D, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x1647;
+    }
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_1648(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $17c0
+    cpu.cycles += 17;
+    cpu.push(0x164B);
+    0x17C0
+}
+
+fn block_164b(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // ANI #$10
+    cpu.cycles += 7;
+    cpu.and(0x10);
+    // RNZ
+    cpu.cycles += 5;
+    if !cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x164E
+}
+
+fn block_164e(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // STA $202d
+    cpu.cycles += 13;
+    cpu.write_byte(0x202D, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x1651;
+    }
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_1652(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LXI H,#$2025
+    cpu.cycles += 10;
+    cpu.set_hl(0x2025);
+    // MVI M,#$01
+    cpu.cycles += 10;
+    cpu.write_byte(cpu.hl() as usize, 0x01);
+    if !cpu.written.is_empty() {
+        return 0x1657;
+    }
+    // LDHD $20ed
+    cpu.cycles += 16;
+    cpu.l = cpu.mem[0x20ED];
+    cpu.h = cpu.mem[0x20EE];
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV A,L
+    cpu.cycles += 5;
+    opcodes::mov_a_l(cpu, io);
+    // CPI #$7e
+    cpu.cycles += 7;
+    cpu.cmp(0x7E);
+    // JC $1663
+    cpu.cycles += 10;
+    if cpu.cc.cy {
+        return 0x1663;
+    }
+    0x1661
+}
+
+fn block_1661(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI L,#$74
+    cpu.cycles += 7;
+    cpu.l = 0x74;
+    0x1663
+}
+
+fn block_1663(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // SHLD $20ed
+    cpu.cycles += 16;
+    cpu.write_byte(0x20ED, cpu.l);
+    cpu.write_byte(0x20EE, cpu.h);
+    if !cpu.written.is_empty() {
+        return 0x1666;
+    }
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // STA $201d
+    cpu.cycles += 13;
+    cpu.write_byte(0x201D, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x166A;
+    }
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_166b(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // STC
+    cpu.cycles += 4;
+    opcodes::stc(cpu, io);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_1671(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $1910
+    cpu.cycles += 17;
+    cpu.push(0x1674);
+    0x1910
+}
+
+fn block_1674(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI M,#$00
+    cpu.cycles += 10;
+    cpu.write_byte(cpu.hl() as usize, 0x00);
+    if !cpu.written.is_empty() {
+        return 0x1676;
+    }
+    // CALL $09ca
+    cpu.cycles += 17;
+    cpu.push(0x1679);
+    0x09CA
+}
+
+fn block_1679(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // LXI D,#$20f5
+    cpu.cycles += 10;
+    cpu.set_de(0x20F5);
+    // LDAX D
+    cpu.cycles += 7;
+    opcodes::ldax_d(cpu, io);
+    // CMP M
+    cpu.cycles += 7;
+    opcodes::cmp_m(cpu, io);
+    // DCX D
+    cpu.cycles += 5;
+    opcodes::dcx_d(cpu, io);
+    // DCX H
+    cpu.cycles += 5;
+    opcodes::dcx_h(cpu, io);
+    // LDAX D
+    cpu.cycles += 7;
+    opcodes::ldax_d(cpu, io);
+    // JZ $168b
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x168B;
+    }
+    0x1685
+}
+
+fn block_1685(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JNC $1698
+    cpu.cycles += 10;
+    if !cpu.cc.cy {
+        return 0x1698;
+    }
+    0x1688
+}
+
+fn block_1688(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JMP $168f
+    cpu.cycles += 10;
+    0x168F
+}
+
+fn block_168b(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // CMP M
+    cpu.cycles += 7;
+    opcodes::cmp_m(cpu, io);
+    // JNC $1698
+    cpu.cycles += 10;
+    if !cpu.cc.cy {
+        return 0x1698;
+    }
+    0x168F
+}
+
+fn block_168f(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // STAX D
+    cpu.cycles += 7;
+    opcodes::stax_d(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x1691;
+    }
+    // INX D
+    cpu.cycles += 5;
+    opcodes::inx_d(cpu, io);
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // STAX D
+    cpu.cycles += 7;
+    opcodes::stax_d(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x1695;
+    }
+    // CALL $1950
+    cpu.cycles += 17;
+    cpu.push(0x1698);
+    0x1950
+}
+
+fn block_1698(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $20ce
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x20CE];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JZ $16c9
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x16C9;
+    }
+    0x169F
+}
+
+fn block_169f(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$2803
+    cpu.cycles += 10;
+    cpu.set_hl(0x2803);
+    // LXI D,#$1aa6
+    cpu.cycles += 10;
+    cpu.set_de(0x1AA6);
+    // MVI C,#$14
+    cpu.cycles += 7;
+    cpu.c = 0x14;
+    // CALL $0a93
+    cpu.cycles += 17;
+    cpu.push(0x16AA);
+    0x0A93
+}
+
+fn block_16aa(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // DCR H
+    cpu.cycles += 5;
+    opcodes::dcr_h(cpu, io);
+    // DCR H
+    cpu.cycles += 5;
+    opcodes::dcr_h(cpu, io);
+    // MVI B,#$1b
+    cpu.cycles += 7;
+    cpu.b = 0x1B;
+    // LDA $2067
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2067];
+    // RRC
+    cpu.cycles += 4;
+    opcodes::rrc(cpu, io);
+    // JC $16b7
+    cpu.cycles += 10;
+    if cpu.cc.cy {
+        return 0x16B7;
+    }
+    0x16B5
+}
+
+fn block_16b5(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI B,#$1c
+    cpu.cycles += 7;
+    cpu.b = 0x1C;
+    0x16B7
+}
+
+fn block_16b7(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV A,B
+    cpu.cycles += 5;
+    opcodes::mov_a_b(cpu, io);
+    // CALL $08ff
+    cpu.cycles += 17;
+    cpu.push(0x16BB);
+    0x08FF
+}
+
+fn block_16bb(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0ab1
+    cpu.cycles += 17;
+    cpu.push(0x16BE);
+    0x0AB1
+}
+
+fn block_16be(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $18e7
+    cpu.cycles += 17;
+    cpu.push(0x16C1);
+    0x18E7
+}
+
+fn block_16c1(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JZ $16c9
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x16C9;
+    }
+    0x16C6
+}
+
+fn block_16c6(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JMP $02ed
+    cpu.cycles += 10;
+    0x02ED
+}
+
+fn block_16c9(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$2d18
+    cpu.cycles += 10;
+    cpu.set_hl(0x2D18);
+    // LXI D,#$1aa6
+    cpu.cycles += 10;
+    cpu.set_de(0x1AA6);
+    // MVI C,#$0a
+    cpu.cycles += 7;
+    cpu.c = 0x0A;
+    // CALL $0a93
+    cpu.cycles += 17;
+    cpu.push(0x16D4);
+    0x0A93
+}
+
+fn block_16d4(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0ab6
+    cpu.cycles += 17;
+    cpu.push(0x16D7);
+    0x0AB6
+}
+
+fn block_16d7(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $09d6
+    cpu.cycles += 17;
+    cpu.push(0x16DA);
+    0x09D6
+}
+
+fn block_16da(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // XRA A
+    cpu.cycles += 4;
+    opcodes::xra_a(cpu, io);
+    // STA $20ef
+    cpu.cycles += 13;
+    cpu.write_byte(0x20EF, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x16DE;
+    }
+    // OUT #$05
+    cpu.cycles += 10;
+    io.output(0x05, cpu.a);
+    // CALL $19d1
+    cpu.cycles += 17;
+    cpu.push(0x16E3);
+    0x19D1
+}
+
+fn block_16e3(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JMP $0b89
+    cpu.cycles += 10;
+    0x0B89
+}
+
+fn block_16e6(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LXI SP,#$2400
+    cpu.cycles += 10;
+    cpu.sp = 0x2400;
+    // EI
+    cpu.cycles += 4;
+    opcodes::ei(cpu, io);
+    // XRA A
+    cpu.cycles += 4;
+    opcodes::xra_a(cpu, io);
+    // STA $2015
+    cpu.cycles += 13;
+    cpu.write_byte(0x2015, cpu.a);
+    0x16EE
+}
+
+fn block_16ee(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $14d8
+    cpu.cycles += 17;
+    cpu.push(0x16F1);
+    0x14D8
+}
+
+fn block_16f1(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI B,#$04
+    cpu.cycles += 7;
+    cpu.b = 0x04;
+    // CALL $18fa
+    cpu.cycles += 17;
+    cpu.push(0x16F6);
+    0x18FA
+}
+
+fn block_16f6(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0a59
+    cpu.cycles += 17;
+    cpu.push(0x16F9);
+    0x0A59
+}
+
+fn block_16f9(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JNZ $16ee
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x16EE;
+    }
+    0x16FC
+}
+
+fn block_16fc(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $19d7
+    cpu.cycles += 17;
+    cpu.push(0x16FF);
+    0x19D7
+}
+
+fn block_16ff(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$2701
+    cpu.cycles += 10;
+    cpu.set_hl(0x2701);
+    // CALL $19fa
+    cpu.cycles += 17;
+    cpu.push(0x1705);
+    0x19FA
+}
+
+fn block_1705(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // XRA A
+    cpu.cycles += 4;
+    opcodes::xra_a(cpu, io);
+    // CALL $1a8b
+    cpu.cycles += 17;
+    cpu.push(0x1709);
+    0x1A8B
+}
+
+fn block_1709(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI B,#$fb
+    cpu.cycles += 7;
+    cpu.b = 0xFB;
+    // JMP $196b
+    cpu.cycles += 10;
+    0x196B
+}
+
+fn block_170e(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $09ca
+    cpu.cycles += 17;
+    cpu.push(0x1711);
+    0x09CA
+}
+
+fn block_1711(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // LXI D,#$1cb8
+    cpu.cycles += 10;
+    cpu.set_de(0x1CB8);
+    // LXI H,#$1aa1
+    cpu.cycles += 10;
+    cpu.set_hl(0x1AA1);
+    // MVI C,#$04
+    cpu.cycles += 7;
+    cpu.c = 0x04;
+    // MOV B,A
+    cpu.cycles += 5;
+    opcodes::mov_b_a(cpu, io);
+    0x171C
+}
+
+fn block_171c(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDAX D
+    cpu.cycles += 7;
+    opcodes::ldax_d(cpu, io);
+    // CMP B
+    cpu.cycles += 4;
+    opcodes::cmp_b(cpu, io);
+    // JNC $1727
+    cpu.cycles += 10;
+    if !cpu.cc.cy {
+        return 0x1727;
+    }
+    0x1721
+}
+
+fn block_1721(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // INX D
+    cpu.cycles += 5;
+    opcodes::inx_d(cpu, io);
+    // DCR C
+    cpu.cycles += 5;
+    opcodes::dcr_c(cpu, io);
+    // JNZ $171c
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x171C;
+    }
+    0x1727
+}
+
+fn block_1727(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // STA $20cf
+    cpu.cycles += 13;
+    cpu.write_byte(0x20CF, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x172B;
+    }
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_172c(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LDA $2025
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2025];
+    // CPI #$00
+    cpu.cycles += 7;
+    cpu.cmp(0x00);
+    // JNZ $1739
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x1739;
+    }
+    0x1734
+}
+
+fn block_1734(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI B,#$fd
+    cpu.cycles += 7;
+    cpu.b = 0xFD;
+    // JMP $19dc
+    cpu.cycles += 10;
+    0x19DC
+}
+
+fn block_1739(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI B,#$02
+    cpu.cycles += 7;
+    cpu.b = 0x02;
+    // JMP $18fa
+    cpu.cycles += 10;
+    0x18FA
+}
+
+fn block_1740(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LXI H,#$209b
+    cpu.cycles += 10;
+    cpu.set_hl(0x209B);
+    // DCR M
+    cpu.cycles += 10;
+    opcodes::dcr_m(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x1744;
+    }
+    // CZ $176d
+    cpu.cycles += 11;
+    if cpu.cc.z {
+        cpu.cycles += 6;
+        cpu.push(0x1747);
+        return 0x176D;
+    }
+    0x1747
+}
+
+fn block_1747(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $2068
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2068];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JZ $176d
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x176D;
+    }
+    0x174E
+}
+
+fn block_174e(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LXI H,#$2096
+    cpu.cycles += 10;
+    cpu.set_hl(0x2096);
+    // DCR M
+    cpu.cycles += 10;
+    opcodes::dcr_m(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x1752;
+    }
+    // RNZ
+    cpu.cycles += 5;
+    if !cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x1753
+}
+
+fn block_1753(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LXI H,#$2098
+    cpu.cycles += 10;
+    cpu.set_hl(0x2098);
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // OUT #$05
+    cpu.cycles += 10;
+    io.output(0x05, cpu.a);
+    // LDA $2082
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2082];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JZ $176d
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x176D;
+    }
+    0x1760
+}
+
+fn block_1760(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // DCX H
+    cpu.cycles += 5;
+    opcodes::dcx_h(cpu, io);
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // DCX H
+    cpu.cycles += 5;
+    opcodes::dcx_h(cpu, io);
+    // MOV M,A
+    cpu.cycles += 7;
+    opcodes::mov_m_a(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x1764;
+    }
+    // DCX H
+    cpu.cycles += 5;
+    opcodes::dcx_h(cpu, io);
+    // MVI M,#$01
+    cpu.cycles += 10;
+    cpu.write_byte(cpu.hl() as usize, 0x01);
+    if !cpu.written.is_empty() {
+        return 0x1767;
+    }
+    // MVI A,#$04
+    cpu.cycles += 7;
+    cpu.a = 0x04;
+    // STA $209b
+    cpu.cycles += 13;
+    cpu.write_byte(0x209B, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x176C;
+    }
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_176d(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $2098
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2098];
+    // ANI #$30
+    cpu.cycles += 7;
+    cpu.and(0x30);
+    // OUT #$05
+    cpu.cycles += 10;
+    io.output(0x05, cpu.a);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_1775(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $2095
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2095];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JZ $17aa
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x17AA;
+    }
+    0x177C
+}
+
+fn block_177c(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$1a11
+    cpu.cycles += 10;
+    cpu.set_hl(0x1A11);
+    // LXI D,#$1a21
+    cpu.cycles += 10;
+    cpu.set_de(0x1A21);
+    // LDA $2082
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2082];
+    0x1785
+}
+
+fn block_1785(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // CMP M
+    cpu.cycles += 7;
+    opcodes::cmp_m(cpu, io);
+    // JNC $178e
+    cpu.cycles += 10;
+    if !cpu.cc.cy {
+        return 0x178E;
+    }
+    0x1789
+}
+
+fn block_1789(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // INX D
+    cpu.cycles += 5;
+    opcodes::inx_d(cpu, io);
+    // JMP $1785
+    cpu.cycles += 10;
+    0x1785
+}
+
+fn block_178e(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDAX D
+    cpu.cycles += 7;
+    opcodes::ldax_d(cpu, io);
+    // STA $2097
+    cpu.cycles += 13;
+    cpu.write_byte(0x2097, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x1792;
+    }
+    // LXI H,#$2098
+    cpu.cycles += 10;
+    cpu.set_hl(0x2098);
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // ANI #$30
+    cpu.cycles += 7;
+    cpu.and(0x30);
+    // MOV B,A
+    cpu.cycles += 5;
+    opcodes::mov_b_a(cpu, io);
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // ANI #$0f
+    cpu.cycles += 7;
+    cpu.and(0x0F);
+    // RLC
+    cpu.cycles += 4;
+    opcodes::rlc(cpu, io);
+    // CPI #$10
+    cpu.cycles += 7;
+    cpu.cmp(0x10);
+    // JNZ $17a4
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x17A4;
+    }
+    0x17A2
+}
+
+fn block_17a2(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI A,#$01
+    cpu.cycles += 7;
+    cpu.a = 0x01;
+    0x17A4
+}
+
+fn block_17a4(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // ORA B
+    cpu.cycles += 4;
+    opcodes::ora_b(cpu, io);
+    // MOV M,A
+    cpu.cycles += 7;
+    opcodes::mov_m_a(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x17A6;
+    }
+    // XRA A
+    cpu.cycles += 4;
+    opcodes::xra_a(cpu, io);
+    // STA $2095
+    cpu.cycles += 13;
+    cpu.write_byte(0x2095, cpu.a);
+    0x17AA
+}
+
+fn block_17aa(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LXI H,#$2099
+    cpu.cycles += 10;
+    cpu.set_hl(0x2099);
+    // DCR M
+    cpu.cycles += 10;
+    opcodes::dcr_m(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x17AE;
+    }
+    // RNZ
+    cpu.cycles += 5;
+    if !cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x17AF
+}
+
+fn block_17af(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI B,#$ef
+    cpu.cycles += 7;
+    cpu.b = 0xEF;
+    // JMP $19dc
+    cpu.cycles += 10;
+    0x19DC
+}
+
+fn block_17c0(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $2067
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2067];
+    // RRC
+    cpu.cycles += 4;
+    opcodes::rrc(cpu, io);
+    // JNC $17ca
+    cpu.cycles += 10;
+    if !cpu.cc.cy {
+        return 0x17CA;
+    }
+    0x17C7
+}
+
+fn block_17c7(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // IN #$01
+    cpu.cycles += 10;
+    cpu.a = io.input(0x01);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_17ca(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // IN #$02
+    cpu.cycles += 10;
+    cpu.a = io.input(0x02);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_17cd(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // IN #$02
+    cpu.cycles += 10;
+    cpu.a = io.input(0x02);
+    // ANI #$04
+    cpu.cycles += 7;
+    cpu.and(0x04);
+    // RZ
+    cpu.cycles += 5;
+    if cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x17D2
+}
+
+fn block_17d2(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $209a
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x209A];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // RNZ
+    cpu.cycles += 5;
+    if !cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x17D7
+}
+
+fn block_17d7(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI SP,#$2400
+    cpu.cycles += 10;
+    cpu.sp = 0x2400;
+    // MVI B,#$04
+    cpu.cycles += 7;
+    cpu.b = 0x04;
+    0x17DC
+}
+
+fn block_17dc(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $09d6
+    cpu.cycles += 17;
+    cpu.push(0x17DF);
+    0x09D6
+}
+
+fn block_17df(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // DCR B
+    cpu.cycles += 5;
+    opcodes::dcr_b(cpu, io);
+    // JNZ $17dc
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x17DC;
+    }
+    0x17E3
+}
+
+fn block_17e3(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI A,#$01
+    cpu.cycles += 7;
+    cpu.a = 0x01;
+    // STA $209a
+    cpu.cycles += 13;
+    cpu.write_byte(0x209A, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x17E8;
+    }
+    // CALL $19d7
+    cpu.cycles += 17;
+    cpu.push(0x17EB);
+    0x19D7
+}
+
+fn block_17eb(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // EI
+    cpu.cycles += 4;
+    opcodes::ei(cpu, io);
+    // LXI D,#$1cbc
+    cpu.cycles += 10;
+    cpu.set_de(0x1CBC);
+    // LXI H,#$3016
+    cpu.cycles += 10;
+    cpu.set_hl(0x3016);
+    // MVI C,#$04
+    cpu.cycles += 7;
+    cpu.c = 0x04;
+    // CALL $0a93
+    cpu.cycles += 17;
+    cpu.push(0x17F7);
+    0x0A93
+}
+
+fn block_17f7(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $0ab1
+    cpu.cycles += 17;
+    cpu.push(0x17FA);
+    0x0AB1
+}
+
+fn block_17fa(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // XRA A
+    cpu.cycles += 4;
+    opcodes::xra_a(cpu, io);
+    // STA $209a
+    cpu.cycles += 13;
+    cpu.write_byte(0x209A, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x17FE;
+    }
+    // STA $2093
+    cpu.cycles += 13;
+    cpu.write_byte(0x2093, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x1801;
+    }
+    // JMP $16c9
+    cpu.cycles += 10;
+    0x16C9
+}
+
+fn block_1804(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LXI H,#$2084
+    cpu.cycles += 10;
+    cpu.set_hl(0x2084);
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JZ $0707
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x0707;
+    }
+    0x180C
+}
+
+fn block_180c(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // RNZ
+    cpu.cycles += 5;
+    if !cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x1810
+}
+
+fn block_1810(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI B,#$01
+    cpu.cycles += 7;
+    cpu.b = 0x01;
+    // JMP $18fa
+    cpu.cycles += 10;
+    0x18FA
+}
+
+fn block_1815(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$2810
+    cpu.cycles += 10;
+    cpu.set_hl(0x2810);
+    // LXI D,#$1ca3
+    cpu.cycles += 10;
+    cpu.set_de(0x1CA3);
+    // MVI C,#$15
+    cpu.cycles += 7;
+    cpu.c = 0x15;
+    // CALL $08f3
+    cpu.cycles += 17;
+    cpu.push(0x1820);
+    0x08F3
+}
+
+fn block_1820(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI A,#$0a
+    cpu.cycles += 7;
+    cpu.a = 0x0A;
+    // STA $206c
+    cpu.cycles += 13;
+    cpu.write_byte(0x206C, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x1825;
+    }
+    // LXI B,#$1dbe
+    cpu.cycles += 10;
+    cpu.set_bc(0x1DBE);
+    0x1828
+}
+
+fn block_1828(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $1856
+    cpu.cycles += 17;
+    cpu.push(0x182B);
+    0x1856
+}
+
+fn block_182b(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JC $1837
+    cpu.cycles += 10;
+    if cpu.cc.cy {
+        return 0x1837;
+    }
+    0x182E
+}
+
+fn block_182e(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $1844
+    cpu.cycles += 17;
+    cpu.push(0x1831);
+    0x1844
+}
+
+fn block_1831(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JMP $1828
+    cpu.cycles += 10;
+    0x1828
+}
+
+fn block_1837(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI B,#$1dcf
+    cpu.cycles += 10;
+    cpu.set_bc(0x1DCF);
+    0x183A
+}
+
+fn block_183a(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $1856
+    cpu.cycles += 17;
+    cpu.push(0x183D);
+    0x1856
+}
+
+fn block_183d(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // RC
+    cpu.cycles += 5;
+    if cpu.cc.cy {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x183E
+}
+
+fn block_183e(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $184c
+    cpu.cycles += 17;
+    cpu.push(0x1841);
+    0x184C
+}
+
+fn block_1841(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JMP $183a
+    cpu.cycles += 10;
+    0x183A
+}
+
+fn block_1844(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // PUSH B
+    cpu.cycles += 11;
+    opcodes::push_b(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x1845;
+    }
+    // MVI B,#$10
+    cpu.cycles += 7;
+    cpu.b = 0x10;
+    // CALL $1439
+    cpu.cycles += 17;
+    cpu.push(0x184A);
+    0x1439
+}
+
+fn block_184a(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // POP B
+    cpu.cycles += 10;
+    opcodes::pop_b(cpu, io);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_184c(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // PUSH B
+    cpu.cycles += 11;
+    opcodes::push_b(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x184D;
+    }
+    // LDA $206c
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x206C];
+    // MOV C,A
+    cpu.cycles += 5;
+    opcodes::mov_c_a(cpu, io);
+    // CALL $0a93
+    cpu.cycles += 17;
+    cpu.push(0x1854);
+    0x0A93
+}
+
+fn block_1854(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // POP B
+    cpu.cycles += 10;
+    opcodes::pop_b(cpu, io);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_1856(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDAX B
+    cpu.cycles += 7;
+    opcodes::ldax_b(cpu, io);
+    // CPI #$ff
+    cpu.cycles += 7;
+    cpu.cmp(0xFF);
+    // STC
+    cpu.cycles += 4;
+    opcodes::stc(cpu, io);
+    // RZ
+    cpu.cycles += 5;
+    if cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x185B
+}
+
+fn block_185b(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV L,A
+    cpu.cycles += 5;
+    opcodes::mov_l_a(cpu, io);
+    // INX B
+    cpu.cycles += 5;
+    opcodes::inx_b(cpu, io);
+    // LDAX B
+    cpu.cycles += 7;
+    opcodes::ldax_b(cpu, io);
+    // MOV H,A
+    cpu.cycles += 5;
+    opcodes::mov_h_a(cpu, io);
+    // INX B
+    cpu.cycles += 5;
+    opcodes::inx_b(cpu, io);
+    // LDAX B
+    cpu.cycles += 7;
+    opcodes::ldax_b(cpu, io);
+    // MOV E,A
+    cpu.cycles += 5;
+    opcodes::mov_e_a(cpu, io);
+    // INX B
+    cpu.cycles += 5;
+    opcodes::inx_b(cpu, io);
+    // LDAX B
+    cpu.cycles += 7;
+    opcodes::ldax_b(cpu, io);
+    // MOV D,A
+    cpu.cycles += 5;
+    opcodes::mov_d_a(cpu, io);
+    // INX B
+    cpu.cycles += 5;
+    opcodes::inx_b(cpu, io);
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_1868(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LXI H,#$20c2
+    cpu.cycles += 10;
+    cpu.set_hl(0x20C2);
+    // INR M
+    cpu.cycles += 10;
+    opcodes::inr_m(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x186C;
+    }
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV C,M
+    cpu.cycles += 7;
+    opcodes::mov_c_m(cpu, io);
+    // CALL $01d9
+    cpu.cycles += 17;
+    cpu.push(0x1871);
+    0x01D9
+}
+
+fn block_1871(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV B,A
+    cpu.cycles += 5;
+    opcodes::mov_b_a(cpu, io);
+    // LDA $20ca
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x20CA];
+    // CMP B
+    cpu.cycles += 4;
+    opcodes::cmp_b(cpu, io);
+    // JZ $1898
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x1898;
+    }
+    0x1879
+}
+
+fn block_1879(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LDA $20c2
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x20C2];
+    // ANI #$04
+    cpu.cycles += 7;
+    cpu.and(0x04);
+    // LDHD $20cc
+    cpu.cycles += 16;
+    cpu.l = cpu.mem[0x20CC];
+    cpu.h = cpu.mem[0x20CD];
+    // JNZ $1888
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x1888;
+    }
+    0x1884
+}
+
+fn block_1884(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LXI D,#$0030
+    cpu.cycles += 10;
+    cpu.set_de(0x0030);
+    // DAD D
+    cpu.cycles += 10;
+    opcodes::dad_d(cpu, io);
+    0x1888
+}
+
+fn block_1888(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // SHLD $20c7
+    cpu.cycles += 16;
+    cpu.write_byte(0x20C7, cpu.l);
+    cpu.write_byte(0x20C8, cpu.h);
+    if !cpu.written.is_empty() {
+        return 0x188B;
+    }
+    // LXI H,#$20c5
+    cpu.cycles += 10;
+    cpu.set_hl(0x20C5);
+    // CALL $1a3b
+    cpu.cycles += 17;
+    cpu.push(0x1891);
+    0x1A3B
+}
+
+fn block_1891(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // XCHG
+    cpu.cycles += 4;
+    opcodes::xchg(cpu, io);
+    // JMP $15d3
+    cpu.cycles += 10;
+    0x15D3
+}
+
+fn block_1898(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI A,#$01
+    cpu.cycles += 7;
+    cpu.a = 0x01;
+    // STA $20cb
+    cpu.cycles += 13;
+    cpu.write_byte(0x20CB, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x189D;
+    }
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_189e(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$2050
+    cpu.cycles += 10;
+    cpu.set_hl(0x2050);
+    // LXI D,#$1bc0
+    cpu.cycles += 10;
+    cpu.set_de(0x1BC0);
+    // MVI B,#$10
+    cpu.cycles += 7;
+    cpu.b = 0x10;
+    // CALL $1a32
+    cpu.cycles += 17;
+    cpu.push(0x18A9);
+    0x1A32
+}
+
+fn block_18a9(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI A,#$02
+    cpu.cycles += 7;
+    cpu.a = 0x02;
+    // STA $2080
+    cpu.cycles += 13;
+    cpu.write_byte(0x2080, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x18AE;
+    }
+    // MVI A,#$ff
+    cpu.cycles += 7;
+    cpu.a = 0xFF;
+    // STA $207e
+    cpu.cycles += 13;
+    cpu.write_byte(0x207E, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x18B3;
+    }
+    // MVI A,#$04
+    cpu.cycles += 7;
+    cpu.a = 0x04;
+    // STA $20c1
+    cpu.cycles += 13;
+    cpu.write_byte(0x20C1, cpu.a);
+    0x18B8
+}
+
+fn block_18b8(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LDA $2055
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2055];
+    // ANI #$01
+    cpu.cycles += 7;
+    cpu.and(0x01);
+    // JZ $18b8
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x18B8;
+    }
+    0x18C0
+}
+
+fn block_18c0(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LDA $2055
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2055];
+    // ANI #$01
+    cpu.cycles += 7;
+    cpu.and(0x01);
+    // JNZ $18c0
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x18C0;
+    }
+    0x18C8
+}
+
+fn block_18c8(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$3311
+    cpu.cycles += 10;
+    cpu.set_hl(0x3311);
+    // MVI A,#$26
+    cpu.cycles += 7;
+    cpu.a = 0x26;
+    // NOP
+    cpu.cycles += 4;
+    // CALL $08ff
+    cpu.cycles += 17;
+    cpu.push(0x18D1);
+    0x08FF
+}
+
+fn block_18d1(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JMP $0ab6
+    cpu.cycles += 10;
+    0x0AB6
+}
+
+fn block_18d4(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI SP,#$2400
+    cpu.cycles += 10;
+    cpu.sp = 0x2400;
+    // MVI B,#$00
+    cpu.cycles += 7;
+    cpu.b = 0x00;
+    // CALL $01e6
+    cpu.cycles += 17;
+    cpu.push(0x18DC);
+    0x01E6
+}
+
+fn block_18dc(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $1956
+    cpu.cycles += 17;
+    cpu.push(0x18DF);
+    0x1956
+}
+
+fn block_18df(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI A,#$08
+    cpu.cycles += 7;
+    cpu.a = 0x08;
+    // STA $20cf
+    cpu.cycles += 13;
+    cpu.write_byte(0x20CF, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x18E4;
+    }
+    // JMP $0aea
+    cpu.cycles += 10;
+    0x0AEA
+}
+
+fn block_18e7(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $2067
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2067];
+    // LXI H,#$20e7
+    cpu.cycles += 10;
+    cpu.set_hl(0x20E7);
+    // RRC
+    cpu.cycles += 4;
+    opcodes::rrc(cpu, io);
+    // RNC
+    cpu.cycles += 5;
+    if !cpu.cc.cy {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x18EF
+}
+
+fn block_18ef(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_18f1(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MVI B,#$02
+    cpu.cycles += 7;
+    cpu.b = 0x02;
+    // LDA $2082
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2082];
+    // DCR A
+    cpu.cycles += 5;
+    opcodes::dcr_a(cpu, io);
+    // RNZ
+    cpu.cycles += 5;
+    if !cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x18F8
+}
+
+fn block_18f8(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // INR B
+    cpu.cycles += 5;
+    opcodes::inr_b(cpu, io);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_18fa(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $2094
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2094];
+    // ORA B
+    cpu.cycles += 4;
+    opcodes::ora_b(cpu, io);
+    // STA $2094
+    cpu.cycles += 13;
+    cpu.write_byte(0x2094, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x1901;
+    }
+    // OUT #$03
+    cpu.cycles += 10;
+    io.output(0x03, cpu.a);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_1904(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$2200
+    cpu.cycles += 10;
+    cpu.set_hl(0x2200);
+    // JMP $01c3
+    cpu.cycles += 10;
+    0x01C3
+}
+
+fn block_190a(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $14d8
+    cpu.cycles += 17;
+    cpu.push(0x190D);
+    0x14D8
+}
+
+fn block_190d(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JMP $1597
+    cpu.cycles += 10;
+    0x1597
+}
+
+fn block_1910(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LXI H,#$20e7
+    cpu.cycles += 10;
+    cpu.set_hl(0x20E7);
+    // LDA $2067
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2067];
+    // RRC
+    cpu.cycles += 4;
+    opcodes::rrc(cpu, io);
+    // RC
+    cpu.cycles += 5;
+    if cpu.cc.cy {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x1918
+}
+
+fn block_1918(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_191a(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI C,#$1c
+    cpu.cycles += 7;
+    cpu.c = 0x1C;
+    // LXI H,#$241e
+    cpu.cycles += 10;
+    cpu.set_hl(0x241E);
+    // LXI D,#$1ae4
+    cpu.cycles += 10;
+    cpu.set_de(0x1AE4);
+    // JMP $08f3
+    cpu.cycles += 10;
+    0x08F3
+}
+
+fn block_1925(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$20f8
+    cpu.cycles += 10;
+    cpu.set_hl(0x20F8);
+    // JMP $1931
+    cpu.cycles += 10;
+    0x1931
+}
+
+fn block_192b(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$20fc
+    cpu.cycles += 10;
+    cpu.set_hl(0x20FC);
+    // JMP $1931
+    cpu.cycles += 10;
+    0x1931
+}
+
+fn block_1931(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV E,M
+    cpu.cycles += 7;
+    opcodes::mov_e_m(cpu, io);
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV D,M
+    cpu.cycles += 7;
+    opcodes::mov_d_m(cpu, io);
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV H,M
+    cpu.cycles += 7;
+    opcodes::mov_h_m(cpu, io);
+    // MOV L,A
+    cpu.cycles += 5;
+    opcodes::mov_l_a(cpu, io);
+    // JMP $09ad
+    cpu.cycles += 10;
+    0x09AD
+}
+
+fn block_193c(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI C,#$07
+    cpu.cycles += 7;
+    cpu.c = 0x07;
+    // LXI H,#$3501
+    cpu.cycles += 10;
+    cpu.set_hl(0x3501);
+    // LXI D,#$1fa9
+    cpu.cycles += 10;
+    cpu.set_de(0x1FA9);
+    // JMP $08f3
+    cpu.cycles += 10;
+    0x08F3
+}
+
+fn block_1947(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LDA $20eb
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x20EB];
+    // LXI H,#$3c01
+    cpu.cycles += 10;
+    cpu.set_hl(0x3C01);
+    // JMP $09b2
+    cpu.cycles += 10;
+    0x09B2
+}
+
+fn block_1950(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$20f4
+    cpu.cycles += 10;
+    cpu.set_hl(0x20F4);
+    // JMP $1931
+    cpu.cycles += 10;
+    0x1931
+}
+
+fn block_1956(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $1a5c
+    cpu.cycles += 17;
+    cpu.push(0x1959);
+    0x1A5C
+}
+
+fn block_1959(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $191a
+    cpu.cycles += 17;
+    cpu.push(0x195C);
+    0x191A
+}
+
+fn block_195c(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $1925
+    cpu.cycles += 17;
+    cpu.push(0x195F);
+    0x1925
+}
+
+fn block_195f(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $192b
+    cpu.cycles += 17;
+    cpu.push(0x1962);
+    0x192B
+}
+
+fn block_1962(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $1950
+    cpu.cycles += 17;
+    cpu.push(0x1965);
+    0x1950
+}
+
+fn block_1965(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $193c
+    cpu.cycles += 17;
+    cpu.push(0x1968);
+    0x193C
+}
+
+fn block_1968(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JMP $1947
+    cpu.cycles += 10;
+    0x1947
+}
+
+fn block_196b(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $19dc
+    cpu.cycles += 17;
+    cpu.push(0x196E);
+    0x19DC
+}
+
+fn block_196e(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JMP $1671
+    cpu.cycles += 10;
+    0x1671
+}
+
+fn block_1971(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI A,#$01
+    cpu.cycles += 7;
+    cpu.a = 0x01;
+    // STA $206d
+    cpu.cycles += 13;
+    cpu.write_byte(0x206D, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x1976;
+    }
+    // JMP $16e6
+    cpu.cycles += 10;
+    0x16E6
+}
+
+fn block_1979(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $19d7
+    cpu.cycles += 17;
+    cpu.push(0x197C);
+    0x19D7
+}
+
+fn block_197c(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $1947
+    cpu.cycles += 17;
+    cpu.push(0x197F);
+    0x1947
+}
+
+fn block_197f(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JMP $193c
+    cpu.cycles += 10;
+    0x193C
+}
+
+fn block_1982(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // STA $20c1
+    cpu.cycles += 13;
+    cpu.write_byte(0x20C1, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x1985;
+    }
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_1988(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // JMP $09d6
+    cpu.cycles += 10;
+    0x09D6
+}
+
+fn block_199a(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $201e
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x201E];
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // JNZ $19ac
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x19AC;
+    }
+    0x19A1
+}
+
+fn block_19a1(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // IN #$01
+    cpu.cycles += 10;
+    cpu.a = io.input(0x01);
+    // ANI #$76
+    cpu.cycles += 7;
+    cpu.and(0x76);
+    // SUI #$72
+    cpu.cycles += 7;
+    cpu.sub(0x72);
+    // RNZ
+    cpu.cycles += 5;
+    if !cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x19A8
+}
+
+fn block_19a8(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // INR A
+    cpu.cycles += 5;
+    opcodes::inr_a(cpu, io);
+    // STA $201e
+    cpu.cycles += 13;
+    cpu.write_byte(0x201E, cpu.a);
+    0x19AC
+}
+
+fn block_19ac(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // IN #$01
+    cpu.cycles += 10;
+    cpu.a = io.input(0x01);
+    // ANI #$76
+    cpu.cycles += 7;
+    cpu.and(0x76);
+    // CPI #$34
+    cpu.cycles += 7;
+    cpu.cmp(0x34);
+    // RNZ
+    cpu.cycles += 5;
+    if !cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x19B3
+}
+
+fn block_19b3(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$2e1b
+    cpu.cycles += 10;
+    cpu.set_hl(0x2E1B);
+    // LXI D,#$0bf7
+    cpu.cycles += 10;
+    cpu.set_de(0x0BF7);
+    // MVI C,#$09
+    cpu.cycles += 7;
+    cpu.c = 0x09;
+    // JMP $08f3
+    cpu.cycles += 10;
+    0x08F3
+}
+
+fn block_19d1(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI A,#$01
+    cpu.cycles += 7;
+    cpu.a = 0x01;
+    0x19D3
+}
+
+fn block_19d3(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // STA $20e9
+    cpu.cycles += 13;
+    cpu.write_byte(0x20E9, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x19D6;
+    }
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_19d7(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // XRA A
+    cpu.cycles += 4;
+    opcodes::xra_a(cpu, io);
+    // JMP $19d3
+    cpu.cycles += 10;
+    0x19D3
+}
+
+fn block_19dc(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDA $2094
+    cpu.cycles += 13;
+    cpu.a = cpu.mem[0x2094];
+    // ANA B
+    cpu.cycles += 4;
+    opcodes::ana_b(cpu, io);
+    // STA $2094
+    cpu.cycles += 13;
+    cpu.write_byte(0x2094, cpu.a);
+    if !cpu.written.is_empty() {
+        return 0x19E3;
+    }
+    // OUT #$03
+    cpu.cycles += 10;
+    io.output(0x03, cpu.a);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_19e6(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$2701
+    cpu.cycles += 10;
+    cpu.set_hl(0x2701);
+    // JZ $19fa
+    cpu.cycles += 10;
+    if cpu.cc.z {
+        return 0x19FA;
+    }
+    0x19EC
+}
+
+fn block_19ec(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LXI D,#$1c60
+    cpu.cycles += 10;
+    cpu.set_de(0x1C60);
+    // MVI B,#$10
+    cpu.cycles += 7;
+    cpu.b = 0x10;
+    // MOV C,A
+    cpu.cycles += 5;
+    opcodes::mov_c_a(cpu, io);
+    // CALL $1439
+    cpu.cycles += 17;
+    cpu.push(0x19F5);
+    0x1439
+}
+
+fn block_19f5(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV A,C
+    cpu.cycles += 5;
+    opcodes::mov_a_c(cpu, io);
+    // DCR A
+    cpu.cycles += 5;
+    opcodes::dcr_a(cpu, io);
+    // JNZ $19ec
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x19EC;
+    }
+    0x19FA
+}
+
+fn block_19fa(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // MVI B,#$10
+    cpu.cycles += 7;
+    cpu.b = 0x10;
+    // CALL $14cb
+    cpu.cycles += 17;
+    cpu.push(0x19FF);
+    0x14CB
+}
+
+fn block_19ff(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV A,H
+    cpu.cycles += 5;
+    opcodes::mov_a_h(cpu, io);
+    // CPI #$35
+    cpu.cycles += 7;
+    cpu.cmp(0x35);
+    // JNZ $19fa
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x19FA;
+    }
+    0x1A05
+}
+
+fn block_1a05(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_1a32(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDAX D
+    cpu.cycles += 7;
+    opcodes::ldax_d(cpu, io);
+    // MOV M,A
+    cpu.cycles += 7;
+    opcodes::mov_m_a(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x1A34;
+    }
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // INX D
+    cpu.cycles += 5;
+    opcodes::inx_d(cpu, io);
+    // DCR B
+    cpu.cycles += 5;
+    opcodes::dcr_b(cpu, io);
+    // JNZ $1a32
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x1A32;
+    }
+    0x1A3A
+}
+
+fn block_1a3a(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_1a3b(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV E,M
+    cpu.cycles += 7;
+    opcodes::mov_e_m(cpu, io);
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV D,M
+    cpu.cycles += 7;
+    opcodes::mov_d_m(cpu, io);
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV A,M
+    cpu.cycles += 7;
+    opcodes::mov_a_m(cpu, io);
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV C,M
+    cpu.cycles += 7;
+    opcodes::mov_c_m(cpu, io);
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV B,M
+    cpu.cycles += 7;
+    opcodes::mov_b_m(cpu, io);
+    // MOV H,C
+    cpu.cycles += 5;
+    opcodes::mov_h_c(cpu, io);
+    // MOV L,A
+    cpu.cycles += 5;
+    opcodes::mov_l_a(cpu, io);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_1a47(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // PUSH B
+    cpu.cycles += 11;
+    opcodes::push_b(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x1A48;
+    }
+    // MVI B,#$03
+    cpu.cycles += 7;
+    cpu.b = 0x03;
+    0x1A4A
+}
+
+fn block_1a4a(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV A,H
+    cpu.cycles += 5;
+    opcodes::mov_a_h(cpu, io);
+    // RAR
+    cpu.cycles += 4;
+    opcodes::rar(cpu, io);
+    // MOV H,A
+    cpu.cycles += 5;
+    opcodes::mov_h_a(cpu, io);
+    // MOV A,L
+    cpu.cycles += 5;
+    opcodes::mov_a_l(cpu, io);
+    // RAR
+    cpu.cycles += 4;
+    opcodes::rar(cpu, io);
+    // MOV L,A
+    cpu.cycles += 5;
+    opcodes::mov_l_a(cpu, io);
+    // DCR B
+    cpu.cycles += 5;
+    opcodes::dcr_b(cpu, io);
+    // JNZ $1a4a
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x1A4A;
+    }
+    0x1A54
+}
+
+fn block_1a54(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MOV A,H
+    cpu.cycles += 5;
+    opcodes::mov_a_h(cpu, io);
+    // ANI #$3f
+    cpu.cycles += 7;
+    cpu.and(0x3F);
+    // ORI #$20
+    cpu.cycles += 7;
+    cpu.or(0x20);
+    // MOV H,A
+    cpu.cycles += 5;
+    opcodes::mov_h_a(cpu, io);
+    // POP B
+    cpu.cycles += 10;
+    opcodes::pop_b(cpu, io);
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_1a5c(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$2400
+    cpu.cycles += 10;
+    cpu.set_hl(0x2400);
+    0x1A5F
+}
+
+fn block_1a5f(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // MVI M,#$00
+    cpu.cycles += 10;
+    cpu.write_byte(cpu.hl() as usize, 0x00);
+    if !cpu.written.is_empty() {
+        return 0x1A61;
+    }
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // MOV A,H
+    cpu.cycles += 5;
+    opcodes::mov_a_h(cpu, io);
+    // CPI #$40
+    cpu.cycles += 7;
+    cpu.cmp(0x40);
+    // JNZ $1a5f
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x1A5F;
+    }
+    0x1A68
+}
+
+fn block_1a68(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_1a69(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // PUSH B
+    cpu.cycles += 11;
+    opcodes::push_b(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x1A6A;
+    }
+    // PUSH H
+    cpu.cycles += 11;
+    opcodes::push_h(cpu, io);
+    0x1A6B
+}
+
+fn block_1a6b(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // LDAX D
+    cpu.cycles += 7;
+    opcodes::ldax_d(cpu, io);
+    // ORA M
+    cpu.cycles += 7;
+    opcodes::ora_m(cpu, io);
+    // MOV M,A
+    cpu.cycles += 7;
+    opcodes::mov_m_a(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x1A6E;
+    }
+    // INX D
+    cpu.cycles += 5;
+    opcodes::inx_d(cpu, io);
+    // INX H
+    cpu.cycles += 5;
+    opcodes::inx_h(cpu, io);
+    // DCR C
+    cpu.cycles += 5;
+    opcodes::dcr_c(cpu, io);
+    // JNZ $1a6b
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x1A6B;
+    }
+    0x1A74
+}
+
+fn block_1a74(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // POP H
+    cpu.cycles += 10;
+    opcodes::pop_h(cpu, io);
+    // LXI B,#$0020
+    cpu.cycles += 10;
+    cpu.set_bc(0x0020);
+    // DAD B
+    cpu.cycles += 10;
+    opcodes::dad_b(cpu, io);
+    // POP B
+    cpu.cycles += 10;
+    opcodes::pop_b(cpu, io);
+    // DCR B
+    cpu.cycles += 5;
+    opcodes::dcr_b(cpu, io);
+    // JNZ $1a69
+    cpu.cycles += 10;
+    if !cpu.cc.z {
+        return 0x1A69;
+    }
+    0x1A7E
+}
+
+fn block_1a7e(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // RET
+    cpu.cycles += 10;
+    cpu.pop() as usize
+}
+
+fn block_1a7f(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // CALL $092e
+    cpu.cycles += 17;
+    cpu.push(0x1A82);
+    0x092E
+}
+
+fn block_1a82(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // ANA A
+    cpu.cycles += 4;
+    opcodes::ana_a(cpu, io);
+    // RZ
+    cpu.cycles += 5;
+    if cpu.cc.z {
+        cpu.cycles += 6;
+        return cpu.pop() as usize;
+    }
+    0x1A84
+}
+
+fn block_1a84(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // PUSH PSW
+    cpu.cycles += 11;
+    opcodes::push_psw(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x1A85;
+    }
+    // DCR A
+    cpu.cycles += 5;
+    opcodes::dcr_a(cpu, io);
+    // MOV M,A
+    cpu.cycles += 7;
+    opcodes::mov_m_a(cpu, io);
+    if !cpu.written.is_empty() {
+        return 0x1A87;
+    }
+    // CALL $19e6
+    cpu.cycles += 17;
+    cpu.push(0x1A8A);
+    0x19E6
+}
+
+fn block_1a8a(cpu: &mut State, io: &mut dyn Io) -> usize {
+    // POP PSW
+    cpu.cycles += 10;
+    opcodes::pop_psw(cpu, io);
+    0x1A8B
+}
+
+fn block_1a8b(cpu: &mut State, _io: &mut dyn Io) -> usize {
+    // LXI H,#$2501
+    cpu.cycles += 10;
+    cpu.set_hl(0x2501);
+    // ANI #$0f
+    cpu.cycles += 7;
+    cpu.and(0x0F);
+    // JMP $09c5
+    cpu.cycles += 10;
+    0x09C5
+}
@@ -0,0 +1,496 @@
+// One named test per opcode. Each runs a single instruction at 0x0100 from a known
+// machine state and compares every register, flag and byte of memory afterwards.
+
+use super::*;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Snapshot {
+    a: u8,
+    b: u8,
+    c: u8,
+    d: u8,
+    e: u8,
+    h: u8,
+    l: u8,
+    sp: u16,
+    pc: u16,
+    z: bool,
+    s: bool,
+    p: bool,
+    cy: bool,
+    ac: bool,
+    int_enable: bool,
+    halted: bool,
+    mem: Vec<u8>,
+}
+
+impl Snapshot {
+    // BC, DE and HL point at marked bytes, and the stack holds a return address of 0x1234
+    fn initial() -> Snapshot {
+        let mut mem = vec![0; 0x10000];
+        mem[0x0B0C] = 0xBC;
+        mem[0x0D0E] = 0xDE;
+        mem[0x2040] = 0x5A;
+        mem[0x3000] = 0x34;
+        mem[0x3001] = 0x12;
+
+        Snapshot {
+            a: 0x0A,
+            b: 0x0B,
+            c: 0x0C,
+            d: 0x0D,
+            e: 0x0E,
+            h: 0x20,
+            l: 0x40,
+            sp: 0x3000,
+            pc: 0x0100,
+            z: false,
+            s: false,
+            p: false,
+            cy: false,
+            ac: false,
+            int_enable: false,
+            halted: false,
+            mem,
+        }
+    }
+
+    fn run(&self) -> Snapshot {
+        let mut mem = self.mem.clone();
+        let mut state = State::new(&mut mem);
+        state.a = self.a;
+        state.b = self.b;
+        state.c = self.c;
+        state.d = self.d;
+        state.e = self.e;
+        state.h = self.h;
+        state.l = self.l;
+        state.sp = self.sp as usize;
+        state.pc = self.pc as usize;
+        state.cc.z = self.z;
+        state.cc.s = self.s;
+        state.cc.p = self.p;
+        state.cc.cy = self.cy;
+        state.cc.ac = self.ac;
+        state.int_enable = self.int_enable;
+        state.halted = self.halted;
+
+        state.step();
+
+        Snapshot {
+            a: state.a,
+            b: state.b,
+            c: state.c,
+            d: state.d,
+            e: state.e,
+            h: state.h,
+            l: state.l,
+            sp: state.sp as u16,
+            pc: state.pc as u16,
+            z: state.cc.z,
+            s: state.cc.s,
+            p: state.cc.p,
+            cy: state.cc.cy,
+            ac: state.cc.ac,
+            int_enable: state.int_enable,
+            halted: state.halted,
+            mem: state.mem.to_vec(),
+        }
+    }
+}
+
+fn check(code: &[u8], setup: fn(&mut Snapshot), expect: fn(&mut Snapshot)) {
+    let mut initial = Snapshot::initial();
+    initial.mem[0x0100..0x0100 + code.len()].copy_from_slice(code);
+    setup(&mut initial);
+
+    // Unless the case says otherwise, only PC moves, past the instruction
+    let mut expected = initial.clone();
+    expected.pc = 0x0100 + code.len() as u16;
+    expect(&mut expected);
+
+    let actual = initial.run();
+
+    let changed: Vec<String> = (0..0x10000)
+        .filter(|i| actual.mem[*i] != expected.mem[*i])
+        .map(|i| {
+            format!(
+                "{:04X}: {:02X} != {:02X}",
+                i, actual.mem[i], expected.mem[i]
+            )
+        })
+        .collect();
+    assert!(changed.is_empty(), "memory differs at {:?}", changed);
+
+    assert_eq!(
+        Snapshot {
+            mem: Vec::new(),
+            ..actual
+        },
+        Snapshot {
+            mem: Vec::new(),
+            ..expected
+        }
+    );
+}
+
+macro_rules! opcode_tests {
+    ($($name:ident: [$($byte:expr),+], |$setup:pat| $setup_body:block, |$expect:pat| $expect_body:block;)+) => {
+        $(
+            #[test]
+            fn $name() {
+                check(&[$($byte),+], |$setup| $setup_body, |$expect| $expect_body);
+            }
+        )+
+
+        #[test]
+        fn every_opcode_covered() {
+            let mut covered = [false; 256];
+            $(covered[[$($byte),+][0] as usize] = true;)+
+
+            let missing: Vec<usize> = (0..256).filter(|op| !covered[*op]).collect();
+            assert!(missing.is_empty(), "no test for opcodes {:02X?}", missing);
+        }
+    };
+}
+
+opcode_tests! {
+    nop: [0x00], |_| {}, |_| {};
+    lxi_b: [0x01, 0x34, 0x12], |_| {}, |m| { m.b = 0x12; m.c = 0x34; };
+    stax_b: [0x02], |_| {}, |m| { m.mem[0x0B0C] = 0x0A; };
+    inx_b: [0x03], |_| {}, |m| { m.c = 0x0D; };
+    inr_b: [0x04], |_| {}, |m| { m.b = 0x0C; m.p = true; };
+    dcr_b: [0x05], |m| { m.b = 0x20; }, |m| { m.b = 0x1F; };
+    mvi_b: [0x06, 0x77], |_| {}, |m| { m.b = 0x77; };
+    rlc: [0x07], |m| { m.a = 0x85; }, |m| { m.a = 0x0B; m.cy = true; };
+    nop_08: [0x08], |_| {}, |_| {};
+    dad_b: [0x09], |_| {}, |m| { m.h = 0x2B; m.l = 0x4C; };
+    ldax_b: [0x0A], |_| {}, |m| { m.a = 0xBC; };
+    dcx_b: [0x0B], |_| {}, |m| { m.c = 0x0B; };
+    inr_c: [0x0C], |_| {}, |m| { m.c = 0x0D; };
+    dcr_c: [0x0D], |m| { m.c = 0x90; }, |m| { m.c = 0x8F; m.s = true; };
+    mvi_c: [0x0E, 0x77], |_| {}, |m| { m.c = 0x77; };
+    rrc: [0x0F], |m| { m.a = 0x0B; }, |m| { m.a = 0x85; m.cy = true; };
+
+    nop_10: [0x10], |_| {}, |_| {};
+    lxi_d: [0x11, 0x34, 0x12], |_| {}, |m| { m.d = 0x12; m.e = 0x34; };
+    stax_d: [0x12], |_| {}, |m| { m.mem[0x0D0E] = 0x0A; };
+    inx_d: [0x13], |_| {}, |m| { m.e = 0x0F; };
+    inr_d: [0x14], |_| {}, |m| { m.d = 0x0E; };
+    dcr_d: [0x15], |m| { m.d = 0x30; }, |m| { m.d = 0x2F; };
+    mvi_d: [0x16, 0x77], |_| {}, |m| { m.d = 0x77; };
+    ral: [0x17], |m| { m.a = 0x85; }, |m| { m.a = 0x0A; m.cy = true; };
+    nop_18: [0x18], |_| {}, |_| {};
+    dad_d: [0x19], |_| {}, |m| { m.h = 0x2D; m.l = 0x4E; };
+    ldax_d: [0x1A], |_| {}, |m| { m.a = 0xDE; };
+    dcx_d: [0x1B], |_| {}, |m| { m.e = 0x0D; };
+    inr_e: [0x1C], |_| {}, |m| { m.e = 0x0F; m.p = true; };
+    dcr_e: [0x1D], |m| { m.e = 0x50; }, |m| { m.e = 0x4F; };
+    mvi_e: [0x1E, 0x77], |_| {}, |m| { m.e = 0x77; };
+    rar: [0x1F], |m| { m.a = 0x0B; m.cy = true; }, |m| { m.a = 0x85; m.cy = true; };
+
+    nop_20: [0x20], |_| {}, |_| {};
+    lxi_h: [0x21, 0x34, 0x12], |_| {}, |m| { m.h = 0x12; m.l = 0x34; };
+    shld: [0x22, 0x00, 0x50], |_| {}, |m| { m.mem[0x5000] = 0x40; m.mem[0x5001] = 0x20; };
+    inx_h: [0x23], |_| {}, |m| { m.l = 0x41; };
+    inr_h: [0x24], |_| {}, |m| { m.h = 0x21; m.p = true; };
+    dcr_h: [0x25], |m| { m.h = 0x60; }, |m| { m.h = 0x5F; m.p = true; };
+    mvi_h: [0x26, 0x77], |_| {}, |m| { m.h = 0x77; };
+    daa: [0x27], |m| { m.a = 0x9B; }, |m| { m.a = 0x01; m.cy = true; m.ac = true; };
+    nop_28: [0x28], |_| {}, |_| {};
+    dad_h: [0x29], |_| {}, |m| { m.h = 0x40; m.l = 0x80; };
+    lhld: [0x2A, 0x00, 0x50], |m| { m.mem[0x5000] = 0x78; m.mem[0x5001] = 0x56; }, |m| { m.l = 0x78; m.h = 0x56; };
+    dcx_h: [0x2B], |_| {}, |m| { m.l = 0x3F; };
+    inr_l: [0x2C], |_| {}, |m| { m.l = 0x41; m.p = true; };
+    dcr_l: [0x2D], |_| {}, |m| { m.l = 0x3F; m.p = true; };
+    mvi_l: [0x2E, 0x77], |_| {}, |m| { m.l = 0x77; };
+    cma: [0x2F], |_| {}, |m| { m.a = 0xF5; };
+
+    nop_30: [0x30], |_| {}, |_| {};
+    lxi_sp: [0x31, 0x34, 0x12], |_| {}, |m| { m.sp = 0x1234; };
+    sta: [0x32, 0x00, 0x50], |_| {}, |m| { m.mem[0x5000] = 0x0A; };
+    inx_sp: [0x33], |_| {}, |m| { m.sp = 0x3001; };
+    inr_m: [0x34], |_| {}, |m| { m.mem[0x2040] = 0x5B; };
+    dcr_m: [0x35], |m| { m.mem[0x2040] = 0x10; }, |m| { m.mem[0x2040] = 0x0F; m.p = true; };
+    mvi_m: [0x36, 0x77], |_| {}, |m| { m.mem[0x2040] = 0x77; };
+    stc: [0x37], |_| {}, |m| { m.cy = true; };
+    nop_38: [0x38], |_| {}, |_| {};
+    dad_sp: [0x39], |m| { m.sp = 0xF000; }, |m| { m.h = 0x10; m.l = 0x40; m.cy = true; };
+    lda: [0x3A, 0x00, 0x50], |m| { m.mem[0x5000] = 0x99; }, |m| { m.a = 0x99; };
+    dcx_sp: [0x3B], |_| {}, |m| { m.sp = 0x2FFF; };
+    inr_a: [0x3C], |_| {}, |m| { m.a = 0x0B; };
+    dcr_a: [0x3D], |m| { m.a = 0x80; }, |m| { m.a = 0x7F; };
+    mvi_a: [0x3E, 0x77], |_| {}, |m| { m.a = 0x77; };
+    cmc: [0x3F], |_| {}, |m| { m.cy = true; };
+
+    mov_b_b: [0x40], |_| {}, |_| {};
+    mov_b_c: [0x41], |_| {}, |m| { m.b = 0x0C; };
+    mov_b_d: [0x42], |_| {}, |m| { m.b = 0x0D; };
+    mov_b_e: [0x43], |_| {}, |m| { m.b = 0x0E; };
+    mov_b_h: [0x44], |_| {}, |m| { m.b = 0x20; };
+    mov_b_l: [0x45], |_| {}, |m| { m.b = 0x40; };
+    mov_b_m: [0x46], |_| {}, |m| { m.b = 0x5A; };
+    mov_b_a: [0x47], |_| {}, |m| { m.b = 0x0A; };
+    mov_c_b: [0x48], |_| {}, |m| { m.c = 0x0B; };
+    mov_c_c: [0x49], |_| {}, |_| {};
+    mov_c_d: [0x4A], |_| {}, |m| { m.c = 0x0D; };
+    mov_c_e: [0x4B], |_| {}, |m| { m.c = 0x0E; };
+    mov_c_h: [0x4C], |_| {}, |m| { m.c = 0x20; };
+    mov_c_l: [0x4D], |_| {}, |m| { m.c = 0x40; };
+    mov_c_m: [0x4E], |_| {}, |m| { m.c = 0x5A; };
+    mov_c_a: [0x4F], |_| {}, |m| { m.c = 0x0A; };
+
+    mov_d_b: [0x50], |_| {}, |m| { m.d = 0x0B; };
+    mov_d_c: [0x51], |_| {}, |m| { m.d = 0x0C; };
+    mov_d_d: [0x52], |_| {}, |_| {};
+    mov_d_e: [0x53], |_| {}, |m| { m.d = 0x0E; };
+    mov_d_h: [0x54], |_| {}, |m| { m.d = 0x20; };
+    mov_d_l: [0x55], |_| {}, |m| { m.d = 0x40; };
+    mov_d_m: [0x56], |_| {}, |m| { m.d = 0x5A; };
+    mov_d_a: [0x57], |_| {}, |m| { m.d = 0x0A; };
+    mov_e_b: [0x58], |_| {}, |m| { m.e = 0x0B; };
+    mov_e_c: [0x59], |_| {}, |m| { m.e = 0x0C; };
+    mov_e_d: [0x5A], |_| {}, |m| { m.e = 0x0D; };
+    mov_e_e: [0x5B], |_| {}, |_| {};
+    mov_e_h: [0x5C], |_| {}, |m| { m.e = 0x20; };
+    mov_e_l: [0x5D], |_| {}, |m| { m.e = 0x40; };
+    mov_e_m: [0x5E], |_| {}, |m| { m.e = 0x5A; };
+    mov_e_a: [0x5F], |_| {}, |m| { m.e = 0x0A; };
+
+    mov_h_b: [0x60], |_| {}, |m| { m.h = 0x0B; };
+    mov_h_c: [0x61], |_| {}, |m| { m.h = 0x0C; };
+    mov_h_d: [0x62], |_| {}, |m| { m.h = 0x0D; };
+    mov_h_e: [0x63], |_| {}, |m| { m.h = 0x0E; };
+    mov_h_h: [0x64], |_| {}, |_| {};
+    mov_h_l: [0x65], |_| {}, |m| { m.h = 0x40; };
+    mov_h_m: [0x66], |_| {}, |m| { m.h = 0x5A; };
+    mov_h_a: [0x67], |_| {}, |m| { m.h = 0x0A; };
+    mov_l_b: [0x68], |_| {}, |m| { m.l = 0x0B; };
+    mov_l_c: [0x69], |_| {}, |m| { m.l = 0x0C; };
+    mov_l_d: [0x6A], |_| {}, |m| { m.l = 0x0D; };
+    mov_l_e: [0x6B], |_| {}, |m| { m.l = 0x0E; };
+    mov_l_h: [0x6C], |_| {}, |m| { m.l = 0x20; };
+    mov_l_l: [0x6D], |_| {}, |_| {};
+    mov_l_m: [0x6E], |_| {}, |m| { m.l = 0x5A; };
+    mov_l_a: [0x6F], |_| {}, |m| { m.l = 0x0A; };
+
+    mov_m_b: [0x70], |_| {}, |m| { m.mem[0x2040] = 0x0B; };
+    mov_m_c: [0x71], |_| {}, |m| { m.mem[0x2040] = 0x0C; };
+    mov_m_d: [0x72], |_| {}, |m| { m.mem[0x2040] = 0x0D; };
+    mov_m_e: [0x73], |_| {}, |m| { m.mem[0x2040] = 0x0E; };
+    mov_m_h: [0x74], |_| {}, |m| { m.mem[0x2040] = 0x20; };
+    mov_m_l: [0x75], |_| {}, |m| { m.mem[0x2040] = 0x40; };
+    hlt: [0x76], |_| {}, |m| { m.halted = true; };
+    mov_m_a: [0x77], |_| {}, |m| { m.mem[0x2040] = 0x0A; };
+    mov_a_b: [0x78], |_| {}, |m| { m.a = 0x0B; };
+    mov_a_c: [0x79], |_| {}, |m| { m.a = 0x0C; };
+    mov_a_d: [0x7A], |_| {}, |m| { m.a = 0x0D; };
+    mov_a_e: [0x7B], |_| {}, |m| { m.a = 0x0E; };
+    mov_a_h: [0x7C], |_| {}, |m| { m.a = 0x20; };
+    mov_a_l: [0x7D], |_| {}, |m| { m.a = 0x40; };
+    mov_a_m: [0x7E], |_| {}, |m| { m.a = 0x5A; };
+    mov_a_a: [0x7F], |_| {}, |_| {};
+
+    add_b: [0x80], |m| { m.a = 0xF0; m.b = 0x20; }, |m| { m.a = 0x10; m.cy = true; };
+    add_c: [0x81], |m| { m.a = 0x21; }, |m| { m.a = 0x2D; m.p = true; };
+    add_d: [0x82], |m| { m.a = 0x21; }, |m| { m.a = 0x2E; m.p = true; };
+    add_e: [0x83], |m| { m.a = 0x21; }, |m| { m.a = 0x2F; };
+    add_h: [0x84], |m| { m.a = 0x21; }, |m| { m.a = 0x41; m.p = true; };
+    add_l: [0x85], |m| { m.a = 0x21; }, |m| { m.a = 0x61; };
+    add_m: [0x86], |m| { m.a = 0x21; }, |m| { m.a = 0x7B; m.p = true; };
+    add_a: [0x87], |m| { m.a = 0x21; }, |m| { m.a = 0x42; m.p = true; };
+    adc_b: [0x88], |m| { m.a = 0x21; m.cy = true; }, |m| { m.a = 0x2D; m.p = true; m.cy = false; };
+    adc_c: [0x89], |m| { m.a = 0x21; m.cy = true; }, |m| { m.a = 0x2E; m.p = true; m.cy = false; };
+    adc_d: [0x8A], |m| { m.a = 0x21; m.cy = true; }, |m| { m.a = 0x2F; m.cy = false; };
+    adc_e: [0x8B], |m| { m.a = 0x20; m.cy = true; }, |m| { m.a = 0x2F; m.cy = false; };
+    adc_h: [0x8C], |m| { m.a = 0x21; m.cy = true; }, |m| { m.a = 0x42; m.p = true; m.cy = false; };
+    adc_l: [0x8D], |m| { m.a = 0x21; m.cy = true; }, |m| { m.a = 0x62; m.cy = false; };
+    adc_m: [0x8E], |m| { m.a = 0x21; m.cy = true; }, |m| { m.a = 0x7C; m.cy = false; };
+    adc_a: [0x8F], |m| { m.a = 0x21; m.cy = true; }, |m| { m.a = 0x43; m.cy = false; };
+
+    sub_b: [0x90], |m| { m.a = 0x50; }, |m| { m.a = 0x45; };
+    sub_c: [0x91], |m| { m.a = 0x50; }, |m| { m.a = 0x44; m.p = true; };
+    sub_d: [0x92], |m| { m.a = 0x50; }, |m| { m.a = 0x43; };
+    sub_e: [0x93], |m| { m.a = 0x50; }, |m| { m.a = 0x42; m.p = true; };
+    sub_h: [0x94], |m| { m.a = 0x50; m.h = 0x2F; }, |m| { m.a = 0x21; m.p = true; };
+    sub_l: [0x95], |m| { m.a = 0x50; m.l = 0x3F; }, |m| { m.a = 0x11; m.p = true; };
+    sub_m: [0x96], |m| { m.a = 0x70; }, |m| { m.a = 0x16; };
+    sub_a: [0x97], |m| { m.ac = true; }, |m| { m.a = 0x00; m.z = true; m.p = true; };
+    sbb_b: [0x98], |m| { m.a = 0x50; m.cy = true; }, |m| { m.a = 0x44; m.p = true; m.cy = false; };
+    sbb_c: [0x99], |m| { m.a = 0x50; m.cy = true; }, |m| { m.a = 0x43; m.cy = false; };
+    sbb_d: [0x9A], |m| { m.a = 0x50; m.cy = true; }, |m| { m.a = 0x42; m.p = true; m.cy = false; };
+    sbb_e: [0x9B], |m| { m.a = 0x50; m.cy = true; }, |m| { m.a = 0x41; m.p = true; m.cy = false; };
+    sbb_h: [0x9C], |m| { m.a = 0x50; m.h = 0x2F; m.cy = true; }, |m| { m.a = 0x20; m.cy = false; };
+    sbb_l: [0x9D], |m| { m.a = 0x50; m.l = 0x3F; m.cy = true; }, |m| { m.a = 0x10; m.cy = false; };
+    sbb_m: [0x9E], |m| { m.a = 0x70; m.cy = true; }, |m| { m.a = 0x15; m.cy = false; };
+    sbb_a: [0x9F], |m| { m.ac = true; }, |m| { m.a = 0x00; m.z = true; m.p = true; };
+
+    ana_b: [0xA0], |m| { m.cy = true; m.ac = true; }, |m| { m.a = 0x0A; m.p = true; m.cy = false; };
+    ana_c: [0xA1], |m| { m.cy = true; m.ac = true; }, |m| { m.a = 0x08; m.cy = false; };
+    ana_d: [0xA2], |m| { m.cy = true; m.ac = true; }, |m| { m.a = 0x08; m.cy = false; };
+    ana_e: [0xA3], |m| { m.cy = true; m.ac = true; }, |m| { m.a = 0x0A; m.p = true; m.cy = false; };
+    ana_h: [0xA4], |m| { m.cy = true; m.ac = true; }, |m| { m.a = 0x00; m.z = true; m.p = true; m.cy = false; };
+    ana_l: [0xA5], |m| { m.cy = true; m.ac = true; }, |m| { m.a = 0x00; m.z = true; m.p = true; m.cy = false; };
+    ana_m: [0xA6], |m| { m.cy = true; m.ac = true; }, |m| { m.a = 0x0A; m.p = true; m.cy = false; };
+    ana_a: [0xA7], |m| { m.cy = true; m.ac = true; }, |m| { m.a = 0x0A; m.p = true; m.cy = false; };
+    xra_b: [0xA8], |m| { m.cy = true; }, |m| { m.a = 0x01; m.cy = false; };
+    xra_c: [0xA9], |m| { m.cy = true; }, |m| { m.a = 0x06; m.p = true; m.cy = false; };
+    xra_d: [0xAA], |m| { m.cy = true; }, |m| { m.a = 0x07; m.cy = false; };
+    xra_e: [0xAB], |m| { m.cy = true; }, |m| { m.a = 0x04; m.cy = false; };
+    xra_h: [0xAC], |m| { m.cy = true; }, |m| { m.a = 0x2A; m.cy = false; };
+    xra_l: [0xAD], |m| { m.cy = true; }, |m| { m.a = 0x4A; m.cy = false; };
+    xra_m: [0xAE], |m| { m.cy = true; }, |m| { m.a = 0x50; m.p = true; m.cy = false; };
+    xra_a: [0xAF], |m| { m.cy = true; }, |m| { m.a = 0x00; m.z = true; m.p = true; m.cy = false; };
+
+    ora_b: [0xB0], |m| { m.cy = true; }, |m| { m.a = 0x0B; m.cy = false; };
+    ora_c: [0xB1], |m| { m.cy = true; }, |m| { m.a = 0x0E; m.cy = false; };
+    ora_d: [0xB2], |m| { m.cy = true; }, |m| { m.a = 0x0F; m.p = true; m.cy = false; };
+    ora_e: [0xB3], |m| { m.cy = true; }, |m| { m.a = 0x0E; m.cy = false; };
+    ora_h: [0xB4], |m| { m.cy = true; }, |m| { m.a = 0x2A; m.cy = false; };
+    ora_l: [0xB5], |m| { m.cy = true; }, |m| { m.a = 0x4A; m.cy = false; };
+    ora_m: [0xB6], |m| { m.cy = true; }, |m| { m.a = 0x5A; m.p = true; m.cy = false; };
+    ora_a: [0xB7], |m| { m.cy = true; }, |m| { m.a = 0x0A; m.p = true; m.cy = false; };
+    cmp_b: [0xB8], |m| { m.a = 0x50; }, |_| {};
+    cmp_c: [0xB9], |m| { m.a = 0x50; }, |m| { m.p = true; };
+    cmp_d: [0xBA], |m| { m.a = 0x50; }, |_| {};
+    cmp_e: [0xBB], |m| { m.a = 0x50; }, |m| { m.p = true; };
+    cmp_h: [0xBC], |m| { m.a = 0x50; m.h = 0x2F; }, |m| { m.p = true; };
+    cmp_l: [0xBD], |m| { m.a = 0x50; m.l = 0x3F; }, |m| { m.p = true; };
+    cmp_m: [0xBE], |m| { m.a = 0x70; }, |_| {};
+    cmp_a: [0xBF], |m| { m.ac = true; }, |m| { m.z = true; m.p = true; };
+
+    rnz_taken: [0xC0], |_| {}, |m| { m.pc = 0x1234; m.sp = 0x3002; };
+    pop_b: [0xC1], |_| {}, |m| { m.b = 0x12; m.c = 0x34; m.sp = 0x3002; };
+    jnz_taken: [0xC2, 0x00, 0x40], |_| {}, |m| { m.pc = 0x4000; };
+    jmp: [0xC3, 0x00, 0x40], |_| {}, |m| { m.pc = 0x4000; };
+    cnz_taken: [0xC4, 0x00, 0x40], |_| {}, |m| {
+        m.mem[0x2FFF] = 0x01;
+        m.mem[0x2FFE] = 0x03;
+        m.sp = 0x2FFE;
+        m.pc = 0x4000;
+    };
+    push_b: [0xC5], |_| {}, |m| { m.mem[0x2FFF] = 0x0B; m.mem[0x2FFE] = 0x0C; m.sp = 0x2FFE; };
+    adi: [0xC6, 0x11], |_| {}, |m| { m.a = 0x1B; m.p = true; };
+    rst_0: [0xC7], |_| {}, |m| { m.mem[0x2FFF] = 0x01; m.mem[0x2FFE] = 0x01; m.sp = 0x2FFE; m.pc = 0x0000; };
+    rz_taken: [0xC8], |m| { m.z = true; }, |m| { m.pc = 0x1234; m.sp = 0x3002; };
+    rz_not_taken: [0xC8], |_| {}, |_| {};
+    ret: [0xC9], |_| {}, |m| { m.pc = 0x1234; m.sp = 0x3002; };
+    jz_not_taken: [0xCA, 0x00, 0x40], |_| {}, |_| {};
+    jmp_cb: [0xCB, 0x00, 0x40], |_| {}, |m| { m.pc = 0x4000; };
+    cz_taken: [0xCC, 0x00, 0x40], |m| { m.z = true; }, |m| {
+        m.mem[0x2FFF] = 0x01;
+        m.mem[0x2FFE] = 0x03;
+        m.sp = 0x2FFE;
+        m.pc = 0x4000;
+    };
+    call: [0xCD, 0x00, 0x40], |_| {}, |m| {
+        m.mem[0x2FFF] = 0x01;
+        m.mem[0x2FFE] = 0x03;
+        m.sp = 0x2FFE;
+        m.pc = 0x4000;
+    };
+    aci: [0xCE, 0x11], |m| { m.cy = true; }, |m| { m.a = 0x1C; m.cy = false; };
+    rst_1: [0xCF], |_| {}, |m| { m.mem[0x2FFF] = 0x01; m.mem[0x2FFE] = 0x01; m.sp = 0x2FFE; m.pc = 0x0008; };
+
+    rnc_not_taken: [0xD0], |m| { m.cy = true; }, |_| {};
+    pop_d: [0xD1], |_| {}, |m| { m.d = 0x12; m.e = 0x34; m.sp = 0x3002; };
+    jnc_taken: [0xD2, 0x00, 0x40], |_| {}, |m| { m.pc = 0x4000; };
+    out: [0xD3, 0x10], |_| {}, |_| {};
+    cnc_not_taken: [0xD4, 0x00, 0x40], |m| { m.cy = true; }, |_| {};
+    push_d: [0xD5], |_| {}, |m| { m.mem[0x2FFF] = 0x0D; m.mem[0x2FFE] = 0x0E; m.sp = 0x2FFE; };
+    sui: [0xD6, 0x05], |m| { m.a = 0x50; }, |m| { m.a = 0x4B; m.p = true; };
+    rst_2: [0xD7], |_| {}, |m| { m.mem[0x2FFF] = 0x01; m.mem[0x2FFE] = 0x01; m.sp = 0x2FFE; m.pc = 0x0010; };
+    rc_taken: [0xD8], |m| { m.cy = true; }, |m| { m.pc = 0x1234; m.sp = 0x3002; };
+    ret_d9: [0xD9], |_| {}, |m| { m.pc = 0x1234; m.sp = 0x3002; };
+    jc_taken: [0xDA, 0x00, 0x40], |m| { m.cy = true; }, |m| { m.pc = 0x4000; };
+    r#in: [0xDB, 0x10], |_| {}, |m| { m.a = 0xFF; };
+    cc_not_taken: [0xDC, 0x00, 0x40], |_| {}, |_| {};
+    call_dd: [0xDD, 0x00, 0x40], |_| {}, |m| {
+        m.mem[0x2FFF] = 0x01;
+        m.mem[0x2FFE] = 0x03;
+        m.sp = 0x2FFE;
+        m.pc = 0x4000;
+    };
+    sbi: [0xDE, 0x05], |m| { m.a = 0x50; m.cy = true; }, |m| { m.a = 0x4A; m.cy = false; };
+    rst_3: [0xDF], |_| {}, |m| { m.mem[0x2FFF] = 0x01; m.mem[0x2FFE] = 0x01; m.sp = 0x2FFE; m.pc = 0x0018; };
+
+    rpo_taken: [0xE0], |_| {}, |m| { m.pc = 0x1234; m.sp = 0x3002; };
+    pop_h: [0xE1], |_| {}, |m| { m.h = 0x12; m.l = 0x34; m.sp = 0x3002; };
+    jpo_not_taken: [0xE2, 0x00, 0x40], |m| { m.p = true; }, |_| {};
+    xthl: [0xE3], |_| {}, |m| { m.h = 0x12; m.l = 0x34; m.mem[0x3000] = 0x40; m.mem[0x3001] = 0x20; };
+    cpo_taken: [0xE4, 0x00, 0x40], |_| {}, |m| {
+        m.mem[0x2FFF] = 0x01;
+        m.mem[0x2FFE] = 0x03;
+        m.sp = 0x2FFE;
+        m.pc = 0x4000;
+    };
+    push_h: [0xE5], |_| {}, |m| { m.mem[0x2FFF] = 0x20; m.mem[0x2FFE] = 0x40; m.sp = 0x2FFE; };
+    ani: [0xE6, 0x0F], |m| { m.cy = true; m.ac = true; }, |m| { m.a = 0x0A; m.p = true; m.cy = false; };
+    rst_4: [0xE7], |_| {}, |m| { m.mem[0x2FFF] = 0x01; m.mem[0x2FFE] = 0x01; m.sp = 0x2FFE; m.pc = 0x0020; };
+    rpe_not_taken: [0xE8], |_| {}, |_| {};
+    pchl: [0xE9], |_| {}, |m| { m.pc = 0x2040; };
+    jpe_taken: [0xEA, 0x00, 0x40], |m| { m.p = true; }, |m| { m.pc = 0x4000; };
+    xchg: [0xEB], |_| {}, |m| { m.h = 0x0D; m.l = 0x0E; m.d = 0x20; m.e = 0x40; };
+    cpe_taken: [0xEC, 0x00, 0x40], |m| { m.p = true; }, |m| {
+        m.mem[0x2FFF] = 0x01;
+        m.mem[0x2FFE] = 0x03;
+        m.sp = 0x2FFE;
+        m.pc = 0x4000;
+    };
+    call_ed: [0xED, 0x00, 0x40], |_| {}, |m| {
+        m.mem[0x2FFF] = 0x01;
+        m.mem[0x2FFE] = 0x03;
+        m.sp = 0x2FFE;
+        m.pc = 0x4000;
+    };
+    xri: [0xEE, 0xFF], |_| {}, |m| { m.a = 0xF5; m.s = true; m.p = true; };
+    rst_5: [0xEF], |_| {}, |m| { m.mem[0x2FFF] = 0x01; m.mem[0x2FFE] = 0x01; m.sp = 0x2FFE; m.pc = 0x0028; };
+
+    rp_taken: [0xF0], |_| {}, |m| { m.pc = 0x1234; m.sp = 0x3002; };
+    pop_psw: [0xF1], |m| { m.mem[0x3000] = 0xC5; }, |m| {
+        m.a = 0x12;
+        m.s = true;
+        m.z = true;
+        m.p = true;
+        m.cy = true;
+        m.sp = 0x3002;
+    };
+    pop_psw_ignores_fixed_bits: [0xF1], |m| { m.mem[0x3000] = 0x3A; }, |m| {
+        m.a = 0x12;
+        m.ac = true;
+        m.sp = 0x3002;
+    };
+    jp_not_taken: [0xF2, 0x00, 0x40], |m| { m.s = true; }, |_| {};
+    di: [0xF3], |m| { m.int_enable = true; }, |m| { m.int_enable = false; };
+    cp_not_taken: [0xF4, 0x00, 0x40], |m| { m.s = true; }, |_| {};
+    push_psw: [0xF5], |m| { m.s = true; m.z = true; m.p = true; m.cy = true; }, |m| {
+        m.mem[0x2FFF] = 0x0A;
+        m.mem[0x2FFE] = 0xC7;
+        m.sp = 0x2FFE;
+    };
+    push_psw_aux_carry: [0xF5], |m| { m.ac = true; }, |m| {
+        m.mem[0x2FFF] = 0x0A;
+        m.mem[0x2FFE] = 0x12;
+        m.sp = 0x2FFE;
+    };
+    ori: [0xF6, 0x80], |_| {}, |m| { m.a = 0x8A; m.s = true; };
+    rst_6: [0xF7], |_| {}, |m| { m.mem[0x2FFF] = 0x01; m.mem[0x2FFE] = 0x01; m.sp = 0x2FFE; m.pc = 0x0030; };
+    rm_taken: [0xF8], |m| { m.s = true; }, |m| { m.pc = 0x1234; m.sp = 0x3002; };
+    sphl: [0xF9], |_| {}, |m| { m.sp = 0x2040; };
+    jm_not_taken: [0xFA, 0x00, 0x40], |_| {}, |_| {};
+    ei: [0xFB], |_| {}, |m| { m.int_enable = true; };
+    cm_taken: [0xFC, 0x00, 0x40], |m| { m.s = true; }, |m| {
+        m.mem[0x2FFF] = 0x01;
+        m.mem[0x2FFE] = 0x03;
+        m.sp = 0x2FFE;
+        m.pc = 0x4000;
+    };
+    call_fd: [0xFD, 0x00, 0x40], |_| {}, |m| {
+        m.mem[0x2FFF] = 0x01;
+        m.mem[0x2FFE] = 0x03;
+        m.sp = 0x2FFE;
+        m.pc = 0x4000;
+    };
+    cpi: [0xFE, 0x05], |m| { m.ac = true; }, |m| { m.p = true; };
+    rst_7: [0xFF], |_| {}, |m| { m.mem[0x2FFF] = 0x01; m.mem[0x2FFE] = 0x01; m.sp = 0x2FFE; m.pc = 0x0038; };
+}
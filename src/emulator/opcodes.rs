@@ -0,0 +1,1531 @@
+// Every opcode's handler and what is known about it before it runs, so `step`
+// dispatches through a table instead of matching on the opcode. Recompiled code
+// calls the handlers for instructions without operands.
+
+use super::{ConditionCodes, Io, Model, State};
+
+type Handler = fn(&mut State, &mut dyn Io);
+
+/// An opcode as the 8080 decodes it. The 8085's undocumented instructions reuse
+/// the aliased opcodes' entries, but with the 8085's cycles.
+pub struct Opcode {
+    pub mnemonic: &'static str,
+    /// Bytes including operands
+    pub len: u8,
+    /// Cycles on an 8080. Conditional calls and returns take 6 more when taken.
+    pub cycles: u8,
+    /// Cycles on an 8085. Conditional returns take 6 more when taken,
+    /// conditional calls 9 more and conditional jumps 3 more.
+    pub cycles_8085: u8,
+    /// Flags the instruction may change, as they sit in PSW
+    pub flags: u8,
+    pub(super) execute: Handler,
+}
+
+const fn op(
+    mnemonic: &'static str,
+    len: u8,
+    cycles: u8,
+    cycles_8085: u8,
+    flags: u8,
+    execute: Handler,
+) -> Opcode {
+    Opcode {
+        mnemonic,
+        len,
+        cycles,
+        cycles_8085,
+        flags,
+        execute,
+    }
+}
+
+const CY: u8 = ConditionCodes::CARRY;
+const ALL: u8 = ConditionCodes::SIGN
+    | ConditionCodes::ZERO
+    | ConditionCodes::AUX_CARRY
+    | ConditionCodes::PARITY
+    | ConditionCodes::CARRY;
+
+#[rustfmt::skip]
+pub static OPCODES: [Opcode; 256] = [
+    op("NOP", 1, 4, 4, 0, nop),
+    op("LXI B", 3, 10, 10, 0, lxi_b),
+    op("STAX B", 1, 7, 7, 0, stax_b),
+    op("INX B", 1, 5, 6, 0, inx_b),
+    op("INR B", 1, 5, 4, ALL & !CY, inr_b),
+    op("DCR B", 1, 5, 4, ALL & !CY, dcr_b),
+    op("MVI B", 2, 7, 7, 0, mvi_b),
+    op("RLC", 1, 4, 4, CY, rlc),
+    op("NOP", 1, 4, 10, 0, dsub_or_nop),
+    op("DAD B", 1, 10, 10, CY, dad_b),
+    op("LDAX B", 1, 7, 7, 0, ldax_b),
+    op("DCX B", 1, 5, 6, 0, dcx_b),
+    op("INR C", 1, 5, 4, ALL & !CY, inr_c),
+    op("DCR C", 1, 5, 4, ALL & !CY, dcr_c),
+    op("MVI C", 2, 7, 7, 0, mvi_c),
+    op("RRC", 1, 4, 4, CY, rrc),
+    op("NOP", 1, 4, 7, 0, arhl_or_nop),
+    op("LXI D", 3, 10, 10, 0, lxi_d),
+    op("STAX D", 1, 7, 7, 0, stax_d),
+    op("INX D", 1, 5, 6, 0, inx_d),
+    op("INR D", 1, 5, 4, ALL & !CY, inr_d),
+    op("DCR D", 1, 5, 4, ALL & !CY, dcr_d),
+    op("MVI D", 2, 7, 7, 0, mvi_d),
+    op("RAL", 1, 4, 4, CY, ral),
+    op("NOP", 1, 4, 10, 0, rdel_or_nop),
+    op("DAD D", 1, 10, 10, CY, dad_d),
+    op("LDAX D", 1, 7, 7, 0, ldax_d),
+    op("DCX D", 1, 5, 6, 0, dcx_d),
+    op("INR E", 1, 5, 4, ALL & !CY, inr_e),
+    op("DCR E", 1, 5, 4, ALL & !CY, dcr_e),
+    op("MVI E", 2, 7, 7, 0, mvi_e),
+    op("RAR", 1, 4, 4, CY, rar),
+    op("NOP", 1, 4, 4, 0, rim_or_nop),
+    op("LXI H", 3, 10, 10, 0, lxi_h),
+    op("SHLD", 3, 16, 16, 0, shld),
+    op("INX H", 1, 5, 6, 0, inx_h),
+    op("INR H", 1, 5, 4, ALL & !CY, inr_h),
+    op("DCR H", 1, 5, 4, ALL & !CY, dcr_h),
+    op("MVI H", 2, 7, 7, 0, mvi_h),
+    op("DAA", 1, 4, 4, ALL, daa),
+    op("NOP", 1, 4, 10, 0, ldhi_or_nop),
+    op("DAD H", 1, 10, 10, CY, dad_h),
+    op("LHLD", 3, 16, 16, 0, lhld),
+    op("DCX H", 1, 5, 6, 0, dcx_h),
+    op("INR L", 1, 5, 4, ALL & !CY, inr_l),
+    op("DCR L", 1, 5, 4, ALL & !CY, dcr_l),
+    op("MVI L", 2, 7, 7, 0, mvi_l),
+    op("CMA", 1, 4, 4, 0, cma),
+    op("NOP", 1, 4, 4, 0, sim_or_nop),
+    op("LXI SP", 3, 10, 10, 0, lxi_sp),
+    op("STA", 3, 13, 13, 0, sta),
+    op("INX SP", 1, 5, 6, 0, inx_sp),
+    op("INR M", 1, 10, 10, ALL & !CY, inr_m),
+    op("DCR M", 1, 10, 10, ALL & !CY, dcr_m),
+    op("MVI M", 2, 10, 10, 0, mvi_m),
+    op("STC", 1, 4, 4, CY, stc),
+    op("NOP", 1, 4, 10, 0, ldsi_or_nop),
+    op("DAD SP", 1, 10, 10, CY, dad_sp),
+    op("LDA", 3, 13, 13, 0, lda),
+    op("DCX SP", 1, 5, 6, 0, dcx_sp),
+    op("INR A", 1, 5, 4, ALL & !CY, inr_a),
+    op("DCR A", 1, 5, 4, ALL & !CY, dcr_a),
+    op("MVI A", 2, 7, 7, 0, mvi_a),
+    op("CMC", 1, 4, 4, CY, cmc),
+    op("MOV B,B", 1, 5, 4, 0, mov_b_b),
+    op("MOV B,C", 1, 5, 4, 0, mov_b_c),
+    op("MOV B,D", 1, 5, 4, 0, mov_b_d),
+    op("MOV B,E", 1, 5, 4, 0, mov_b_e),
+    op("MOV B,H", 1, 5, 4, 0, mov_b_h),
+    op("MOV B,L", 1, 5, 4, 0, mov_b_l),
+    op("MOV B,M", 1, 7, 7, 0, mov_b_m),
+    op("MOV B,A", 1, 5, 4, 0, mov_b_a),
+    op("MOV C,B", 1, 5, 4, 0, mov_c_b),
+    op("MOV C,C", 1, 5, 4, 0, mov_c_c),
+    op("MOV C,D", 1, 5, 4, 0, mov_c_d),
+    op("MOV C,E", 1, 5, 4, 0, mov_c_e),
+    op("MOV C,H", 1, 5, 4, 0, mov_c_h),
+    op("MOV C,L", 1, 5, 4, 0, mov_c_l),
+    op("MOV C,M", 1, 7, 7, 0, mov_c_m),
+    op("MOV C,A", 1, 5, 4, 0, mov_c_a),
+    op("MOV D,B", 1, 5, 4, 0, mov_d_b),
+    op("MOV D,C", 1, 5, 4, 0, mov_d_c),
+    op("MOV D,D", 1, 5, 4, 0, mov_d_d),
+    op("MOV D,E", 1, 5, 4, 0, mov_d_e),
+    op("MOV D,H", 1, 5, 4, 0, mov_d_h),
+    op("MOV D,L", 1, 5, 4, 0, mov_d_l),
+    op("MOV D,M", 1, 7, 7, 0, mov_d_m),
+    op("MOV D,A", 1, 5, 4, 0, mov_d_a),
+    op("MOV E,B", 1, 5, 4, 0, mov_e_b),
+    op("MOV E,C", 1, 5, 4, 0, mov_e_c),
+    op("MOV E,D", 1, 5, 4, 0, mov_e_d),
+    op("MOV E,E", 1, 5, 4, 0, mov_e_e),
+    op("MOV E,H", 1, 5, 4, 0, mov_e_h),
+    op("MOV E,L", 1, 5, 4, 0, mov_e_l),
+    op("MOV E,M", 1, 7, 7, 0, mov_e_m),
+    op("MOV E,A", 1, 5, 4, 0, mov_e_a),
+    op("MOV H,B", 1, 5, 4, 0, mov_h_b),
+    op("MOV H,C", 1, 5, 4, 0, mov_h_c),
+    op("MOV H,D", 1, 5, 4, 0, mov_h_d),
+    op("MOV H,E", 1, 5, 4, 0, mov_h_e),
+    op("MOV H,H", 1, 5, 4, 0, mov_h_h),
+    op("MOV H,L", 1, 5, 4, 0, mov_h_l),
+    op("MOV H,M", 1, 7, 7, 0, mov_h_m),
+    op("MOV H,A", 1, 5, 4, 0, mov_h_a),
+    op("MOV L,B", 1, 5, 4, 0, mov_l_b),
+    op("MOV L,C", 1, 5, 4, 0, mov_l_c),
+    op("MOV L,D", 1, 5, 4, 0, mov_l_d),
+    op("MOV L,E", 1, 5, 4, 0, mov_l_e),
+    op("MOV L,H", 1, 5, 4, 0, mov_l_h),
+    op("MOV L,L", 1, 5, 4, 0, mov_l_l),
+    op("MOV L,M", 1, 7, 7, 0, mov_l_m),
+    op("MOV L,A", 1, 5, 4, 0, mov_l_a),
+    op("MOV M,B", 1, 7, 7, 0, mov_m_b),
+    op("MOV M,C", 1, 7, 7, 0, mov_m_c),
+    op("MOV M,D", 1, 7, 7, 0, mov_m_d),
+    op("MOV M,E", 1, 7, 7, 0, mov_m_e),
+    op("MOV M,H", 1, 7, 7, 0, mov_m_h),
+    op("MOV M,L", 1, 7, 7, 0, mov_m_l),
+    op("HLT", 1, 7, 5, 0, hlt),
+    op("MOV M,A", 1, 7, 7, 0, mov_m_a),
+    op("MOV A,B", 1, 5, 4, 0, mov_a_b),
+    op("MOV A,C", 1, 5, 4, 0, mov_a_c),
+    op("MOV A,D", 1, 5, 4, 0, mov_a_d),
+    op("MOV A,E", 1, 5, 4, 0, mov_a_e),
+    op("MOV A,H", 1, 5, 4, 0, mov_a_h),
+    op("MOV A,L", 1, 5, 4, 0, mov_a_l),
+    op("MOV A,M", 1, 7, 7, 0, mov_a_m),
+    op("MOV A,A", 1, 5, 4, 0, mov_a_a),
+    op("ADD B", 1, 4, 4, ALL, add_b),
+    op("ADD C", 1, 4, 4, ALL, add_c),
+    op("ADD D", 1, 4, 4, ALL, add_d),
+    op("ADD E", 1, 4, 4, ALL, add_e),
+    op("ADD H", 1, 4, 4, ALL, add_h),
+    op("ADD L", 1, 4, 4, ALL, add_l),
+    op("ADD M", 1, 7, 7, ALL, add_m),
+    op("ADD A", 1, 4, 4, ALL, add_a),
+    op("ADC B", 1, 4, 4, ALL, adc_b),
+    op("ADC C", 1, 4, 4, ALL, adc_c),
+    op("ADC D", 1, 4, 4, ALL, adc_d),
+    op("ADC E", 1, 4, 4, ALL, adc_e),
+    op("ADC H", 1, 4, 4, ALL, adc_h),
+    op("ADC L", 1, 4, 4, ALL, adc_l),
+    op("ADC M", 1, 7, 7, ALL, adc_m),
+    op("ADC A", 1, 4, 4, ALL, adc_a),
+    op("SUB B", 1, 4, 4, ALL, sub_b),
+    op("SUB C", 1, 4, 4, ALL, sub_c),
+    op("SUB D", 1, 4, 4, ALL, sub_d),
+    op("SUB E", 1, 4, 4, ALL, sub_e),
+    op("SUB H", 1, 4, 4, ALL, sub_h),
+    op("SUB L", 1, 4, 4, ALL, sub_l),
+    op("SUB M", 1, 7, 7, ALL, sub_m),
+    op("SUB A", 1, 4, 4, ALL, sub_a),
+    op("SBB B", 1, 4, 4, ALL, sbb_b),
+    op("SBB C", 1, 4, 4, ALL, sbb_c),
+    op("SBB D", 1, 4, 4, ALL, sbb_d),
+    op("SBB E", 1, 4, 4, ALL, sbb_e),
+    op("SBB H", 1, 4, 4, ALL, sbb_h),
+    op("SBB L", 1, 4, 4, ALL, sbb_l),
+    op("SBB M", 1, 7, 7, ALL, sbb_m),
+    op("SBB A", 1, 4, 4, ALL, sbb_a),
+    op("ANA B", 1, 4, 4, ALL, ana_b),
+    op("ANA C", 1, 4, 4, ALL, ana_c),
+    op("ANA D", 1, 4, 4, ALL, ana_d),
+    op("ANA E", 1, 4, 4, ALL, ana_e),
+    op("ANA H", 1, 4, 4, ALL, ana_h),
+    op("ANA L", 1, 4, 4, ALL, ana_l),
+    op("ANA M", 1, 7, 7, ALL, ana_m),
+    op("ANA A", 1, 4, 4, ALL, ana_a),
+    op("XRA B", 1, 4, 4, ALL, xra_b),
+    op("XRA C", 1, 4, 4, ALL, xra_c),
+    op("XRA D", 1, 4, 4, ALL, xra_d),
+    op("XRA E", 1, 4, 4, ALL, xra_e),
+    op("XRA H", 1, 4, 4, ALL, xra_h),
+    op("XRA L", 1, 4, 4, ALL, xra_l),
+    op("XRA M", 1, 7, 7, ALL, xra_m),
+    op("XRA A", 1, 4, 4, ALL, xra_a),
+    op("ORA B", 1, 4, 4, ALL, ora_b),
+    op("ORA C", 1, 4, 4, ALL, ora_c),
+    op("ORA D", 1, 4, 4, ALL, ora_d),
+    op("ORA E", 1, 4, 4, ALL, ora_e),
+    op("ORA H", 1, 4, 4, ALL, ora_h),
+    op("ORA L", 1, 4, 4, ALL, ora_l),
+    op("ORA M", 1, 7, 7, ALL, ora_m),
+    op("ORA A", 1, 4, 4, ALL, ora_a),
+    op("CMP B", 1, 4, 4, ALL, cmp_b),
+    op("CMP C", 1, 4, 4, ALL, cmp_c),
+    op("CMP D", 1, 4, 4, ALL, cmp_d),
+    op("CMP E", 1, 4, 4, ALL, cmp_e),
+    op("CMP H", 1, 4, 4, ALL, cmp_h),
+    op("CMP L", 1, 4, 4, ALL, cmp_l),
+    op("CMP M", 1, 7, 7, ALL, cmp_m),
+    op("CMP A", 1, 4, 4, ALL, cmp_a),
+    op("RNZ", 1, 5, 6, 0, rnz),
+    op("POP B", 1, 10, 10, 0, pop_b),
+    op("JNZ", 3, 10, 7, 0, jnz),
+    op("JMP", 3, 10, 10, 0, jmp),
+    op("CNZ", 3, 11, 9, 0, cnz),
+    op("PUSH B", 1, 11, 12, 0, push_b),
+    op("ADI", 2, 7, 7, ALL, adi),
+    op("RST 0", 1, 11, 12, 0, rst_0),
+    op("RZ", 1, 5, 6, 0, rz),
+    op("RET", 1, 10, 10, 0, ret),
+    op("JZ", 3, 10, 7, 0, jz),
+    op("JMP", 3, 10, 6, 0, rstv_or_jmp),
+    op("CZ", 3, 11, 9, 0, cz),
+    op("CALL", 3, 17, 18, 0, call),
+    op("ACI", 2, 7, 7, ALL, aci),
+    op("RST 1", 1, 11, 12, 0, rst_1),
+    op("RNC", 1, 5, 6, 0, rnc),
+    op("POP D", 1, 10, 10, 0, pop_d),
+    op("JNC", 3, 10, 7, 0, jnc),
+    op("OUT", 2, 10, 10, 0, output),
+    op("CNC", 3, 11, 9, 0, cnc),
+    op("PUSH D", 1, 11, 12, 0, push_d),
+    op("SUI", 2, 7, 7, ALL, sui),
+    op("RST 2", 1, 11, 12, 0, rst_2),
+    op("RC", 1, 5, 6, 0, rc),
+    op("RET", 1, 10, 10, 0, shlx_or_ret),
+    op("JC", 3, 10, 7, 0, jc),
+    op("IN", 2, 10, 10, 0, input),
+    op("CC", 3, 11, 9, 0, cc),
+    op("CALL", 3, 17, 7, 0, jnk_or_call),
+    op("SBI", 2, 7, 7, ALL, sbi),
+    op("RST 3", 1, 11, 12, 0, rst_3),
+    op("RPO", 1, 5, 6, 0, rpo),
+    op("POP H", 1, 10, 10, 0, pop_h),
+    op("JPO", 3, 10, 7, 0, jpo),
+    op("XTHL", 1, 18, 16, 0, xthl),
+    op("CPO", 3, 11, 9, 0, cpo),
+    op("PUSH H", 1, 11, 12, 0, push_h),
+    op("ANI", 2, 7, 7, ALL, ani),
+    op("RST 4", 1, 11, 12, 0, rst_4),
+    op("RPE", 1, 5, 6, 0, rpe),
+    op("PCHL", 1, 5, 6, 0, pchl),
+    op("JPE", 3, 10, 7, 0, jpe),
+    op("XCHG", 1, 4, 4, 0, xchg),
+    op("CPE", 3, 11, 9, 0, cpe),
+    op("CALL", 3, 17, 10, 0, lhlx_or_call),
+    op("XRI", 2, 7, 7, ALL, xri),
+    op("RST 5", 1, 11, 12, 0, rst_5),
+    op("RP", 1, 5, 6, 0, rp),
+    op("POP PSW", 1, 10, 10, ALL, pop_psw),
+    op("JP", 3, 10, 7, 0, jp),
+    op("DI", 1, 4, 4, 0, di),
+    op("CP", 3, 11, 9, 0, cp),
+    op("PUSH PSW", 1, 11, 12, 0, push_psw),
+    op("ORI", 2, 7, 7, ALL, ori),
+    op("RST 6", 1, 11, 12, 0, rst_6),
+    op("RM", 1, 5, 6, 0, rm),
+    op("SPHL", 1, 5, 6, 0, sphl),
+    op("JM", 3, 10, 7, 0, jm),
+    op("EI", 1, 4, 4, 0, ei),
+    op("CM", 3, 11, 9, 0, cm),
+    op("CALL", 3, 17, 7, 0, jk_or_call),
+    op("CPI", 2, 7, 7, ALL, cpi),
+    op("RST 7", 1, 11, 12, 0, rst_7),
+];
+
+pub(super) fn nop(_cpu: &mut State, _io: &mut dyn Io) {}
+
+pub(super) fn lxi_b(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.b = cpu.fetch(2);
+    cpu.c = cpu.fetch(1);
+    cpu.pc += 2;
+}
+
+pub(super) fn stax_b(cpu: &mut State, _io: &mut dyn Io) {
+    let offset = State::extend(cpu.b, cpu.c) as usize;
+    cpu.write_byte(offset, cpu.a);
+}
+
+pub(super) fn inx_b(cpu: &mut State, _io: &mut dyn Io) {
+    let answer = State::extend(cpu.b, cpu.c).wrapping_add(1);
+    cpu.k = answer == 0;
+
+    State::assign_ref((&mut cpu.b, &mut cpu.c), State::separate(answer));
+}
+
+pub(super) fn inr_b(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.b = cpu.inr(cpu.b);
+}
+
+pub(super) fn dcr_b(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.b = cpu.dcr(cpu.b);
+}
+
+pub(super) fn mvi_b(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.b = cpu.fetch(1);
+    cpu.pc += 1;
+}
+
+pub(super) fn rlc(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.a = cpu.a.rotate_left(1);
+    cpu.cc.cy = (cpu.a & 0x01) == 0x01;
+}
+
+pub(super) fn dsub_or_nop(cpu: &mut State, _io: &mut dyn Io) {
+    if cpu.undocumented_8085() {
+        // DSUB
+        let low = cpu.sub_bytes(cpu.l, cpu.c, false);
+        let high = cpu.sub_bytes(cpu.h, cpu.b, cpu.cc.cy);
+        cpu.cc.z = low == 0 && high == 0;
+        cpu.l = low;
+        cpu.h = high;
+    }
+}
+
+pub(super) fn dad_b(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.dad(State::extend(cpu.b, cpu.c));
+}
+
+pub(super) fn ldax_b(cpu: &mut State, _io: &mut dyn Io) {
+    let offset = State::extend(cpu.b, cpu.c) as usize;
+    cpu.a = cpu.mem[offset];
+}
+
+pub(super) fn dcx_b(cpu: &mut State, _io: &mut dyn Io) {
+    let answer = State::extend(cpu.b, cpu.c).wrapping_sub(1);
+    cpu.k = answer == 0xFFFF;
+
+    State::assign_ref((&mut cpu.b, &mut cpu.c), State::separate(answer));
+}
+
+pub(super) fn inr_c(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.c = cpu.inr(cpu.c);
+}
+
+pub(super) fn dcr_c(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.c = cpu.dcr(cpu.c);
+}
+
+pub(super) fn mvi_c(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.c = cpu.fetch(1);
+    cpu.pc += 1;
+}
+
+pub(super) fn rrc(cpu: &mut State, _io: &mut dyn Io) {
+    let previous = cpu.a;
+    cpu.a = cpu.a.rotate_right(1);
+    cpu.cc.cy = (previous & 0x01) == 0x01;
+}
+
+pub(super) fn arhl_or_nop(cpu: &mut State, _io: &mut dyn Io) {
+    if cpu.undocumented_8085() {
+        // ARHL
+        cpu.cc.cy = cpu.l & 1 != 0;
+        let answer = (State::extend(cpu.h, cpu.l) as i16 >> 1) as u16;
+        State::assign_ref((&mut cpu.h, &mut cpu.l), State::separate(answer));
+    }
+}
+
+pub(super) fn lxi_d(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.d = cpu.fetch(2);
+    cpu.e = cpu.fetch(1);
+    cpu.pc += 2;
+}
+
+pub(super) fn stax_d(cpu: &mut State, _io: &mut dyn Io) {
+    let offset = State::extend(cpu.d, cpu.e) as usize;
+    cpu.write_byte(offset, cpu.a);
+}
+
+pub(super) fn inx_d(cpu: &mut State, _io: &mut dyn Io) {
+    let answer = State::extend(cpu.d, cpu.e).wrapping_add(1);
+    cpu.k = answer == 0;
+
+    State::assign_ref((&mut cpu.d, &mut cpu.e), State::separate(answer));
+}
+
+pub(super) fn inr_d(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.d = cpu.inr(cpu.d);
+}
+
+pub(super) fn dcr_d(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.d = cpu.dcr(cpu.d);
+}
+
+pub(super) fn mvi_d(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.d = cpu.fetch(1);
+    cpu.pc += 1;
+}
+
+pub(super) fn ral(cpu: &mut State, _io: &mut dyn Io) {
+    let previous = cpu.a;
+    cpu.a = (cpu.a << 1) | (cpu.cc.cy as u8);
+    cpu.cc.cy = (previous & 0b10000000) == 0b10000000;
+}
+
+pub(super) fn rdel_or_nop(cpu: &mut State, _io: &mut dyn Io) {
+    if cpu.undocumented_8085() {
+        // RDEL
+        let de = State::extend(cpu.d, cpu.e);
+        let answer = de << 1 | cpu.cc.cy as u16;
+        cpu.cc.cy = de & 0x8000 != 0;
+        cpu.overflow = (de ^ answer) & 0x8000 != 0;
+        State::assign_ref((&mut cpu.d, &mut cpu.e), State::separate(answer));
+    }
+}
+
+pub(super) fn dad_d(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.dad(State::extend(cpu.d, cpu.e));
+}
+
+pub(super) fn ldax_d(cpu: &mut State, _io: &mut dyn Io) {
+    let offset = State::extend(cpu.d, cpu.e) as usize;
+    cpu.a = cpu.mem[offset];
+}
+
+pub(super) fn dcx_d(cpu: &mut State, _io: &mut dyn Io) {
+    let answer = State::extend(cpu.d, cpu.e).wrapping_sub(1);
+    cpu.k = answer == 0xFFFF;
+
+    State::assign_ref((&mut cpu.d, &mut cpu.e), State::separate(answer));
+}
+
+pub(super) fn inr_e(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.e = cpu.inr(cpu.e);
+}
+
+pub(super) fn dcr_e(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.e = cpu.dcr(cpu.e);
+}
+
+pub(super) fn mvi_e(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.e = cpu.fetch(1);
+    cpu.pc += 1;
+}
+
+pub(super) fn rar(cpu: &mut State, _io: &mut dyn Io) {
+    let previous = cpu.a;
+    cpu.a = (cpu.a >> 1) | ((cpu.cc.cy as u8) << 7);
+    cpu.cc.cy = (previous & 0x01) == 0x01;
+}
+
+pub(super) fn rim_or_nop(cpu: &mut State, _io: &mut dyn Io) {
+    if cpu.model == Model::I8085 {
+        // RIM
+        cpu.a = cpu.read_interrupt_mask();
+    }
+}
+
+pub(super) fn lxi_h(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.h = cpu.fetch(2);
+    cpu.l = cpu.fetch(1);
+    cpu.pc += 2;
+}
+
+pub(super) fn shld(cpu: &mut State, _io: &mut dyn Io) {
+    let offset = State::extend(cpu.fetch(2), cpu.fetch(1)) as usize;
+    cpu.write_byte(offset, cpu.l);
+    cpu.write_byte(offset + 1, cpu.h);
+
+    cpu.pc += 2;
+}
+
+pub(super) fn inx_h(cpu: &mut State, _io: &mut dyn Io) {
+    let answer = State::extend(cpu.h, cpu.l).wrapping_add(1);
+    cpu.k = answer == 0;
+
+    State::assign_ref((&mut cpu.h, &mut cpu.l), State::separate(answer));
+}
+
+pub(super) fn inr_h(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.h = cpu.inr(cpu.h);
+}
+
+pub(super) fn dcr_h(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.h = cpu.dcr(cpu.h);
+}
+
+pub(super) fn mvi_h(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.h = cpu.fetch(1);
+    cpu.pc += 1;
+}
+
+pub(super) fn daa(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.daa();
+}
+
+pub(super) fn ldhi_or_nop(cpu: &mut State, _io: &mut dyn Io) {
+    if cpu.undocumented_8085() {
+        // LDHI byte
+        let answer = State::extend(cpu.h, cpu.l).wrapping_add(cpu.fetch(1) as u16);
+        State::assign_ref((&mut cpu.d, &mut cpu.e), State::separate(answer));
+        cpu.pc += 1;
+    }
+}
+
+pub(super) fn dad_h(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.dad(State::extend(cpu.h, cpu.l));
+}
+
+pub(super) fn lhld(cpu: &mut State, _io: &mut dyn Io) {
+    let offset = State::extend(cpu.fetch(2), cpu.fetch(1)) as usize;
+    cpu.l = cpu.mem[offset];
+    cpu.h = cpu.mem[offset + 1];
+
+    cpu.pc += 2;
+}
+
+pub(super) fn dcx_h(cpu: &mut State, _io: &mut dyn Io) {
+    let answer = State::extend(cpu.h, cpu.l).wrapping_sub(1);
+    cpu.k = answer == 0xFFFF;
+
+    State::assign_ref((&mut cpu.h, &mut cpu.l), State::separate(answer));
+}
+
+pub(super) fn inr_l(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.l = cpu.inr(cpu.l);
+}
+
+pub(super) fn dcr_l(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.l = cpu.dcr(cpu.l);
+}
+
+pub(super) fn mvi_l(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.l = cpu.fetch(1);
+    cpu.pc += 1;
+}
+
+pub(super) fn cma(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.a = !cpu.a;
+}
+
+pub(super) fn sim_or_nop(cpu: &mut State, _io: &mut dyn Io) {
+    if cpu.model == Model::I8085 {
+        // SIM
+        cpu.set_interrupt_mask(cpu.a);
+    }
+}
+
+pub(super) fn lxi_sp(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.sp = State::extend(cpu.fetch(2), cpu.fetch(1)) as usize;
+    cpu.pc += 2;
+}
+
+pub(super) fn sta(cpu: &mut State, _io: &mut dyn Io) {
+    let offset = State::extend(cpu.fetch(2), cpu.fetch(1)) as usize;
+    cpu.write_byte(offset, cpu.a);
+
+    cpu.pc += 2;
+}
+
+pub(super) fn inx_sp(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.sp = (cpu.sp + 1) & 0xFFFF;
+    cpu.k = cpu.sp == 0;
+}
+
+pub(super) fn inr_m(cpu: &mut State, _io: &mut dyn Io) {
+    let offset = State::extend(cpu.h, cpu.l) as usize;
+    let answer = cpu.inr(cpu.mem[offset]);
+    cpu.write_byte(offset, answer);
+}
+
+pub(super) fn dcr_m(cpu: &mut State, _io: &mut dyn Io) {
+    let offset = State::extend(cpu.h, cpu.l) as usize;
+    let answer = cpu.dcr(cpu.mem[offset]);
+    cpu.write_byte(offset, answer);
+}
+
+pub(super) fn mvi_m(cpu: &mut State, _io: &mut dyn Io) {
+    let offset = State::extend(cpu.h, cpu.l) as usize;
+    cpu.write_byte(offset, cpu.fetch(1));
+    cpu.pc += 1;
+}
+
+pub(super) fn stc(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.cc.cy = true;
+}
+
+pub(super) fn ldsi_or_nop(cpu: &mut State, _io: &mut dyn Io) {
+    if cpu.undocumented_8085() {
+        // LDSI byte
+        let answer = (cpu.sp as u16).wrapping_add(cpu.fetch(1) as u16);
+        State::assign_ref((&mut cpu.d, &mut cpu.e), State::separate(answer));
+        cpu.pc += 1;
+    }
+}
+
+pub(super) fn dad_sp(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.dad(cpu.sp as u16);
+}
+
+pub(super) fn lda(cpu: &mut State, _io: &mut dyn Io) {
+    let offset = State::extend(cpu.fetch(2), cpu.fetch(1)) as usize;
+    cpu.a = cpu.mem[offset];
+
+    cpu.pc += 2;
+}
+
+pub(super) fn dcx_sp(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.sp = cpu.sp.wrapping_sub(1) & 0xFFFF;
+    cpu.k = cpu.sp == 0xFFFF;
+}
+
+pub(super) fn inr_a(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.a = cpu.inr(cpu.a);
+}
+
+pub(super) fn dcr_a(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.a = cpu.dcr(cpu.a);
+}
+
+pub(super) fn mvi_a(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.a = cpu.fetch(1);
+    cpu.pc += 1;
+}
+
+pub(super) fn cmc(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.cc.cy = !cpu.cc.cy;
+}
+
+pub(super) fn mov_b_b(_cpu: &mut State, _io: &mut dyn Io) {}
+
+pub(super) fn mov_b_c(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.b = cpu.c;
+}
+
+pub(super) fn mov_b_d(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.b = cpu.d;
+}
+
+pub(super) fn mov_b_e(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.b = cpu.e;
+}
+
+pub(super) fn mov_b_h(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.b = cpu.h;
+}
+
+pub(super) fn mov_b_l(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.b = cpu.l;
+}
+
+pub(super) fn mov_b_m(cpu: &mut State, _io: &mut dyn Io) {
+    let offset: usize = State::extend(cpu.h, cpu.l) as usize;
+    cpu.b = cpu.mem[offset];
+}
+
+pub(super) fn mov_b_a(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.b = cpu.a;
+}
+
+pub(super) fn mov_c_b(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.c = cpu.b;
+}
+
+pub(super) fn mov_c_c(_cpu: &mut State, _io: &mut dyn Io) {}
+
+pub(super) fn mov_c_d(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.c = cpu.d;
+}
+
+pub(super) fn mov_c_e(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.c = cpu.e;
+}
+
+pub(super) fn mov_c_h(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.c = cpu.h;
+}
+
+pub(super) fn mov_c_l(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.c = cpu.l;
+}
+
+pub(super) fn mov_c_m(cpu: &mut State, _io: &mut dyn Io) {
+    let offset: usize = State::extend(cpu.h, cpu.l) as usize;
+    cpu.c = cpu.mem[offset];
+}
+
+pub(super) fn mov_c_a(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.c = cpu.a;
+}
+
+pub(super) fn mov_d_b(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.d = cpu.b;
+}
+
+pub(super) fn mov_d_c(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.d = cpu.c;
+}
+
+pub(super) fn mov_d_d(_cpu: &mut State, _io: &mut dyn Io) {}
+
+pub(super) fn mov_d_e(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.d = cpu.e;
+}
+
+pub(super) fn mov_d_h(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.d = cpu.h;
+}
+
+pub(super) fn mov_d_l(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.d = cpu.l;
+}
+
+pub(super) fn mov_d_m(cpu: &mut State, _io: &mut dyn Io) {
+    let offset: usize = State::extend(cpu.h, cpu.l) as usize;
+    cpu.d = cpu.mem[offset];
+}
+
+pub(super) fn mov_d_a(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.d = cpu.a;
+}
+
+pub(super) fn mov_e_b(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.e = cpu.b;
+}
+
+pub(super) fn mov_e_c(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.e = cpu.c;
+}
+
+pub(super) fn mov_e_d(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.e = cpu.d;
+}
+
+pub(super) fn mov_e_e(_cpu: &mut State, _io: &mut dyn Io) {}
+
+pub(super) fn mov_e_h(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.e = cpu.h;
+}
+
+pub(super) fn mov_e_l(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.e = cpu.l;
+}
+
+pub(super) fn mov_e_m(cpu: &mut State, _io: &mut dyn Io) {
+    let offset: usize = State::extend(cpu.h, cpu.l) as usize;
+    cpu.e = cpu.mem[offset];
+}
+
+pub(super) fn mov_e_a(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.e = cpu.a;
+}
+
+pub(super) fn mov_h_b(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.h = cpu.b;
+}
+
+pub(super) fn mov_h_c(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.h = cpu.c;
+}
+
+pub(super) fn mov_h_d(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.h = cpu.d;
+}
+
+pub(super) fn mov_h_e(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.h = cpu.e;
+}
+
+pub(super) fn mov_h_h(_cpu: &mut State, _io: &mut dyn Io) {}
+
+pub(super) fn mov_h_l(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.h = cpu.l;
+}
+
+pub(super) fn mov_h_m(cpu: &mut State, _io: &mut dyn Io) {
+    let offset: usize = State::extend(cpu.h, cpu.l) as usize;
+    cpu.h = cpu.mem[offset];
+}
+
+pub(super) fn mov_h_a(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.h = cpu.a;
+}
+
+pub(super) fn mov_l_b(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.l = cpu.b;
+}
+
+pub(super) fn mov_l_c(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.l = cpu.c;
+}
+
+pub(super) fn mov_l_d(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.l = cpu.d;
+}
+
+pub(super) fn mov_l_e(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.l = cpu.e;
+}
+
+pub(super) fn mov_l_h(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.l = cpu.h;
+}
+
+pub(super) fn mov_l_l(_cpu: &mut State, _io: &mut dyn Io) {}
+
+pub(super) fn mov_l_m(cpu: &mut State, _io: &mut dyn Io) {
+    let offset: usize = State::extend(cpu.h, cpu.l) as usize;
+    cpu.l = cpu.mem[offset];
+}
+
+pub(super) fn mov_l_a(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.l = cpu.a;
+}
+
+pub(super) fn mov_m_b(cpu: &mut State, _io: &mut dyn Io) {
+    let offset: usize = State::extend(cpu.h, cpu.l) as usize;
+    cpu.write_byte(offset, cpu.b);
+}
+
+pub(super) fn mov_m_c(cpu: &mut State, _io: &mut dyn Io) {
+    let offset: usize = State::extend(cpu.h, cpu.l) as usize;
+    cpu.write_byte(offset, cpu.c);
+}
+
+pub(super) fn mov_m_d(cpu: &mut State, _io: &mut dyn Io) {
+    let offset: usize = State::extend(cpu.h, cpu.l) as usize;
+    cpu.write_byte(offset, cpu.d);
+}
+
+pub(super) fn mov_m_e(cpu: &mut State, _io: &mut dyn Io) {
+    let offset: usize = State::extend(cpu.h, cpu.l) as usize;
+    cpu.write_byte(offset, cpu.e);
+}
+
+pub(super) fn mov_m_h(cpu: &mut State, _io: &mut dyn Io) {
+    let offset: usize = State::extend(cpu.h, cpu.l) as usize;
+    cpu.write_byte(offset, cpu.h);
+}
+
+pub(super) fn mov_m_l(cpu: &mut State, _io: &mut dyn Io) {
+    let offset: usize = State::extend(cpu.h, cpu.l) as usize;
+    cpu.write_byte(offset, cpu.l);
+}
+
+pub(super) fn hlt(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.halted = true;
+}
+
+pub(super) fn mov_m_a(cpu: &mut State, _io: &mut dyn Io) {
+    let offset: usize = State::extend(cpu.h, cpu.l) as usize;
+    cpu.write_byte(offset, cpu.a);
+}
+
+pub(super) fn mov_a_b(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.a = cpu.b;
+}
+
+pub(super) fn mov_a_c(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.a = cpu.c;
+}
+
+pub(super) fn mov_a_d(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.a = cpu.d;
+}
+
+pub(super) fn mov_a_e(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.a = cpu.e;
+}
+
+pub(super) fn mov_a_h(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.a = cpu.h;
+}
+
+pub(super) fn mov_a_l(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.a = cpu.l;
+}
+
+pub(super) fn mov_a_m(cpu: &mut State, _io: &mut dyn Io) {
+    let offset: usize = State::extend(cpu.h, cpu.l) as usize;
+    cpu.a = cpu.mem[offset];
+}
+
+pub(super) fn mov_a_a(_cpu: &mut State, _io: &mut dyn Io) {}
+
+pub(super) fn add_b(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.add(cpu.b);
+}
+
+pub(super) fn add_c(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.add(cpu.c);
+}
+
+pub(super) fn add_d(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.add(cpu.d);
+}
+
+pub(super) fn add_e(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.add(cpu.e);
+}
+
+pub(super) fn add_h(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.add(cpu.h);
+}
+
+pub(super) fn add_l(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.add(cpu.l);
+}
+
+pub(super) fn add_m(cpu: &mut State, _io: &mut dyn Io) {
+    let offset = State::extend(cpu.h, cpu.l) as usize;
+    cpu.add(cpu.mem[offset]);
+}
+
+pub(super) fn add_a(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.add(cpu.a);
+}
+
+pub(super) fn adc_b(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.add_cy(cpu.b);
+}
+
+pub(super) fn adc_c(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.add_cy(cpu.c);
+}
+
+pub(super) fn adc_d(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.add_cy(cpu.d);
+}
+
+pub(super) fn adc_e(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.add_cy(cpu.e);
+}
+
+pub(super) fn adc_h(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.add_cy(cpu.h);
+}
+
+pub(super) fn adc_l(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.add_cy(cpu.l);
+}
+
+pub(super) fn adc_m(cpu: &mut State, _io: &mut dyn Io) {
+    let offset = State::extend(cpu.h, cpu.l) as usize;
+    cpu.add_cy(cpu.mem[offset]);
+}
+
+pub(super) fn adc_a(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.add_cy(cpu.a);
+}
+
+pub(super) fn sub_b(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.sub(cpu.b);
+}
+
+pub(super) fn sub_c(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.sub(cpu.c);
+}
+
+pub(super) fn sub_d(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.sub(cpu.d);
+}
+
+pub(super) fn sub_e(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.sub(cpu.e);
+}
+
+pub(super) fn sub_h(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.sub(cpu.h);
+}
+
+pub(super) fn sub_l(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.sub(cpu.l);
+}
+
+pub(super) fn sub_m(cpu: &mut State, _io: &mut dyn Io) {
+    let offset = State::extend(cpu.h, cpu.l) as usize;
+    cpu.sub(cpu.mem[offset]);
+}
+
+pub(super) fn sub_a(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.sub(cpu.a);
+}
+
+pub(super) fn sbb_b(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.sub_cy(cpu.b);
+}
+
+pub(super) fn sbb_c(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.sub_cy(cpu.c);
+}
+
+pub(super) fn sbb_d(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.sub_cy(cpu.d);
+}
+
+pub(super) fn sbb_e(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.sub_cy(cpu.e);
+}
+
+pub(super) fn sbb_h(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.sub_cy(cpu.h);
+}
+
+pub(super) fn sbb_l(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.sub_cy(cpu.l);
+}
+
+pub(super) fn sbb_m(cpu: &mut State, _io: &mut dyn Io) {
+    let offset = State::extend(cpu.h, cpu.l) as usize;
+    cpu.sub_cy(cpu.mem[offset]);
+}
+
+pub(super) fn sbb_a(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.sub_cy(cpu.a);
+}
+
+pub(super) fn ana_b(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.and(cpu.b);
+}
+
+pub(super) fn ana_c(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.and(cpu.c);
+}
+
+pub(super) fn ana_d(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.and(cpu.d);
+}
+
+pub(super) fn ana_e(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.and(cpu.e);
+}
+
+pub(super) fn ana_h(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.and(cpu.h);
+}
+
+pub(super) fn ana_l(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.and(cpu.l);
+}
+
+pub(super) fn ana_m(cpu: &mut State, _io: &mut dyn Io) {
+    let offset = State::extend(cpu.h, cpu.l) as usize;
+    cpu.and(cpu.mem[offset]);
+}
+
+pub(super) fn ana_a(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.and(cpu.a);
+}
+
+pub(super) fn xra_b(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.xor(cpu.b);
+}
+
+pub(super) fn xra_c(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.xor(cpu.c);
+}
+
+pub(super) fn xra_d(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.xor(cpu.d);
+}
+
+pub(super) fn xra_e(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.xor(cpu.e);
+}
+
+pub(super) fn xra_h(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.xor(cpu.h);
+}
+
+pub(super) fn xra_l(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.xor(cpu.l);
+}
+
+pub(super) fn xra_m(cpu: &mut State, _io: &mut dyn Io) {
+    let offset = State::extend(cpu.h, cpu.l) as usize;
+    cpu.xor(cpu.mem[offset]);
+}
+
+pub(super) fn xra_a(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.xor(cpu.a);
+}
+
+pub(super) fn ora_b(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.or(cpu.b);
+}
+
+pub(super) fn ora_c(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.or(cpu.c);
+}
+
+pub(super) fn ora_d(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.or(cpu.d);
+}
+
+pub(super) fn ora_e(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.or(cpu.e);
+}
+
+pub(super) fn ora_h(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.or(cpu.h);
+}
+
+pub(super) fn ora_l(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.or(cpu.l);
+}
+
+pub(super) fn ora_m(cpu: &mut State, _io: &mut dyn Io) {
+    let offset = State::extend(cpu.h, cpu.l) as usize;
+    cpu.or(cpu.mem[offset]);
+}
+
+pub(super) fn ora_a(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.or(cpu.a);
+}
+
+pub(super) fn cmp_b(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.cmp(cpu.b);
+}
+
+pub(super) fn cmp_c(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.cmp(cpu.c);
+}
+
+pub(super) fn cmp_d(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.cmp(cpu.d);
+}
+
+pub(super) fn cmp_e(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.cmp(cpu.e);
+}
+
+pub(super) fn cmp_h(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.cmp(cpu.h);
+}
+
+pub(super) fn cmp_l(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.cmp(cpu.l);
+}
+
+pub(super) fn cmp_m(cpu: &mut State, _io: &mut dyn Io) {
+    let offset = State::extend(cpu.h, cpu.l) as usize;
+    cpu.cmp(cpu.mem[offset]);
+}
+
+pub(super) fn cmp_a(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.cmp(cpu.a);
+}
+
+pub(super) fn rnz(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.ret_if(!cpu.cc.z);
+}
+
+pub(super) fn pop_b(cpu: &mut State, _io: &mut dyn Io) {
+    let value = cpu.pop();
+    State::assign_ref((&mut cpu.b, &mut cpu.c), State::separate(value));
+}
+
+pub(super) fn jnz(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.jmp_if(!cpu.cc.z);
+}
+
+pub(super) fn jmp(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.jmp();
+}
+
+pub(super) fn cnz(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.call_if(!cpu.cc.z);
+}
+
+pub(super) fn push_b(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.push(State::extend(cpu.b, cpu.c));
+}
+
+pub(super) fn adi(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.add(cpu.fetch(1));
+    cpu.pc += 1;
+}
+
+pub(super) fn rst_0(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.rst(0);
+}
+
+pub(super) fn rz(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.ret_if(cpu.cc.z);
+}
+
+pub(super) fn ret(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.ret();
+}
+
+pub(super) fn jz(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.jmp_if(cpu.cc.z);
+}
+
+pub(super) fn rstv_or_jmp(cpu: &mut State, _io: &mut dyn Io) {
+    if cpu.undocumented_8085() {
+        // RSTV
+        if cpu.overflow {
+            cpu.cycles += 6;
+            cpu.rst(8);
+        }
+    } else {
+        // JMP bytes
+        cpu.jmp();
+    }
+}
+
+pub(super) fn cz(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.call_if(cpu.cc.z);
+}
+
+pub(super) fn call(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.call();
+}
+
+pub(super) fn aci(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.add_cy(cpu.fetch(1));
+    cpu.pc += 1;
+}
+
+pub(super) fn rst_1(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.rst(1);
+}
+
+pub(super) fn rnc(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.ret_if(!cpu.cc.cy);
+}
+
+pub(super) fn pop_d(cpu: &mut State, _io: &mut dyn Io) {
+    let value = cpu.pop();
+    State::assign_ref((&mut cpu.d, &mut cpu.e), State::separate(value));
+}
+
+pub(super) fn jnc(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.jmp_if(!cpu.cc.cy);
+}
+
+pub(super) fn output(cpu: &mut State, io: &mut dyn Io) {
+    io.output(cpu.fetch(1), cpu.a);
+    cpu.pc += 1;
+}
+
+pub(super) fn cnc(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.call_if(!cpu.cc.cy);
+}
+
+pub(super) fn push_d(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.push(State::extend(cpu.d, cpu.e));
+}
+
+pub(super) fn sui(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.sub(cpu.fetch(1));
+    cpu.pc += 1;
+}
+
+pub(super) fn rst_2(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.rst(2);
+}
+
+pub(super) fn rc(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.ret_if(cpu.cc.cy);
+}
+
+pub(super) fn shlx_or_ret(cpu: &mut State, _io: &mut dyn Io) {
+    if cpu.undocumented_8085() {
+        // SHLX
+        let offset = State::extend(cpu.d, cpu.e) as usize;
+        cpu.write_word(offset, State::extend(cpu.h, cpu.l));
+    } else {
+        // RET
+        cpu.ret();
+    }
+}
+
+pub(super) fn jc(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.jmp_if(cpu.cc.cy);
+}
+
+pub(super) fn input(cpu: &mut State, io: &mut dyn Io) {
+    cpu.a = io.input(cpu.fetch(1));
+    cpu.pc += 1;
+}
+
+pub(super) fn cc(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.call_if(cpu.cc.cy);
+}
+
+pub(super) fn jnk_or_call(cpu: &mut State, _io: &mut dyn Io) {
+    if cpu.undocumented_8085() {
+        // JNK bytes
+        cpu.jmp_if(!cpu.k);
+    } else {
+        // CALL bytes
+        cpu.call();
+    }
+}
+
+pub(super) fn sbi(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.sub_cy(cpu.fetch(1));
+    cpu.pc += 1;
+}
+
+pub(super) fn rst_3(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.rst(3);
+}
+
+pub(super) fn rpo(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.ret_if(!cpu.cc.p);
+}
+
+pub(super) fn pop_h(cpu: &mut State, _io: &mut dyn Io) {
+    let value = cpu.pop();
+    State::assign_ref((&mut cpu.h, &mut cpu.l), State::separate(value));
+}
+
+pub(super) fn jpo(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.jmp_if(!cpu.cc.p);
+}
+
+pub(super) fn xthl(cpu: &mut State, _io: &mut dyn Io) {
+    let top = cpu.read_word(cpu.sp);
+    cpu.write_word(cpu.sp, State::extend(cpu.h, cpu.l));
+    State::assign_ref((&mut cpu.h, &mut cpu.l), State::separate(top));
+}
+
+pub(super) fn cpo(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.call_if(!cpu.cc.p);
+}
+
+pub(super) fn push_h(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.push(State::extend(cpu.h, cpu.l));
+}
+
+pub(super) fn ani(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.and(cpu.fetch(1));
+    cpu.pc += 1;
+}
+
+pub(super) fn rst_4(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.rst(4);
+}
+
+pub(super) fn rpe(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.ret_if(cpu.cc.p);
+}
+
+pub(super) fn pchl(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.pc = (State::extend(cpu.h, cpu.l) as usize).wrapping_sub(1);
+}
+
+pub(super) fn jpe(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.jmp_if(cpu.cc.p);
+}
+
+pub(super) fn xchg(cpu: &mut State, _io: &mut dyn Io) {
+    std::mem::swap(&mut cpu.h, &mut cpu.d);
+    std::mem::swap(&mut cpu.l, &mut cpu.e);
+}
+
+pub(super) fn cpe(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.call_if(cpu.cc.p);
+}
+
+pub(super) fn lhlx_or_call(cpu: &mut State, _io: &mut dyn Io) {
+    if cpu.undocumented_8085() {
+        // LHLX
+        let offset = State::extend(cpu.d, cpu.e) as usize;
+        let answer = cpu.read_word(offset);
+        State::assign_ref((&mut cpu.h, &mut cpu.l), State::separate(answer));
+    } else {
+        // CALL bytes
+        cpu.call()
+    }
+}
+
+pub(super) fn xri(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.xor(cpu.fetch(1));
+    cpu.pc += 1;
+}
+
+pub(super) fn rst_5(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.rst(5);
+}
+
+pub(super) fn rp(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.ret_if(!cpu.cc.s);
+}
+
+pub(super) fn pop_psw(cpu: &mut State, _io: &mut dyn Io) {
+    let psw = cpu.pop();
+    cpu.set_psw(psw);
+}
+
+pub(super) fn jp(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.jmp_if(!cpu.cc.s);
+}
+
+pub(super) fn di(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.int_enable = false;
+}
+
+pub(super) fn cp(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.call_if(!cpu.cc.s);
+}
+
+pub(super) fn push_psw(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.push(cpu.psw());
+}
+
+pub(super) fn ori(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.or(cpu.fetch(1));
+    cpu.pc += 1;
+}
+
+pub(super) fn rst_6(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.rst(6);
+}
+
+pub(super) fn rm(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.ret_if(cpu.cc.s);
+}
+
+pub(super) fn sphl(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.sp = State::extend(cpu.h, cpu.l) as usize;
+}
+
+pub(super) fn jm(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.jmp_if(cpu.cc.s);
+}
+
+pub(super) fn ei(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.int_enable = true;
+}
+
+pub(super) fn cm(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.call_if(cpu.cc.s);
+}
+
+pub(super) fn jk_or_call(cpu: &mut State, _io: &mut dyn Io) {
+    if cpu.undocumented_8085() {
+        // JK bytes
+        cpu.jmp_if(cpu.k);
+    } else {
+        // CALL bytes
+        cpu.call();
+    }
+}
+
+pub(super) fn cpi(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.cmp(cpu.fetch(1));
+    cpu.pc += 1;
+}
+
+pub(super) fn rst_7(cpu: &mut State, _io: &mut dyn Io) {
+    cpu.rst(7);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::disasm;
+
+    #[test]
+    fn lengths_match_the_disassembler() {
+        for opcode in 0..=255 {
+            let mut asm = String::new();
+            let len = disasm::disasm_single(&mut asm, &[opcode, 0, 0], 0).unwrap();
+            assert_eq!(OPCODES[opcode as usize].len as usize, len, "{:02X}", opcode);
+        }
+    }
+
+    // Runs every opcode from a spread of flag states and checks it leaves the
+    // flags outside its mask alone
+    #[test]
+    fn flags_stay_within_the_mask() {
+        for opcode in 0..=255 {
+            for psw in [0x00, 0xFF, 0x55, 0xAA] {
+                for a in [0x00, 0x0F, 0x80, 0x99, 0xFF] {
+                    let mut mem = vec![0; 0x10000];
+                    mem[..3].copy_from_slice(&[opcode, 0x8A, 0x00]);
+                    let mut cpu = State::new(&mut mem);
+                    cpu.set_psw(((a as u16) << 8) | psw);
+                    cpu.set_sp(0x8000);
+                    cpu.set_hl(0x4000);
+                    let before = cpu.psw() as u8;
+
+                    cpu.step();
+
+                    let changed = before ^ cpu.psw() as u8;
+                    let op = &OPCODES[opcode as usize];
+                    assert_eq!(changed & !op.flags, 0, "{} from {:02X}", op.mnemonic, psw);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,153 @@
+// Register pair arithmetic over every value, and around the 16-bit boundaries.
+
+use super::*;
+
+const EDGES: [u16; 10] = [
+    0x0000, 0x0001, 0x00FF, 0x0100, 0x0FFF, 0x7FFF, 0x8000, 0x8001, 0xFFFE, 0xFFFF,
+];
+
+fn set_pair(state: &mut State, pair: usize, value: u16) {
+    let (high, low) = State::separate(value);
+    match pair {
+        0 => State::assign_ref((&mut state.b, &mut state.c), (high, low)),
+        1 => State::assign_ref((&mut state.d, &mut state.e), (high, low)),
+        2 => State::assign_ref((&mut state.h, &mut state.l), (high, low)),
+        _ => state.sp = value as usize,
+    }
+}
+
+fn get_pair(state: &State, pair: usize) -> u16 {
+    match pair {
+        0 => State::extend(state.b, state.c),
+        1 => State::extend(state.d, state.e),
+        2 => State::extend(state.h, state.l),
+        _ => state.sp as u16,
+    }
+}
+
+// Pairs are numbered as in bits 4 and 5 of their opcodes: BC, DE, HL, SP
+fn run(state: &mut State, opcode: u8) {
+    state.mem[0x08] = opcode;
+    state.pc = 0x08;
+    state.step();
+}
+
+#[test]
+fn inx_wraps() {
+    let mut mem = vec![0; 0x10];
+    let mut state = State::new(&mut mem);
+
+    for pair in 0..4 {
+        for value in 0..=0xFFFF {
+            set_pair(&mut state, pair, value);
+            state.cc.cy = false;
+            run(&mut state, 0x03 | (pair as u8) << 4);
+
+            assert_eq!(get_pair(&state, pair), value.wrapping_add(1));
+            assert!(!state.cc.cy);
+        }
+    }
+}
+
+#[test]
+fn dcx_wraps() {
+    let mut mem = vec![0; 0x10];
+    let mut state = State::new(&mut mem);
+
+    for pair in 0..4 {
+        for value in 0..=0xFFFF {
+            set_pair(&mut state, pair, value);
+            state.cc.cy = true;
+            run(&mut state, 0x0B | (pair as u8) << 4);
+
+            assert_eq!(get_pair(&state, pair), value.wrapping_sub(1));
+            assert!(state.cc.cy);
+        }
+    }
+}
+
+#[test]
+fn dad_carries_from_bit_15() {
+    let mut mem = vec![0; 0x10];
+    let mut state = State::new(&mut mem);
+
+    for pair in [0, 1, 3].iter().copied() {
+        for hl in EDGES.iter().copied() {
+            for value in EDGES.iter().copied() {
+                set_pair(&mut state, pair, value);
+                set_pair(&mut state, 2, hl);
+                state.cc.cy = !state.cc.cy;
+                state.cc.z = true;
+                run(&mut state, 0x09 | (pair as u8) << 4);
+
+                let sum = hl as u32 + value as u32;
+                assert_eq!(
+                    get_pair(&state, 2),
+                    sum as u16,
+                    "{:04X} + {:04X}",
+                    hl,
+                    value
+                );
+                assert_eq!(state.cc.cy, sum > 0xFFFF, "{:04X} + {:04X}", hl, value);
+                // Only CY is affected
+                assert!(state.cc.z);
+            }
+        }
+    }
+}
+
+#[test]
+fn dad_h_doubles() {
+    let mut mem = vec![0; 0x10];
+    let mut state = State::new(&mut mem);
+
+    for hl in EDGES.iter().copied() {
+        set_pair(&mut state, 2, hl);
+        run(&mut state, 0x29);
+
+        assert_eq!(get_pair(&state, 2), hl.wrapping_mul(2));
+        assert_eq!(state.cc.cy, hl & 0x8000 != 0);
+    }
+}
+
+#[test]
+fn stack_wraps_at_top_of_memory() {
+    let mut mem = vec![0; 0x10000];
+    let mut state = State::new(&mut mem);
+
+    // PUSH B with SP at 0x0001 writes 0x0000 and 0xFFFF
+    set_pair(&mut state, 0, 0x1234);
+    state.sp = 0x0001;
+    run(&mut state, 0xC5);
+    assert_eq!(state.sp, 0xFFFF);
+    assert_eq!((state.mem[0xFFFF], state.mem[0x0000]), (0x34, 0x12));
+
+    // XTHL swaps across the wrap
+    set_pair(&mut state, 2, 0xABCD);
+    run(&mut state, 0xE3);
+    assert_eq!(get_pair(&state, 2), 0x1234);
+    assert_eq!((state.mem[0xFFFF], state.mem[0x0000]), (0xCD, 0xAB));
+
+    // POP D reads it back and wraps SP to 0x0001
+    run(&mut state, 0xD1);
+    assert_eq!(get_pair(&state, 1), 0xABCD);
+    assert_eq!(state.sp, 0x0001);
+}
+
+#[test]
+fn sphl_and_xchg() {
+    let mut mem = vec![0; 0x10];
+    let mut state = State::new(&mut mem);
+
+    for value in EDGES.iter().copied() {
+        set_pair(&mut state, 1, !value);
+        set_pair(&mut state, 2, value);
+
+        run(&mut state, 0xF9);
+        assert_eq!(get_pair(&state, 3), value);
+
+        run(&mut state, 0xEB);
+        assert_eq!(get_pair(&state, 1), value);
+        assert_eq!(get_pair(&state, 2), !value);
+    }
+}
@@ -0,0 +1,46 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::pacer::Speed;
+
+pub mod terminal;
+#[cfg(feature = "window")]
+pub mod window;
+
+// Frames run for each one of real time while fast-forwarding
+const FAST_FORWARD: u32 = 4;
+
+// Speed to run at while fast-forwarding, or not, from `normal`
+fn fast_forward_speed(normal: Speed, fast_forward: bool) -> Speed {
+    match normal {
+        Speed::Scaled(multiplier) if fast_forward => Speed::Scaled(multiplier * FAST_FORWARD),
+        _ => normal,
+    }
+}
+
+/// Saves 0x00RRGGBB `pixels` as a binary PPM image.
+pub fn screenshot(path: &Path, pixels: &[u32], width: usize, height: usize) -> io::Result<()> {
+    let mut image = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+    for pixel in pixels.iter().take(width * height) {
+        image.extend_from_slice(&[(pixel >> 16) as u8, (pixel >> 8) as u8, *pixel as u8]);
+    }
+
+    fs::write(path, image)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ppm_screenshot() {
+        let path = std::env::temp_dir().join(format!("emurs-shot-{}.ppm", std::process::id()));
+
+        screenshot(&path, &[0x123456, 0xFFFFFF], 2, 1).unwrap();
+
+        let image = fs::read(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(image, b"P6\n2 1\n255\n\x12\x34\x56\xFF\xFF\xFF");
+    }
+}
@@ -0,0 +1,253 @@
+use std::error;
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::time::Duration;
+
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::{cursor, execute, terminal};
+
+use super::fast_forward_speed;
+use crate::machines::input::{InputMap, Inputs};
+use crate::machines::midway::video::BLACK;
+use crate::machines::midway::Midway;
+use crate::pacer::Pacer;
+use crate::scheduler::Clocked;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mode {
+    /// Two pixels a character with `▀`: full resolution, but 128 rows tall
+    HalfBlock,
+    /// Eight pixels a character with braille dots, fitting in 112 by 64
+    Braille,
+}
+
+// Terminals only report presses, so a key counts as held for this many frames
+// after each one, long enough for the keyboard's auto-repeat to keep it down
+const HOLD_FRAMES: u32 = 8;
+
+/// Draws `pixels` as text, one line per row of characters, coloured with ANSI
+/// 24-bit escapes.
+pub fn render(pixels: &[u32], width: usize, height: usize, mode: Mode) -> String {
+    let mut out = String::new();
+    let pixel = |x: usize, y: usize| {
+        if x < width && y < height {
+            pixels[y * width + x]
+        } else {
+            BLACK
+        }
+    };
+
+    match mode {
+        Mode::HalfBlock => {
+            for y in (0..height).step_by(2) {
+                let mut colors = None;
+                for x in 0..width {
+                    let (top, bottom) = (pixel(x, y), pixel(x, y + 1));
+                    if colors != Some((top, bottom)) {
+                        out.push_str(&foreground(top));
+                        out.push_str(&background(bottom));
+                        colors = Some((top, bottom));
+                    }
+                    out.push('▀');
+                }
+                out.push_str("\x1b[0m\r\n");
+            }
+        }
+        Mode::Braille => {
+            // Dot bits for each pixel of a 2 by 4 cell, by column then row
+            const DOTS: [[u32; 4]; 2] = [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+
+            for y in (0..height).step_by(4) {
+                let mut current = None;
+                for x in (0..width).step_by(2) {
+                    let mut dots = 0;
+                    let mut color = BLACK;
+                    for (dx, column) in DOTS.iter().enumerate() {
+                        for (dy, dot) in column.iter().enumerate() {
+                            let lit = pixel(x + dx, y + dy);
+                            if lit != BLACK {
+                                dots |= dot;
+                                color = lit;
+                            }
+                        }
+                    }
+
+                    if dots == 0 {
+                        out.push(' ');
+                        continue;
+                    }
+                    if current != Some(color) {
+                        out.push_str(&foreground(color));
+                        current = Some(color);
+                    }
+                    out.push(std::char::from_u32(0x2800 + dots).unwrap());
+                }
+                out.push_str("\x1b[0m\r\n");
+            }
+        }
+    }
+
+    out
+}
+
+fn foreground(color: u32) -> String {
+    let mut escape = String::new();
+    let (r, g, b) = (color >> 16, (color >> 8) & 0xFF, color & 0xFF);
+    write!(escape, "\x1b[38;2;{};{};{}m", r, g, b).unwrap();
+    escape
+}
+
+fn background(color: u32) -> String {
+    let mut escape = String::new();
+    let (r, g, b) = (color >> 16, (color >> 8) & 0xFF, color & 0xFF);
+    write!(escape, "\x1b[48;2;{};{};{}m", r, g, b).unwrap();
+    escape
+}
+
+/// The name of a key in an `InputMap`.
+pub fn key_name(key: KeyCode) -> Option<String> {
+    let name = match key {
+        KeyCode::Char(' ') => "space",
+        KeyCode::Char(c) => return Some(c.to_lowercase().collect()),
+        KeyCode::Left => "left",
+        KeyCode::Right => "right",
+        KeyCode::Up => "up",
+        KeyCode::Down => "down",
+        KeyCode::Enter => "enter",
+        KeyCode::Tab => "tab",
+        KeyCode::Backspace => "backspace",
+        _ => return None,
+    };
+    Some(name.to_string())
+}
+
+// Puts the terminal back however the frontend exits
+struct Raw;
+
+impl Raw {
+    fn enter() -> io::Result<Raw> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(Raw)
+    }
+}
+
+impl Drop for Raw {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Plays `midway` in the terminal with keys bound by `inputs`, paced by `pacer`,
+/// until Q, Escape or Ctrl-C. Tab toggles fast-forward.
+pub fn run(
+    midway: &mut Midway,
+    mode: Mode,
+    inputs: &InputMap,
+    pacer: &mut Pacer,
+) -> Result<(), Box<dyn error::Error>> {
+    let _raw = Raw::enter()?;
+    let mut held: Vec<(String, u32)> = Vec::new();
+    let mut stdout = io::stdout();
+    let normal = pacer.speed();
+    let mut fast_forward = false;
+    pacer.resync(midway.cycles());
+
+    for frame in 0.. {
+        while event::poll(Duration::from_secs(0))? {
+            if let Event::Key(KeyEvent {
+                code,
+                modifiers,
+                kind,
+                ..
+            }) = event::read()?
+            {
+                if kind == KeyEventKind::Release {
+                    continue;
+                }
+                let ctrl_c =
+                    code == KeyCode::Char('c') && modifiers.contains(KeyModifiers::CONTROL);
+                if ctrl_c || code == KeyCode::Char('q') || code == KeyCode::Esc {
+                    return Ok(());
+                }
+                if code == KeyCode::Tab {
+                    fast_forward = !fast_forward;
+                    pacer.set_speed(fast_forward_speed(normal, fast_forward), midway.cycles());
+                    continue;
+                }
+
+                let name = key_name(code);
+                if let Some(control) = name.as_deref().and_then(|key| inputs.control(key)) {
+                    midway.board_mut().set_control(control, true);
+                    held.retain(|(held, _)| held != control);
+                    held.push((control.to_string(), HOLD_FRAMES));
+                }
+            }
+        }
+
+        midway.run_frame();
+        // There's no audio output here, so drop the sound writes before they pile up
+        midway.board_mut().sound_writes.clear();
+
+        for (control, frames) in held.iter_mut() {
+            *frames -= 1;
+            if *frames == 0 {
+                midway.board_mut().set_control(control, false);
+            }
+        }
+        held.retain(|(_, frames)| *frames > 0);
+
+        if pacer.draw(frame) {
+            let (width, height) = midway.size();
+            let text = render(&midway.render(), width, height, mode);
+            execute!(stdout, cursor::MoveTo(0, 0))?;
+            stdout.write_all(text.as_bytes())?;
+            stdout.flush()?;
+        }
+
+        pacer.wait(midway.cycles());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::machines::midway::video::WHITE;
+
+    #[test]
+    fn half_blocks() {
+        // Red over white, then black over black
+        let pixels = [0xFF0000, BLACK, WHITE, BLACK];
+
+        let text = render(&pixels, 2, 2, Mode::HalfBlock);
+
+        assert_eq!(
+            text,
+            "\x1b[38;2;255;0;0m\x1b[48;2;255;255;255m▀\
+             \x1b[38;2;0;0;0m\x1b[48;2;0;0;0m▀\x1b[0m\r\n"
+        );
+    }
+
+    #[test]
+    fn braille_dots() {
+        // Top left and bottom right of one cell lit, then an empty cell
+        let mut pixels = [BLACK; 4 * 4];
+        pixels[0] = WHITE;
+        pixels[3 * 4 + 1] = WHITE;
+
+        let text = render(&pixels, 4, 4, Mode::Braille);
+
+        assert_eq!(text, "\x1b[38;2;255;255;255m\u{2881} \x1b[0m\r\n");
+    }
+
+    #[test]
+    fn keys() {
+        assert_eq!(key_name(KeyCode::Char('C')), Some("c".to_string()));
+        assert_eq!(key_name(KeyCode::Left), Some("left".to_string()));
+        assert_eq!(key_name(KeyCode::Char(' ')), Some("space".to_string()));
+        assert_eq!(key_name(KeyCode::Esc), None);
+    }
+}
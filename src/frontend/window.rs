@@ -0,0 +1,158 @@
+use std::error;
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+use minifb::{Key, KeyRepeat, Scale, ScaleMode, Window, WindowOptions};
+
+use super::{fast_forward_speed, screenshot};
+use crate::machines::input::InputMap;
+use crate::machines::midway::{Midway, FRAMES_PER_SECOND};
+use crate::pacer::Pacer;
+use crate::scheduler::Clocked;
+
+/// Plays `midway` in a window scaled up by `scale`, with keys bound by `inputs` and
+/// paced by `pacer`, until it's closed or Escape is pressed, or `frames` have run.
+/// P pauses, F3 resets, Tab fast-forwards while held and F12 saves a screenshot.
+///
+/// Without a display it runs `frames` headless instead, ten seconds' worth if
+/// there's no limit.
+pub fn run(
+    midway: &mut Midway,
+    scale: Scale,
+    frames: Option<u64>,
+    inputs: &InputMap,
+    pacer: &mut Pacer,
+) -> Result<(), Box<dyn error::Error>> {
+    let title = midway.board().driver().description;
+    let (width, height) = midway.size();
+    let options = WindowOptions {
+        resize: true,
+        scale,
+        scale_mode: ScaleMode::AspectRatioStretch,
+        ..WindowOptions::default()
+    };
+    let mut window = match Window::new(title, width, height, options) {
+        Ok(window) => window,
+        Err(err) => {
+            eprintln!("No window ({}), running headless", err);
+            run_headless(midway, frames.unwrap_or(FRAMES_PER_SECOND * 10));
+            return Ok(());
+        }
+    };
+    pacer.resync(midway.cycles());
+
+    let normal = pacer.speed();
+    let mut paused = false;
+    let mut frame = 0;
+    while window.is_open() && !window.is_key_down(Key::Escape) && Some(frame) != frames {
+        let down: Vec<String> = window.get_keys().into_iter().map(key_name).collect();
+        inputs.update(midway.board_mut(), &down);
+
+        if window.is_key_pressed(Key::P, KeyRepeat::No) {
+            paused = !paused;
+            window.set_title(&if paused {
+                format!("{} (paused)", title)
+            } else {
+                title.to_string()
+            });
+            pacer.resync(midway.cycles());
+        }
+        if window.is_key_pressed(Key::F3, KeyRepeat::No) {
+            midway.reset();
+        }
+        if paused {
+            window.update();
+            thread::sleep(Duration::from_secs(1) / FRAMES_PER_SECOND as u32);
+            continue;
+        }
+
+        let speed = fast_forward_speed(normal, window.is_key_down(Key::Tab));
+        pacer.set_speed(speed, midway.cycles());
+        midway.run_frame();
+        // There's no audio output here, so drop the sound writes before they pile up
+        midway.board_mut().sound_writes.clear();
+
+        let shoot = window.is_key_pressed(Key::F12, KeyRepeat::No);
+        if pacer.draw(frame) || shoot {
+            let pixels = midway.render();
+            if shoot {
+                let path = screenshot_path(midway.board().driver().name);
+                screenshot(&path, &pixels, width, height)?;
+                eprintln!("Saved {}", path.display());
+            }
+            window.update_with_buffer(&pixels, width, height)?;
+        } else {
+            window.update();
+        }
+
+        frame += 1;
+        pacer.wait(midway.cycles());
+    }
+
+    Ok(())
+}
+
+fn run_headless(midway: &mut Midway, frames: u64) {
+    for _ in 0..frames {
+        midway.run_frame();
+        midway.board_mut().sound_writes.clear();
+    }
+}
+
+// The name of a key in an `InputMap`: Key1 is 1, Left is left
+fn key_name(key: Key) -> String {
+    let name = format!("{:?}", key).to_lowercase();
+    match name.strip_prefix("key") {
+        Some(digit) => digit.to_string(),
+        None => name,
+    }
+}
+
+// The first of invaders-0.ppm, invaders-1.ppm and so on that's free
+fn screenshot_path(name: &str) -> PathBuf {
+    (0..)
+        .map(|n| PathBuf::from(format!("{}-{}.ppm", name, n)))
+        .find(|path| !path.exists())
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::machines::midway::{drivers, CPU_HZ, CYCLES_PER_FRAME};
+    use crate::pacer::Speed;
+    use std::env;
+    use std::path::Path;
+
+    // Only checks anything where there's no display to open a window on, as in CI
+    #[test]
+    fn headless_without_a_display() {
+        if env::var_os("DISPLAY").is_some() || env::var_os("WAYLAND_DISPLAY").is_some() {
+            return;
+        }
+        let rom_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("games/invaders");
+        let mut mem = vec![0; 0x10000];
+        let mut midway = Midway::new(&mut mem, &drivers::INVADERS, &rom_dir).unwrap();
+        let mut pacer = Pacer::new(CPU_HZ, Speed::Uncapped);
+
+        run(
+            &mut midway,
+            Scale::X1,
+            Some(30),
+            &InputMap::default(),
+            &mut pacer,
+        )
+        .unwrap();
+
+        assert!(midway.cycles() >= 30 * CYCLES_PER_FRAME);
+        assert!(midway.board().sound_writes.is_empty());
+    }
+
+    #[test]
+    fn keys() {
+        assert_eq!(key_name(Key::Key1), "1");
+        assert_eq!(key_name(Key::Left), "left");
+        assert_eq!(key_name(Key::Space), "space");
+    }
+}
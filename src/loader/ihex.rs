@@ -0,0 +1,179 @@
+use std::fmt::Write;
+use std::ops::Range;
+
+use super::LoadError;
+
+const DATA: u8 = 0x00;
+const END_OF_FILE: u8 = 0x01;
+const EXTENDED_SEGMENT_ADDRESS: u8 = 0x02;
+const START_SEGMENT_ADDRESS: u8 = 0x03;
+const EXTENDED_LINEAR_ADDRESS: u8 = 0x04;
+const START_LINEAR_ADDRESS: u8 = 0x05;
+
+const BYTES_PER_RECORD: usize = 16;
+
+/// Parses Intel HEX `text` into `mem`, returning the start address if the file has one.
+pub fn load(text: &str, mem: &mut [u8]) -> Result<Option<u16>, LoadError> {
+    let mut base: usize = 0;
+    let mut start = None;
+
+    for (index, line) in text.lines().enumerate() {
+        let line_no = index + 1;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let record = line
+            .strip_prefix(':')
+            .ok_or_else(|| LoadError::new(line_no, "record does not start with `:`"))?;
+        let bytes = hex::decode(record).map_err(|e| LoadError::new(line_no, &e.to_string()))?;
+
+        if bytes.len() < 5 || bytes.len() != bytes[0] as usize + 5 {
+            return Err(LoadError::new(
+                line_no,
+                "record length does not match byte count",
+            ));
+        }
+
+        let checksum = bytes.iter().fold(0u8, |sum, b| sum.wrapping_add(*b));
+        if checksum != 0 {
+            return Err(LoadError::new(line_no, "bad checksum"));
+        }
+
+        let address = ((bytes[1] as usize) << 8) | bytes[2] as usize;
+        let data = &bytes[4..bytes.len() - 1];
+
+        match bytes[3] {
+            DATA => {
+                let offset = base + address;
+                let dest = mem
+                    .get_mut(offset..offset + data.len())
+                    .ok_or_else(|| LoadError::new(line_no, "data outside of memory"))?;
+                dest.copy_from_slice(data);
+            }
+            END_OF_FILE => break,
+            EXTENDED_SEGMENT_ADDRESS if data.len() == 2 => {
+                base = (((data[0] as usize) << 8) | data[1] as usize) << 4;
+            }
+            EXTENDED_LINEAR_ADDRESS if data.len() == 2 => {
+                base = (((data[0] as usize) << 8) | data[1] as usize) << 16;
+            }
+            START_SEGMENT_ADDRESS if data.len() == 4 => {
+                let cs = ((data[0] as usize) << 8) | data[1] as usize;
+                let ip = ((data[2] as usize) << 8) | data[3] as usize;
+                start = Some(((cs << 4) + ip) as u16);
+            }
+            START_LINEAR_ADDRESS if data.len() == 4 => {
+                start = Some(((data[2] as u16) << 8) | data[3] as u16);
+            }
+            EXTENDED_SEGMENT_ADDRESS
+            | EXTENDED_LINEAR_ADDRESS
+            | START_SEGMENT_ADDRESS
+            | START_LINEAR_ADDRESS => {
+                return Err(LoadError::new(line_no, "malformed address record"));
+            }
+            kind => {
+                return Err(LoadError::new(
+                    line_no,
+                    &format!("unknown record type {:02X}", kind),
+                ));
+            }
+        }
+    }
+
+    Ok(start)
+}
+
+fn record(out: &mut String, kind: u8, address: u16, data: &[u8]) {
+    let mut bytes = vec![data.len() as u8, (address >> 8) as u8, address as u8, kind];
+    bytes.extend_from_slice(data);
+    let checksum = bytes
+        .iter()
+        .fold(0u8, |sum, b| sum.wrapping_add(*b))
+        .wrapping_neg();
+    bytes.push(checksum);
+
+    writeln!(out, ":{}", hex::encode_upper(bytes)).expect("Failed to write to string");
+}
+
+/// Dumps `mem[range]` as Intel HEX, with a start segment address record if `start` is given.
+pub fn save(mem: &[u8], range: Range<usize>, start: Option<u16>) -> String {
+    let mut out = String::new();
+    let mut upper = 0;
+
+    for (index, chunk) in mem[range.clone()].chunks(BYTES_PER_RECORD).enumerate() {
+        let address = range.start + index * BYTES_PER_RECORD;
+        if address >> 16 != upper {
+            upper = address >> 16;
+            record(
+                &mut out,
+                EXTENDED_LINEAR_ADDRESS,
+                0,
+                &[(upper >> 8) as u8, upper as u8],
+            );
+        }
+        record(&mut out, DATA, address as u16, chunk);
+    }
+
+    if let Some(start) = start {
+        record(
+            &mut out,
+            START_SEGMENT_ADDRESS,
+            0,
+            &[0, 0, (start >> 8) as u8, start as u8],
+        );
+    }
+    record(&mut out, END_OF_FILE, 0, &[]);
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_data_and_start() {
+        let text = ":0300300002337A1E\n:040000030000010BED\n:00000001FF\n";
+        let mut mem = [0; 0x40];
+
+        let start = load(text, &mut mem).unwrap();
+
+        assert_eq!(&mem[0x30..0x33], &[0x02, 0x33, 0x7A]);
+        assert_eq!(start, Some(0x010B));
+    }
+
+    #[test]
+    fn load_bad_checksum() {
+        let mut mem = [0; 0x40];
+
+        let err = load(":0300300002337A1F\n", &mut mem).unwrap_err();
+
+        assert_eq!(err.line, 1);
+    }
+
+    #[test]
+    fn load_outside_memory() {
+        let mut mem = [0; 0x10];
+
+        assert!(load(":0300300002337A1E\n", &mut mem).is_err());
+    }
+
+    #[test]
+    fn save_round_trip() {
+        let mut mem = [0; 0x100];
+        for (i, byte) in mem.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+
+        let text = save(&mem, 0x08..0x2C, Some(0x0100));
+        let mut loaded = [0; 0x100];
+        let start = load(&text, &mut loaded).unwrap();
+
+        assert_eq!(&loaded[0x08..0x2C], &mem[0x08..0x2C]);
+        assert_eq!(loaded[0x07], 0);
+        assert_eq!(loaded[0x2C], 0);
+        assert_eq!(start, Some(0x0100));
+    }
+}
@@ -0,0 +1,91 @@
+use std::error;
+use std::fmt;
+use std::fs;
+use std::ops::Range;
+use std::path::Path;
+
+pub mod ihex;
+pub mod srec;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Binary,
+    IntelHex,
+    SRecord,
+}
+
+impl Format {
+    pub fn from_path(path: &Path) -> Format {
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_ascii_lowercase());
+
+        match extension.as_deref() {
+            Some("hex") | Some("ihx") => Format::IntelHex,
+            Some("s19") | Some("srec") | Some("mot") => Format::SRecord,
+            _ => Format::Binary,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct LoadError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl LoadError {
+    fn new(line: usize, message: &str) -> LoadError {
+        LoadError {
+            line,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl error::Error for LoadError {}
+
+/// Loads the file at `path` into `mem`, picking the format from the extension.
+/// Raw binaries are placed at `offset`; HEX and S-record files carry their own addresses.
+/// Returns the start address when the file specifies one.
+pub fn load(
+    path: &Path,
+    mem: &mut [u8],
+    offset: usize,
+) -> Result<Option<u16>, Box<dyn error::Error>> {
+    match Format::from_path(path) {
+        Format::Binary => {
+            let contents = fs::read(path)?;
+            let dest = mem
+                .get_mut(offset..offset + contents.len())
+                .ok_or("Binary does not fit in memory")?;
+            dest.copy_from_slice(&contents);
+            Ok(None)
+        }
+        Format::IntelHex => Ok(ihex::load(&fs::read_to_string(path)?, mem)?),
+        Format::SRecord => Ok(srec::load(&fs::read_to_string(path)?, mem)?),
+    }
+}
+
+/// Writes `mem[range]` to `path` in the format picked from its extension.
+pub fn save(
+    path: &Path,
+    mem: &[u8],
+    range: Range<usize>,
+    start: Option<u16>,
+) -> Result<(), Box<dyn error::Error>> {
+    match Format::from_path(path) {
+        Format::Binary => fs::write(path, &mem[range])?,
+        Format::IntelHex => fs::write(path, ihex::save(mem, range, start))?,
+        Format::SRecord => fs::write(path, srec::save(mem, range, start)?)?,
+    }
+
+    Ok(())
+}
@@ -0,0 +1,207 @@
+use std::error;
+use std::fmt::Write;
+use std::ops::Range;
+
+use super::LoadError;
+
+const BYTES_PER_RECORD: usize = 16;
+
+/// Parses Motorola S-record `text` into `mem`, returning the start address from the
+/// S7/S8/S9 termination record if there is one.
+pub fn load(text: &str, mem: &mut [u8]) -> Result<Option<u16>, LoadError> {
+    let mut start = None;
+
+    for (index, line) in text.lines().enumerate() {
+        let line_no = index + 1;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if !line.starts_with('S') || line.len() < 2 {
+            return Err(LoadError::new(line_no, "record does not start with `S`"));
+        }
+        let kind = line.as_bytes()[1];
+        let bytes = hex::decode(&line.as_bytes()[2..])
+            .map_err(|e| LoadError::new(line_no, &e.to_string()))?;
+
+        if bytes.is_empty() || bytes.len() != bytes[0] as usize + 1 {
+            return Err(LoadError::new(
+                line_no,
+                "record length does not match byte count",
+            ));
+        }
+
+        let checksum = bytes.iter().fold(0u8, |sum, b| sum.wrapping_add(*b));
+        if checksum != 0xFF {
+            return Err(LoadError::new(line_no, "bad checksum"));
+        }
+
+        let address_len = match kind {
+            b'0' | b'1' | b'5' | b'9' => 2,
+            b'2' | b'6' | b'8' => 3,
+            b'3' | b'7' => 4,
+            _ => {
+                return Err(LoadError::new(
+                    line_no,
+                    &format!("unknown record type S{}", kind as char),
+                ));
+            }
+        };
+        if bytes.len() < address_len + 2 {
+            return Err(LoadError::new(line_no, "record too short for its address"));
+        }
+
+        let address = bytes[1..=address_len]
+            .iter()
+            .fold(0usize, |addr, b| (addr << 8) | *b as usize);
+        let data = &bytes[address_len + 1..bytes.len() - 1];
+
+        match kind {
+            b'1' | b'2' | b'3' => {
+                let dest = mem
+                    .get_mut(address..address + data.len())
+                    .ok_or_else(|| LoadError::new(line_no, "data outside of memory"))?;
+                dest.copy_from_slice(data);
+            }
+            b'7' | b'8' | b'9' => {
+                start = Some(address as u16);
+                break;
+            }
+            // S0 headers and S5/S6 record counts carry nothing to load
+            _ => {}
+        }
+    }
+
+    Ok(start)
+}
+
+// Writes a record with an `address_len` byte address
+fn record(out: &mut String, kind: u8, address: u32, address_len: usize, data: &[u8]) {
+    let mut bytes = vec![(data.len() + address_len + 1) as u8];
+    bytes.extend_from_slice(&address.to_be_bytes()[4 - address_len..]);
+    bytes.extend_from_slice(data);
+    let checksum = !bytes.iter().fold(0u8, |sum, b| sum.wrapping_add(*b));
+    bytes.push(checksum);
+
+    writeln!(out, "S{}{}", kind, hex::encode_upper(bytes)).expect("Failed to write to string");
+}
+
+/// Dumps `mem[range]` as S19 (16-bit address) records, followed by an S5 record count,
+/// or S6 when it takes 24 bits, and an S9 carrying `start` if there is one.
+pub fn save(
+    mem: &[u8],
+    range: Range<usize>,
+    start: Option<u16>,
+) -> Result<String, Box<dyn error::Error>> {
+    if range.end > 0x10000 {
+        return Err("S19 records only address 64K".into());
+    }
+    let data = mem
+        .get(range.clone())
+        .ok_or("Range to save is outside of memory")?;
+
+    let mut out = String::new();
+    record(&mut out, 0, 0, 2, b"emurs");
+
+    let mut count = 0;
+    for (index, chunk) in data.chunks(BYTES_PER_RECORD).enumerate() {
+        let address = range.start + index * BYTES_PER_RECORD;
+        record(&mut out, 1, address as u32, 2, chunk);
+        count += 1;
+    }
+
+    if count <= 0xFFFF {
+        record(&mut out, 5, count, 2, &[]);
+    } else {
+        record(&mut out, 6, count, 3, &[]);
+    }
+    if let Some(start) = start {
+        record(&mut out, 9, start as u32, 2, &[]);
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_data_and_start() {
+        let text = "S00F000068656C6C6F202020202000003C\n\
+                    S11F00007C0802A6900100049421FFF07C6C1B787C8C23783C6000003863000026\n\
+                    S5030001FB\n\
+                    S9030100FB\n";
+        let mut mem = [0; 0x20];
+
+        let start = load(text, &mut mem).unwrap();
+
+        assert_eq!(&mem[0x00..0x04], &[0x7C, 0x08, 0x02, 0xA6]);
+        assert_eq!(mem[0x1B], 0x00);
+        assert_eq!(start, Some(0x0100));
+    }
+
+    #[test]
+    fn load_bad_checksum() {
+        let mut mem = [0; 0x20];
+
+        let err = load("S1050000AABB00\n", &mut mem).unwrap_err();
+
+        assert_eq!(err.line, 1);
+    }
+
+    #[test]
+    fn load_non_ascii() {
+        let mut mem = [0; 0x20];
+
+        let err = load("S1050000AABB95\nSé050000AABB00\n", &mut mem).unwrap_err();
+
+        assert_eq!(err.line, 2);
+    }
+
+    #[test]
+    fn save_round_trip() {
+        let mut mem = [0; 0x100];
+        for (i, byte) in mem.iter_mut().enumerate() {
+            *byte = !(i as u8);
+        }
+
+        let text = save(&mem, 0x10..0x35, Some(0x0010)).unwrap();
+        let mut loaded = [0; 0x100];
+        let start = load(&text, &mut loaded).unwrap();
+
+        assert_eq!(&loaded[0x10..0x35], &mem[0x10..0x35]);
+        assert_eq!(loaded[0x35], 0);
+        assert_eq!(start, Some(0x0010));
+    }
+
+    #[test]
+    fn save_start_at_zero() {
+        let mem = [0x76; 0x10];
+
+        let with_start = save(&mem, 0..0x10, Some(0)).unwrap();
+        let without = save(&mem, 0..0x10, None).unwrap();
+
+        assert_eq!(load(&with_start, &mut [0; 0x10]).unwrap(), Some(0));
+        assert_eq!(load(&without, &mut [0; 0x10]).unwrap(), None);
+    }
+
+    #[test]
+    fn save_outside_64k() {
+        let mem = vec![0; 0x10010];
+
+        assert!(save(&mem, 0xFFF0..0x10010, None).is_err());
+        assert!(save(&mem[..0x100], 0x80..0x200, None).is_err());
+    }
+
+    #[test]
+    fn record_counts() {
+        let mut out = String::new();
+        record(&mut out, 5, 0x1234, 2, &[]);
+        record(&mut out, 6, 0x012345, 3, &[]);
+
+        assert_eq!(out, "S5031234B6\nS60401234592\n");
+        load(&out, &mut []).unwrap();
+    }
+}
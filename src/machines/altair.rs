@@ -0,0 +1,302 @@
+use std::error;
+
+use super::input::Inputs;
+use super::Console;
+use crate::cpu::{Cpu, CpuKind};
+use crate::emulator::{Io, Status};
+
+// 88-SIO serial board
+pub const SIO_STATUS: u8 = 0x00;
+pub const SIO_DATA: u8 = 0x01;
+// First port of the 88-2SIO board
+pub const SIO2_STATUS: u8 = 0x10;
+pub const SIO2_DATA: u8 = 0x11;
+pub const SENSE_SWITCHES: u8 = 0xFF;
+
+// 88-SIO status bits are active low
+const SIO_INPUT_NOT_READY: u8 = 0b00000001;
+// 88-2SIO (MC6850 ACIA) status bits are active high
+const SIO2_RECEIVE_FULL: u8 = 0b00000001;
+const SIO2_TRANSMIT_EMPTY: u8 = 0b00000010;
+
+pub struct Config {
+    /// Bytes of RAM from address 0. Anything above reads back 0xFF.
+    pub ram: usize,
+    pub sense_switches: u8,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            ram: 0x10000,
+            sense_switches: 0x00,
+        }
+    }
+}
+
+/// The Altair's I/O bus: both serial boards share the console, and the front panel
+/// sense switches are read on port 0xFF.
+pub struct Ports<C: Console> {
+    pub console: C,
+    pub sense_switches: u8,
+}
+
+impl<C: Console> Ports<C> {
+    fn receive(&mut self) -> u8 {
+        if !self.console.ready() {
+            return 0;
+        }
+        match self.console.read() {
+            Some(b'\n') => b'\r',
+            Some(byte) => byte,
+            None => 0,
+        }
+    }
+}
+
+impl<C: Console> Io for Ports<C> {
+    fn input(&mut self, port: u8) -> u8 {
+        match port {
+            SIO_STATUS => {
+                if self.console.ready() {
+                    0
+                } else {
+                    SIO_INPUT_NOT_READY
+                }
+            }
+            SIO2_STATUS => {
+                let received = if self.console.ready() {
+                    SIO2_RECEIVE_FULL
+                } else {
+                    0
+                };
+                received | SIO2_TRANSMIT_EMPTY
+            }
+            SIO_DATA | SIO2_DATA => self.receive(),
+            SENSE_SWITCHES => self.sense_switches,
+            _ => 0xFF,
+        }
+    }
+
+    fn output(&mut self, port: u8, value: u8) {
+        match port {
+            SIO_DATA | SIO2_DATA => self.console.write(value & 0x7F),
+            // Status writes only configure the boards
+            _ => {}
+        }
+    }
+}
+
+// Each sense switch is a control, sense0 to sense7, that's up while pressed. The
+// whole bank can also be set as a hex setting, sense_switches.
+impl<C: Console> Inputs for Ports<C> {
+    fn set_control(&mut self, name: &str, pressed: bool) -> bool {
+        let bit = match name
+            .strip_prefix("sense")
+            .and_then(|bit| bit.parse::<u8>().ok())
+        {
+            Some(bit) if bit < 8 => 1 << bit,
+            _ => return false,
+        };
+
+        if pressed {
+            self.sense_switches |= bit;
+        } else {
+            self.sense_switches &= !bit;
+        }
+        true
+    }
+
+    fn set_setting(&mut self, name: &str, value: &str) -> Result<(), Box<dyn error::Error>> {
+        match name {
+            "sense_switches" => self.sense_switches = u8::from_str_radix(value, 16)?,
+            _ => return Err(format!("The Altair has no {} setting", name).into()),
+        }
+        Ok(())
+    }
+}
+
+/// A MITS Altair 8800 with an 88-SIO and 88-2SIO wired to the same console.
+pub struct Altair<'a, C: Console> {
+    cpu: Box<dyn Cpu + 'a>,
+    ports: Ports<C>,
+}
+
+impl<'a, C: Console> Altair<'a, C> {
+    /// Clears memory above `config.ram` to read as unpopulated, so ROM images go in
+    /// afterwards through `memory_mut`.
+    pub fn new(mem: &'a mut [u8], config: Config, console: C) -> Altair<'a, C> {
+        assert!(mem.len() >= 0x10000, "The Altair needs a 64K address space");
+
+        let ram = config.ram.min(0x10000);
+        for byte in mem[ram..].iter_mut() {
+            *byte = 0xFF;
+        }
+
+        let mut cpu = CpuKind::I8080.build(mem);
+        cpu.protect(ram..0x10000);
+
+        Altair {
+            cpu,
+            ports: Ports {
+                console,
+                sense_switches: config.sense_switches,
+            },
+        }
+    }
+
+    pub fn set_pc(&mut self, pc: u16) {
+        self.cpu.set_pc(pc);
+    }
+
+    /// Memory for loading images, including above RAM where the CPU can't write.
+    pub fn memory_mut(&mut self) -> &mut [u8] {
+        self.cpu.memory_mut()
+    }
+
+    pub fn ports(&mut self) -> &mut Ports<C> {
+        &mut self.ports
+    }
+
+    /// Runs until the CPU halts. Nothing on this bus raises interrupts, so it stays
+    /// halted.
+    pub fn run(&mut self) -> Status {
+        loop {
+            let status = self.cpu.step_io(&mut self.ports);
+            if status != Status::Running {
+                return status;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::machines::BufferConsole;
+
+    fn run(program: &[u8], config: Config, input: &[u8]) -> (Vec<u8>, Vec<u8>) {
+        let mut mem = vec![0; 0x10000];
+        mem[..program.len()].copy_from_slice(program);
+
+        let mut altair = Altair::new(&mut mem, config, BufferConsole::new(input));
+        altair.run();
+        let output = altair.ports().console.output.clone();
+        drop(altair);
+
+        (output, mem)
+    }
+
+    #[test]
+    fn echo_through_sio() {
+        let program = [
+            0xDB, SIO_DATA, // IN 1
+            0xD3, SIO_DATA, // OUT 1
+            0xDB, SIO2_DATA, // IN 11
+            0xD3, SIO2_DATA, // OUT 11
+            0x76,
+        ];
+
+        let (output, _) = run(&program, Config::default(), b"hi");
+
+        assert_eq!(output, b"hi");
+    }
+
+    #[test]
+    fn sio_status() {
+        // Read the SIO status with input pending, drain it, read it again, then
+        // read the 2SIO status
+        let program = [
+            0xDB,
+            SIO_STATUS,
+            0xD3,
+            SIO_DATA,
+            0xDB,
+            SIO_DATA,
+            0xDB,
+            SIO_STATUS,
+            0xD3,
+            SIO_DATA,
+            0xDB,
+            SIO2_STATUS,
+            0xD3,
+            SIO_DATA,
+            0x76,
+        ];
+
+        let (output, _) = run(&program, Config::default(), b"x");
+
+        assert_eq!(output, &[0x00, SIO_INPUT_NOT_READY, SIO2_TRANSMIT_EMPTY]);
+    }
+
+    #[test]
+    fn sense_switches() {
+        let program = [0xDB, SENSE_SWITCHES, 0xD3, SIO_DATA, 0x76];
+        let config = Config {
+            sense_switches: 0x42,
+            ..Config::default()
+        };
+
+        let (output, _) = run(&program, config, b"");
+
+        assert_eq!(output, &[0x42]);
+    }
+
+    #[test]
+    fn sense_switches_from_inputs() {
+        let mut mem = vec![0; 0x10000];
+        let mut altair = Altair::new(&mut mem, Config::default(), BufferConsole::new(b""));
+        let ports = altair.ports();
+
+        ports.set_setting("sense_switches", "81").unwrap();
+        assert!(ports.set_control("sense1", true));
+        assert!(ports.set_control("sense7", false));
+        assert!(!ports.set_control("sense8", true));
+        assert!(ports.set_setting("lives", "3").is_err());
+
+        assert_eq!(ports.sense_switches, 0x03);
+    }
+
+    #[test]
+    fn memory_above_ram_is_unpopulated() {
+        let program = [
+            0x3E, 0x12, // MVI A,12
+            0x32, 0x00, 0x10, // STA 1000
+            0x32, 0xFF, 0x0F, // STA 0FFF
+            0x76,
+        ];
+        let config = Config {
+            ram: 0x1000,
+            ..Config::default()
+        };
+
+        let (_, mem) = run(&program, config, b"");
+
+        assert_eq!(mem[0x0FFF], 0x12);
+        assert_eq!(mem[0x1000], 0xFF);
+    }
+
+    // A monitor in ROM above a small RAM boots and can use the RAM below it
+    #[test]
+    fn boots_a_monitor_above_ram() {
+        let monitor = [
+            0x3E, b'>', // MVI A,'>'
+            0x32, 0x00, 0x00, // STA 0000
+            0x3A, 0x00, 0x00, // LDA 0000
+            0xD3, SIO_DATA, // OUT 1
+            0x76,
+        ];
+        let config = Config {
+            ram: 0x1000,
+            ..Config::default()
+        };
+        let mut mem = vec![0; 0x10000];
+        let mut altair = Altair::new(&mut mem, config, BufferConsole::new(b""));
+        altair.memory_mut()[0xF800..0xF800 + monitor.len()].copy_from_slice(&monitor);
+        altair.set_pc(0xF800);
+
+        altair.run();
+
+        assert_eq!(altair.ports().console.output, b">");
+    }
+}
@@ -0,0 +1,906 @@
+use std::error;
+use std::fs::{self, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use super::Console;
+use crate::cpu::{Cpu, CpuKind};
+use crate::emulator::Status;
+
+pub const TPA: usize = 0x0100;
+pub const BDOS: usize = 0xFE00;
+pub const BIOS: usize = 0xFF00;
+
+const DEFAULT_FCB: usize = 0x005C;
+const DEFAULT_DMA: usize = 0x0080;
+
+const RECORD_SIZE: usize = 128;
+const BIOS_ENTRIES: usize = 17;
+
+const JMP: u8 = 0xC3;
+const RET: u8 = 0xC9;
+
+// FCB byte offsets
+const FCB_NAME: usize = 1;
+const FCB_EX: usize = 12;
+const FCB_S2: usize = 14;
+const FCB_RC: usize = 15;
+const FCB_CR: usize = 32;
+const FCB_R0: usize = 33;
+
+// A file in the host directory and its CP/M name
+type DirEntry = ([u8; 11], PathBuf);
+
+/// A CP/M 2.2 system: a `.COM` program in the TPA, with BDOS and BIOS calls
+/// trapped and serviced by the host. Drive A: is mapped to a host directory.
+pub struct Cpm<'a, C: Console> {
+    cpu: Box<dyn Cpu + 'a>,
+    console: C,
+    dir: PathBuf,
+    dma: usize,
+    search: Vec<[u8; 11]>,
+}
+
+impl<'a, C: Console> Cpm<'a, C> {
+    /// CP/M on an 8080.
+    pub fn new(
+        mem: &'a mut [u8],
+        program: &[u8],
+        args: &[String],
+        dir: &Path,
+        console: C,
+    ) -> Result<Cpm<'a, C>, Box<dyn error::Error>> {
+        Self::with_cpu(CpuKind::I8080, mem, program, args, dir, console)
+    }
+
+    pub fn with_cpu(
+        kind: CpuKind,
+        mem: &'a mut [u8],
+        program: &[u8],
+        args: &[String],
+        dir: &Path,
+        console: C,
+    ) -> Result<Cpm<'a, C>, Box<dyn error::Error>> {
+        if mem.len() < 0x10000 {
+            return Err("CP/M needs 64K of memory".into());
+        }
+        if TPA + program.len() > BDOS {
+            return Err("Program does not fit in the TPA".into());
+        }
+
+        for byte in mem[..TPA].iter_mut() {
+            *byte = 0;
+        }
+        mem[TPA..TPA + program.len()].copy_from_slice(program);
+
+        // Warm boot vector and BDOS entry point
+        mem[0x0000] = JMP;
+        mem[0x0001..0x0003].copy_from_slice(&((BIOS + 3) as u16).to_le_bytes());
+        mem[0x0005] = JMP;
+        mem[0x0006..0x0008].copy_from_slice(&(BDOS as u16).to_le_bytes());
+
+        // The trapped entry points return straight back to the caller
+        mem[BDOS] = RET;
+        for entry in 0..BIOS_ENTRIES {
+            mem[BIOS + entry * 3] = RET;
+        }
+
+        // Command tail and default FCBs
+        let tail = args
+            .iter()
+            .map(|arg| format!(" {}", arg.to_ascii_uppercase()))
+            .collect::<String>();
+        let tail = &tail.as_bytes()[..tail.len().min(RECORD_SIZE - 1)];
+        mem[DEFAULT_DMA] = tail.len() as u8;
+        mem[DEFAULT_DMA + 1..DEFAULT_DMA + 1 + tail.len()].copy_from_slice(tail);
+
+        for (fcb, arg) in [DEFAULT_FCB, DEFAULT_FCB + 16].iter().zip(args.iter()) {
+            let (drive, name) = parse_file_name(arg);
+            mem[*fcb] = drive;
+            mem[fcb + FCB_NAME..fcb + FCB_NAME + 11].copy_from_slice(&name);
+        }
+        for fcb in [DEFAULT_FCB, DEFAULT_FCB + 16].iter().skip(args.len()) {
+            for byte in mem[fcb + FCB_NAME..fcb + FCB_NAME + 11].iter_mut() {
+                *byte = b' ';
+            }
+        }
+
+        // Returning from the program warm boots
+        mem[BDOS - 2] = 0;
+        mem[BDOS - 1] = 0;
+        let mut cpu = kind.build(mem);
+        cpu.set_pc(TPA as u16);
+        cpu.set_sp((BDOS - 2) as u16);
+
+        Ok(Cpm {
+            cpu,
+            console,
+            dir: dir.to_path_buf(),
+            dma: DEFAULT_DMA,
+            search: Vec::new(),
+        })
+    }
+
+    pub fn console(&self) -> &C {
+        &self.console
+    }
+
+    /// Runs the program until it warm boots, calls BDOS function 0 or halts.
+    pub fn run(&mut self) -> Result<(), Box<dyn error::Error>> {
+        loop {
+            let pc = self.cpu.pc() as usize;
+            let running = if pc == 0x0000 {
+                false
+            } else if pc == BDOS {
+                self.bdos()?
+            } else if (BIOS..BIOS + BIOS_ENTRIES * 3).contains(&pc) && (pc - BIOS).is_multiple_of(3)
+            {
+                self.bios((pc - BIOS) / 3)
+            } else {
+                true
+            };
+
+            if !running {
+                return Ok(());
+            }
+            if self.cpu.step() != Status::Running {
+                return Ok(());
+            }
+        }
+    }
+
+    fn de(&self) -> usize {
+        self.cpu.de() as usize
+    }
+
+    fn ret_byte(&mut self, value: u8) {
+        self.ret_word(value as u16);
+    }
+
+    // Results go in HL, with copies in A and B
+    fn ret_word(&mut self, value: u16) {
+        self.cpu.set_hl(value);
+        self.cpu.set_a(value as u8);
+        let c = self.cpu.bc() as u8;
+        self.cpu.set_bc((value & 0xFF00) | c as u16);
+    }
+
+    fn console_in(&mut self) -> u8 {
+        match self.console.read() {
+            Some(b'\n') => b'\r',
+            Some(byte) => byte,
+            // Ctrl-Z marks the end of console input
+            None => 0x1A,
+        }
+    }
+
+    fn console_in_echo(&mut self) -> u8 {
+        let byte = self.console_in();
+        if !self.console.local_echo() {
+            self.console.write(byte);
+        }
+        byte
+    }
+
+    fn read_line(&mut self, buffer: usize) {
+        let max = self.cpu.memory()[buffer] as usize;
+        let mut len = 0;
+
+        while len < max {
+            match self.console_in() {
+                b'\r' | 0x1A => break,
+                0x08 | 0x7F => len = len.saturating_sub(1),
+                byte => {
+                    if !self.console.local_echo() {
+                        self.console.write(byte);
+                    }
+                    self.poke(buffer + 2 + len, byte);
+                    len += 1;
+                }
+            }
+        }
+        if !self.console.local_echo() {
+            self.console.write(b'\r');
+        }
+
+        self.poke(buffer + 1, len as u8);
+    }
+
+    /// Handles the BDOS call selected by register C. Returns false if the program exits.
+    fn bdos(&mut self) -> Result<bool, Box<dyn error::Error>> {
+        let de = self.de();
+
+        match self.cpu.bc() as u8 {
+            0 => return Ok(false), // System reset
+            1 => {
+                // Console input
+                let byte = self.console_in_echo();
+                self.ret_byte(byte);
+            }
+            2 => {
+                // Console output
+                self.console.write(self.cpu.de() as u8);
+            }
+            6 => {
+                // Direct console I/O
+                match self.cpu.de() as u8 {
+                    0xFF => {
+                        let byte = if self.console.ready() {
+                            self.console_in()
+                        } else {
+                            0
+                        };
+                        self.ret_byte(byte);
+                    }
+                    0xFE => {
+                        let status = if self.console.ready() { 0xFF } else { 0 };
+                        self.ret_byte(status);
+                    }
+                    byte => self.console.write(byte),
+                }
+            }
+            9 => {
+                // Print string, giving up after 64K without a '$'
+                for offset in de..de + 0x10000 {
+                    let byte = self.peek(offset);
+                    if byte == b'$' {
+                        break;
+                    }
+                    self.console.write(byte);
+                }
+            }
+            10 => self.read_line(de), // Read console buffer
+            11 => {
+                // Console status
+                let status = if self.console.ready() { 0xFF } else { 0 };
+                self.ret_byte(status);
+            }
+            12 => self.ret_word(0x0022), // Return version number
+            13 | 14 => {
+                // Reset disk system, select disk
+                self.dma = DEFAULT_DMA;
+                self.ret_byte(0);
+            }
+            15 => {
+                // Open file
+                let result = self.open(de);
+                self.ret_byte(result);
+            }
+            16 => {
+                // Close file
+                let result = if self.host_path(de).is_some() {
+                    0
+                } else {
+                    0xFF
+                };
+                self.ret_byte(result);
+            }
+            17 => {
+                // Search for first
+                self.search = self.matching(de)?;
+                self.search.reverse();
+                let result = self.search_next();
+                self.ret_byte(result);
+            }
+            18 => {
+                // Search for next
+                let result = self.search_next();
+                self.ret_byte(result);
+            }
+            19 => {
+                // Delete file
+                let matches = self.matching(de)?;
+                for name in matches.iter() {
+                    if let Some(path) = self.find(name)? {
+                        fs::remove_file(path)?;
+                    }
+                }
+                self.ret_byte(if matches.is_empty() { 0xFF } else { 0 });
+            }
+            20 => {
+                // Read sequential
+                let record = Self::current_record(&self.fcb(de));
+                let result = self.read_record(de, record)?;
+                if result == 0 {
+                    self.advance_record(de);
+                }
+                self.ret_byte(result);
+            }
+            21 => {
+                // Write sequential
+                let record = Self::current_record(&self.fcb(de));
+                let result = self.write_record(de, record)?;
+                if result == 0 {
+                    self.advance_record(de);
+                }
+                self.ret_byte(result);
+            }
+            22 => {
+                // Make file, failing if it already exists
+                let name = self.fcb_name(de);
+                if self.find(&name)?.is_some() {
+                    self.ret_byte(0xFF);
+                    return Ok(true);
+                }
+                fs::File::create(self.dir.join(host_name(&name).to_ascii_lowercase()))?;
+                for offset in [FCB_EX, FCB_S2, FCB_RC, FCB_CR].iter() {
+                    self.poke(de + offset, 0);
+                }
+                self.ret_byte(0);
+            }
+            25 => self.ret_byte(0), // Return current disk
+            26 => self.dma = de,    // Set DMA address
+            32 => self.ret_byte(0), // Get/set user code
+            33 => {
+                // Read random, leaving sequential access at the same record
+                let record = self.random_record(de);
+                let result = self.read_record(de, record)?;
+                self.set_record(de, record);
+                self.ret_byte(result);
+            }
+            34 => {
+                // Write random
+                let record = self.random_record(de);
+                let result = self.write_record(de, record)?;
+                self.set_record(de, record);
+                self.ret_byte(result);
+            }
+            35 => {
+                // Compute file size
+                let records = match self.host_path(de) {
+                    Some(path) => (fs::metadata(path)?.len() as usize).div_ceil(RECORD_SIZE),
+                    None => 0,
+                };
+                self.poke(de + FCB_R0, records as u8);
+                self.poke(de + FCB_R0 + 1, (records >> 8) as u8);
+                self.poke(de + FCB_R0 + 2, (records >> 16) as u8);
+            }
+            _ => self.ret_byte(0xFF),
+        }
+
+        Ok(true)
+    }
+
+    /// Handles a call into the BIOS jump table. Returns false if the program exits.
+    fn bios(&mut self, entry: usize) -> bool {
+        match entry {
+            0 | 1 => return false, // BOOT, WBOOT
+            2 => {
+                // CONST
+                self.cpu.set_a(if self.console.ready() { 0xFF } else { 0 });
+            }
+            3 => {
+                // CONIN
+                let byte = self.console_in();
+                self.cpu.set_a(byte);
+            }
+            4 => self.console.write(self.cpu.bc() as u8), // CONOUT
+            7 => self.cpu.set_a(0x1A),                    // READER
+            _ => self.cpu.set_a(0),
+        }
+
+        true
+    }
+
+    // Guest addresses wrap at the top of memory, like the CPU's own accesses
+    fn peek(&self, address: usize) -> u8 {
+        self.cpu.memory()[address & 0xFFFF]
+    }
+
+    fn poke(&mut self, address: usize, value: u8) {
+        self.cpu.memory_mut()[address & 0xFFFF] = value;
+    }
+
+    fn fcb(&self, fcb: usize) -> [u8; 36] {
+        let mut bytes = [0; 36];
+        for (offset, byte) in bytes.iter_mut().enumerate() {
+            *byte = self.peek(fcb + offset);
+        }
+        bytes
+    }
+
+    fn fcb_name(&self, fcb: usize) -> [u8; 11] {
+        let mut name = [0; 11];
+        for (dest, src) in name.iter_mut().zip(self.fcb(fcb)[FCB_NAME..].iter()) {
+            *dest = src & 0x7F;
+        }
+        name
+    }
+
+    fn current_record(fcb: &[u8]) -> usize {
+        let extent = ((fcb[FCB_S2] as usize & 0x3F) << 5) | (fcb[FCB_EX] as usize & 0x1F);
+        extent * 128 + (fcb[FCB_CR] as usize & 0x7F)
+    }
+
+    fn random_record(&self, fcb: usize) -> usize {
+        let fcb = self.fcb(fcb);
+        fcb[FCB_R0] as usize | (fcb[FCB_R0 + 1] as usize) << 8
+    }
+
+    fn advance_record(&mut self, fcb: usize) {
+        let next = Self::current_record(&self.fcb(fcb)) + 1;
+        self.set_record(fcb, next);
+    }
+
+    fn set_record(&mut self, fcb: usize, record: usize) {
+        self.poke(fcb + FCB_CR, (record % 128) as u8);
+        self.poke(fcb + FCB_EX, ((record / 128) & 0x1F) as u8);
+        self.poke(fcb + FCB_S2, ((record / 128) >> 5) as u8);
+    }
+
+    fn entries(&self) -> Result<Vec<DirEntry>, Box<dyn error::Error>> {
+        let mut entries = Vec::new();
+        for entry in fs::read_dir(&self.dir)? {
+            let entry = entry?;
+            if !entry.file_type()?.is_file() {
+                continue;
+            }
+            if let Some(name) = entry.file_name().to_str().and_then(cpm_name) {
+                entries.push((name, entry.path()));
+            }
+        }
+        entries.sort();
+        Ok(entries)
+    }
+
+    fn find(&self, name: &[u8; 11]) -> Result<Option<PathBuf>, Box<dyn error::Error>> {
+        Ok(self
+            .entries()?
+            .into_iter()
+            .find(|(entry, _)| entry == name)
+            .map(|(_, path)| path))
+    }
+
+    fn host_path(&self, fcb: usize) -> Option<PathBuf> {
+        self.find(&self.fcb_name(fcb)).ok().flatten()
+    }
+
+    fn matching(&self, fcb: usize) -> Result<Vec<[u8; 11]>, Box<dyn error::Error>> {
+        let pattern = self.fcb_name(fcb);
+        let any = self.peek(fcb) == b'?';
+
+        Ok(self
+            .entries()?
+            .into_iter()
+            .map(|(name, _)| name)
+            .filter(|name| {
+                any || name
+                    .iter()
+                    .zip(pattern.iter())
+                    .all(|(c, p)| *p == b'?' || c == p)
+            })
+            .collect())
+    }
+
+    fn search_next(&mut self) -> u8 {
+        match self.search.pop() {
+            Some(name) => {
+                let mut entry = [0; 32];
+                entry[1..12].copy_from_slice(&name);
+                self.write_dma(&entry);
+                0
+            }
+            None => 0xFF,
+        }
+    }
+
+    fn open(&mut self, fcb: usize) -> u8 {
+        let path = match self.host_path(fcb) {
+            Some(path) => path,
+            None => return 0xFF,
+        };
+        let size = fs::metadata(path)
+            .map(|meta| meta.len() as usize)
+            .unwrap_or(0);
+        let records = size.div_ceil(RECORD_SIZE);
+        let extent = self.peek(fcb + FCB_EX) as usize & 0x1F;
+
+        self.poke(fcb + FCB_S2, 0);
+        self.poke(
+            fcb + FCB_RC,
+            records.saturating_sub(extent * 128).min(128) as u8,
+        );
+        0
+    }
+
+    fn read_record(&mut self, fcb: usize, record: usize) -> Result<u8, Box<dyn error::Error>> {
+        let path = match self.host_path(fcb) {
+            Some(path) => path,
+            None => return Ok(0xFF),
+        };
+
+        let mut file = fs::File::open(path)?;
+        file.seek(SeekFrom::Start((record * RECORD_SIZE) as u64))?;
+
+        let mut buffer = [0x1A; RECORD_SIZE];
+        let mut read = 0;
+        while read < RECORD_SIZE {
+            match file.read(&mut buffer[read..])? {
+                0 => break,
+                n => read += n,
+            }
+        }
+        if read == 0 {
+            return Ok(1);
+        }
+
+        self.write_dma(&buffer);
+        Ok(0)
+    }
+
+    fn write_record(&mut self, fcb: usize, record: usize) -> Result<u8, Box<dyn error::Error>> {
+        let path = match self.host_path(fcb) {
+            Some(path) => path,
+            None => return Ok(0xFF),
+        };
+
+        let mut file = OpenOptions::new().write(true).open(path)?;
+        file.seek(SeekFrom::Start((record * RECORD_SIZE) as u64))?;
+        let record = (0..RECORD_SIZE)
+            .map(|offset| self.peek(self.dma + offset))
+            .collect::<Vec<_>>();
+        file.write_all(&record)?;
+        Ok(0)
+    }
+
+    fn write_dma(&mut self, data: &[u8]) {
+        for (offset, byte) in data.iter().enumerate() {
+            self.poke(self.dma + offset, *byte);
+        }
+    }
+}
+
+/// Converts a host file name into a blank padded CP/M 8.3 name, if it fits.
+fn cpm_name(file_name: &str) -> Option<[u8; 11]> {
+    let (base, ext) = match file_name.rfind('.') {
+        Some(dot) => (&file_name[..dot], &file_name[dot + 1..]),
+        None => (file_name, ""),
+    };
+    if base.is_empty() || base.len() > 8 || ext.len() > 3 || !file_name.is_ascii() {
+        return None;
+    }
+
+    let mut name = [b' '; 11];
+    name[..base.len()].copy_from_slice(base.to_ascii_uppercase().as_bytes());
+    name[8..8 + ext.len()].copy_from_slice(ext.to_ascii_uppercase().as_bytes());
+    Some(name)
+}
+
+fn host_name(name: &[u8; 11]) -> String {
+    let base = String::from_utf8_lossy(&name[..8]);
+    let ext = String::from_utf8_lossy(&name[8..]);
+    let (base, ext) = (base.trim_end(), ext.trim_end());
+
+    if ext.is_empty() {
+        base.to_string()
+    } else {
+        format!("{}.{}", base, ext)
+    }
+}
+
+/// Parses a command line argument such as `B:FOO*.TXT` into a drive number and
+/// FCB name, expanding `*` into `?` wildcards.
+fn parse_file_name(arg: &str) -> (u8, [u8; 11]) {
+    let arg = arg.to_ascii_uppercase();
+    let (drive, rest) = match arg.as_bytes() {
+        [letter @ b'A'..=b'P', b':', ..] => (letter - b'A' + 1, &arg[2..]),
+        _ => (0, &arg[..]),
+    };
+    let (base, ext) = match rest.find('.') {
+        Some(dot) => (&rest[..dot], &rest[dot + 1..]),
+        None => (rest, ""),
+    };
+
+    let mut name = [b' '; 11];
+    fill_field(&mut name[..8], base);
+    fill_field(&mut name[8..], ext);
+    (drive, name)
+}
+
+fn fill_field(field: &mut [u8], part: &str) {
+    for (i, byte) in part.bytes().take(field.len()).enumerate() {
+        if byte == b'*' {
+            for dest in field[i..].iter_mut() {
+                *dest = b'?';
+            }
+            return;
+        }
+        field[i] = byte;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::machines::BufferConsole;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("emurs-cpm-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn print_string() {
+        let program = [
+            0x11, 0x09, 0x01, // LXI D,0109
+            0x0E, 0x09, // MVI C,9
+            0xCD, 0x05, 0x00, // CALL 5
+            0xC9, // RET
+            b'h', b'i', b'$',
+        ];
+        let mut mem = vec![0; 0x10000];
+        let dir = temp_dir("print");
+
+        let mut cpm = Cpm::new(&mut mem, &program, &[], &dir, BufferConsole::new(b"")).unwrap();
+        cpm.run().unwrap();
+
+        assert_eq!(cpm.console().output, b"hi");
+    }
+
+    #[test]
+    fn print_string_without_a_dollar() {
+        let mut mem = vec![0; 0x10000];
+        let dir = temp_dir("dollar");
+        let mut cpm = Cpm::new(&mut mem, &[0xC9], &[], &dir, BufferConsole::new(b"")).unwrap();
+        cpm.cpu.memory_mut().fill(b'x');
+        cpm.cpu.set_de(0xFF00);
+        cpm.cpu.set_bc(9);
+
+        cpm.bdos().unwrap();
+
+        assert_eq!(cpm.console().output.len(), 0x10000);
+    }
+
+    #[test]
+    fn read_console_buffer() {
+        let program = [
+            0x11, 0x00, 0x02, // LXI D,0200
+            0x0E, 0x0A, // MVI C,10
+            0xCD, 0x05, 0x00, // CALL 5
+            0xC9, // RET
+        ];
+        let mut mem = vec![0; 0x10000];
+        mem[0x0200] = 4;
+        let dir = temp_dir("line");
+
+        let mut cpm = Cpm::new(&mut mem, &program, &[], &dir, BufferConsole::new(b"ab\n")).unwrap();
+        cpm.run().unwrap();
+        drop(cpm);
+
+        assert_eq!(&mem[0x0201..0x0204], &[2, b'a', b'b']);
+    }
+
+    // Reads a line over code that has already run, so a cached core must drop it
+    #[test]
+    fn reading_over_code() {
+        #[rustfmt::skip]
+        let program = [
+            0xCD, 0x12, 0x01, // CALL 0112
+            0x11, 0x10, 0x01, // LXI D,0110
+            0x0E, 0x0A, // MVI C,10
+            0xCD, 0x05, 0x00, // CALL 5
+            0xC3, 0x12, 0x01, // JMP 0112
+            0x00, 0x00,
+            0x01, 0x00, // Buffer for one byte
+            0x04, 0xC9, // INR B RET, then INR A RET
+        ];
+        for kind in [CpuKind::I8080, CpuKind::Cached, CpuKind::Lockstep] {
+            let mut mem = vec![0; 0x10000];
+            let dir = temp_dir("code");
+
+            let console = BufferConsole::new(b"<\n");
+            let mut cpm = Cpm::with_cpu(kind, &mut mem, &program, &[], &dir, console).unwrap();
+            cpm.run().unwrap();
+
+            assert_eq!(cpm.cpu.a(), 1, "{:?}", kind);
+            assert_eq!(cpm.cpu.bc() >> 8, 1, "{:?}", kind);
+        }
+    }
+
+    #[test]
+    fn make_write_and_read_back() {
+        let write = [
+            0x11, 0x5C, 0x00, 0x0E, 0x16, 0xCD, 0x05, 0x00, // make
+            0x11, 0x5C, 0x00, 0x0E, 0x15, 0xCD, 0x05, 0x00, // write sequential
+            0x11, 0x5C, 0x00, 0x0E, 0x10, 0xCD, 0x05, 0x00, // close
+            0xC9,
+        ];
+        let dir = temp_dir("file");
+        let args = vec!["test.txt".to_string()];
+
+        let mut mem = vec![0; 0x10000];
+        let mut cpm = Cpm::new(&mut mem, &write, &args, &dir, BufferConsole::new(b"")).unwrap();
+        cpm.run().unwrap();
+        drop(cpm);
+
+        let written = fs::read(dir.join("test.txt")).unwrap();
+        assert_eq!(written.len(), RECORD_SIZE);
+        assert_eq!(&written[..10], b"\x09 TEST.TXT");
+
+        let read = [
+            0x11, 0x5C, 0x00, 0x0E, 0x0F, 0xCD, 0x05, 0x00, // open
+            0x11, 0x5C, 0x00, 0x0E, 0x14, 0xCD, 0x05, 0x00, // read sequential
+            0x32, 0x00, 0x03, // STA 0300
+            0x11, 0x5C, 0x00, 0x0E, 0x14, 0xCD, 0x05, 0x00, // read sequential
+            0x32, 0x01, 0x03, // STA 0301
+            0xC9,
+        ];
+        let mut mem = vec![0; 0x10000];
+        let mut cpm = Cpm::new(&mut mem, &read, &args, &dir, BufferConsole::new(b"")).unwrap();
+        cpm.run().unwrap();
+        drop(cpm);
+
+        assert_eq!(&mem[0x0080..0x008A], b"\x09 TEST.TXT");
+        assert_eq!(mem[0x0300], 0);
+        assert_eq!(mem[0x0301], 1);
+    }
+
+    // An FCB and DMA buffer running off the top of memory wrap around to 0
+    #[test]
+    fn buffers_at_the_top_of_memory() {
+        #[rustfmt::skip]
+        let program = [
+            0x21, 0x5C, 0x00, 0x11, 0xF0, 0xFF, 0x01, 0x10, 0x00, // LXI H,005C LXI D,FFF0 LXI B,16
+            0x7E, 0x12, 0x23, 0x13, 0x0B, 0x78, 0xB1, 0xC2, 0x09, 0x01, // copy the name
+            0x11, 0xC0, 0xFF, 0x0E, 0x1A, 0xCD, 0x05, 0x00, // set DMA
+            0x11, 0xF0, 0xFF, 0x0E, 0x16, 0xCD, 0x05, 0x00, // make
+            0x11, 0xF0, 0xFF, 0x0E, 0x15, 0xCD, 0x05, 0x00, // write sequential
+            0x11, 0xF0, 0xFF, 0x0E, 0x14, 0xCD, 0x05, 0x00, // read sequential
+            0x11, 0xF0, 0xFF, 0x0E, 0x11, 0xCD, 0x05, 0x00, // search for first
+            0xC9,
+        ];
+        let dir = temp_dir("top");
+        let args = vec!["top.txt".to_string()];
+
+        let mut mem = vec![0; 0x10000];
+        let mut cpm = Cpm::new(&mut mem, &program, &args, &dir, BufferConsole::new(b"")).unwrap();
+        cpm.run().unwrap();
+        drop(cpm);
+
+        assert_eq!(fs::read(dir.join("top.txt")).unwrap().len(), RECORD_SIZE);
+        // The directory entry written at FFC0
+        assert_eq!(&mem[0xFFC1..0xFFCC], b"TOP     TXT");
+        // The FCB's current record, 32 bytes in
+        assert_eq!(mem[0x0010], 1);
+    }
+
+    #[test]
+    fn make_existing_file_fails() {
+        let program = [
+            0x11, 0x5C, 0x00, 0x0E, 0x16, 0xCD, 0x05, 0x00, // make
+            0x32, 0x00, 0x03, // STA 0300
+            0xC9,
+        ];
+        let dir = temp_dir("exists");
+        fs::write(dir.join("old.txt"), b"kept").unwrap();
+        let args = vec!["old.txt".to_string()];
+
+        let mut mem = vec![0; 0x10000];
+        let mut cpm = Cpm::new(&mut mem, &program, &args, &dir, BufferConsole::new(b"")).unwrap();
+        cpm.run().unwrap();
+        drop(cpm);
+
+        assert_eq!(mem[0x0300], 0xFF);
+        assert_eq!(fs::read(dir.join("old.txt")).unwrap(), b"kept");
+    }
+
+    // Read random moves the sequential position to the record it read
+    #[test]
+    fn read_random_sets_the_current_record() {
+        let program = [
+            0x11, 0x5C, 0x00, 0x0E, 0x0F, 0xCD, 0x05, 0x00, // open
+            0x21, 0x82, 0x00, 0x22, 0x7D, 0x00, // LXI H,0082 SHLD 007D: record 130
+            0x11, 0x5C, 0x00, 0x0E, 0x21, 0xCD, 0x05, 0x00, // read random
+            0x11, 0x5C, 0x00, 0x0E, 0x14, 0xCD, 0x05, 0x00, // read sequential
+            0xC9,
+        ];
+        let dir = temp_dir("random");
+        let contents = (0..132 * RECORD_SIZE)
+            .map(|offset| (offset / RECORD_SIZE) as u8)
+            .collect::<Vec<_>>();
+        fs::write(dir.join("big.dat"), contents).unwrap();
+        let args = vec!["big.dat".to_string()];
+
+        let mut mem = vec![0; 0x10000];
+        let mut cpm = Cpm::new(&mut mem, &program, &args, &dir, BufferConsole::new(b"")).unwrap();
+        cpm.run().unwrap();
+        drop(cpm);
+
+        // The sequential read got record 130 again, then moved on
+        assert_eq!(mem[0x0080], 130);
+        assert_eq!(mem[0x005C + FCB_EX], 1);
+        assert_eq!(mem[0x005C + FCB_CR], 3);
+    }
+
+    #[test]
+    fn z80_program() {
+        let program = [
+            0x21, 0x10, 0x01, // LD HL,0110
+            0x11, 0x00, 0x02, // LD DE,0200
+            0x01, 0x03, 0x00, // LD BC,3
+            0xED, 0xB0, // LDIR
+            0x11, 0x00, 0x02, // LD DE,0200
+            0x18, 0x03, // JR 0113
+            b'z', b'8', b'$', // 0110
+            0x0E, 0x09, // MVI C,9
+            0xC3, 0x05, 0x00, // JP 5
+        ];
+        let mut mem = vec![0; 0x10000];
+        let dir = temp_dir("z80");
+
+        let mut cpm = Cpm::with_cpu(
+            CpuKind::Z80,
+            &mut mem,
+            &program,
+            &[],
+            &dir,
+            BufferConsole::new(b""),
+        )
+        .unwrap();
+        cpm.run().unwrap();
+
+        assert_eq!(cpm.console().output, b"z8");
+    }
+
+    // The ZEXDOC and ZEXALL instruction exercisers, which aren't distributed with
+    // the source. Put zexdoc.com and zexall.com in games/zex and run with
+    // `cargo test --release -- --ignored --nocapture` to see each group's result.
+    #[test]
+    #[ignore]
+    fn z80_instruction_exercisers() {
+        let zex = Path::new(env!("CARGO_MANIFEST_DIR")).join("games/zex");
+        for name in ["zexdoc.com", "zexall.com"] {
+            let program = fs::read(zex.join(name)).expect("Exerciser not found");
+            let mut mem = vec![0; 0x10000];
+            let console = BufferConsole::new(b"");
+
+            let mut cpm =
+                Cpm::with_cpu(CpuKind::Z80, &mut mem, &program, &[], &zex, console).unwrap();
+            cpm.run().unwrap();
+
+            let output = String::from_utf8_lossy(&cpm.console().output).to_string();
+            println!("{}", output);
+            assert!(output.contains("Tests complete"), "{}", output);
+            assert!(!output.contains("ERROR"), "{}", output);
+        }
+    }
+
+    // The 8080 exercisers, which aren't distributed with the source either: TST8080,
+    // 8080PRE, CPUTEST and 8080EXM, each with the message it finishes on. Put them
+    // in games/8080 and run as for the Z80 ones.
+    #[test]
+    #[ignore]
+    fn i8080_instruction_exercisers() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("games/8080");
+        for (name, passed) in [
+            ("TST8080.COM", "CPU IS OPERATIONAL"),
+            ("8080PRE.COM", "Preliminary tests complete"),
+            ("CPUTEST.COM", "CPU TESTS OK"),
+            ("8080EXM.COM", "Tests complete"),
+        ] {
+            let program = fs::read(dir.join(name)).expect("Exerciser not found");
+            let mut mem = vec![0; 0x10000];
+            let console = BufferConsole::new(b"");
+
+            let mut cpm =
+                Cpm::with_cpu(CpuKind::I8080, &mut mem, &program, &[], &dir, console).unwrap();
+            cpm.run().unwrap();
+
+            let output = String::from_utf8_lossy(&cpm.console().output).to_string();
+            println!("{}", output);
+            assert!(output.contains(passed), "{}: {}", name, output);
+            assert!(!output.contains("ERROR"), "{}: {}", name, output);
+            assert!(!output.contains("FAILED"), "{}: {}", name, output);
+        }
+    }
+
+    #[test]
+    fn file_names() {
+        assert_eq!(cpm_name("zexdoc.com"), Some(*b"ZEXDOC  COM"));
+        assert_eq!(cpm_name("toolongname.com"), None);
+        assert_eq!(parse_file_name("b:foo*.t?t"), (2, *b"FOO?????T?T"));
+        assert_eq!(host_name(b"README     "), "README");
+    }
+}
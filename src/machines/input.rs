@@ -0,0 +1,240 @@
+use std::error;
+use std::fs;
+use std::path::Path;
+
+/// A machine's controls and settings, by name, so frontends and config files can
+/// drive any machine the same way.
+pub trait Inputs {
+    /// Presses or releases the named control. Returns false if there isn't one.
+    fn set_control(&mut self, name: &str, pressed: bool) -> bool;
+    /// Changes the named DIP switch or setting to `value`.
+    fn set_setting(&mut self, name: &str, value: &str) -> Result<(), Box<dyn error::Error>>;
+}
+
+/// Host keys bound to machine controls, and the settings to start a machine with.
+///
+/// Read from a file of `key = control` lines under `[keys]` and `name = value`
+/// lines under `[settings]`, with `#` comments:
+///
+/// ```text
+/// [keys]
+/// c = coin
+/// left = left1
+/// [settings]
+/// lives = 5
+/// ```
+///
+/// Keys are named in lower case: letters and digits as themselves, and `space`,
+/// `left`, `enter` and so on for the rest.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputMap {
+    pub keys: Vec<(String, String)>,
+    pub settings: Vec<(String, String)>,
+}
+
+impl Default for InputMap {
+    fn default() -> Self {
+        let keys = [
+            ("c", "coin"),
+            ("5", "coin"),
+            ("1", "start1"),
+            ("2", "start2"),
+            ("left", "left1"),
+            ("right", "right1"),
+            ("up", "up1"),
+            ("down", "down1"),
+            ("space", "fire1"),
+            ("a", "left2"),
+            ("d", "right2"),
+            ("w", "up2"),
+            ("s", "down2"),
+            ("f", "fire2"),
+            ("t", "tilt"),
+        ];
+
+        InputMap {
+            keys: keys
+                .iter()
+                .map(|(key, control)| (key.to_string(), control.to_string()))
+                .collect(),
+            settings: Vec::new(),
+        }
+    }
+}
+
+impl InputMap {
+    pub fn load(path: &Path) -> Result<InputMap, Box<dyn error::Error>> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn parse(text: &str) -> Result<InputMap, Box<dyn error::Error>> {
+        let mut map = InputMap {
+            keys: Vec::new(),
+            settings: Vec::new(),
+        };
+        let mut section = None;
+
+        for (number, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if line.starts_with('[') && line.ends_with(']') {
+                section = match &line[1..line.len() - 1] {
+                    "keys" => Some(&mut map.keys),
+                    "settings" => Some(&mut map.settings),
+                    other => return Err(format!("Unknown section [{}]", other).into()),
+                };
+                continue;
+            }
+
+            let (name, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("Expected name = value on line {}", number + 1))?;
+            match &mut section {
+                Some(entries) => entries.push((name.trim().to_string(), value.trim().to_string())),
+                None => return Err(format!("Line {} isn't in a section", number + 1).into()),
+            }
+        }
+
+        Ok(map)
+    }
+
+    /// The control bound to `key`, if any.
+    pub fn control(&self, key: &str) -> Option<&str> {
+        self.keys
+            .iter()
+            .find(|(bound, _)| bound == key)
+            .map(|(_, control)| control.as_str())
+    }
+
+    /// Presses every bound control with a key in `down` and releases the rest.
+    pub fn update(&self, machine: &mut dyn Inputs, down: &[String]) {
+        for (_, control) in self.keys.iter() {
+            let pressed = self
+                .keys
+                .iter()
+                .any(|(key, bound)| bound == control && down.contains(key));
+            machine.set_control(control, pressed);
+        }
+    }
+
+    pub fn apply_settings(&self, machine: &mut dyn Inputs) -> Result<(), Box<dyn error::Error>> {
+        for (name, value) in self.settings.iter() {
+            machine.set_setting(name, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Control presses and releases scripted by frame, for replaying input without a
+/// player. Each line is a frame number, a control and `press` or `release`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Movie {
+    // In frame order
+    events: Vec<(u64, String, bool)>,
+}
+
+impl Movie {
+    pub fn load(path: &Path) -> Result<Movie, Box<dyn error::Error>> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn parse(text: &str) -> Result<Movie, Box<dyn error::Error>> {
+        let mut events = Vec::new();
+
+        for (number, line) in text.lines().enumerate() {
+            let fields: Vec<&str> = line.split('#').next().unwrap().split_whitespace().collect();
+            let (frame, control, action) = match fields[..] {
+                [] => continue,
+                [frame, control, action] => (frame, control, action),
+                _ => return Err(format!("Expected 3 fields on line {}", number + 1).into()),
+            };
+
+            let pressed = match action {
+                "press" => true,
+                "release" => false,
+                _ => return Err(format!("Unknown action on line {}", number + 1).into()),
+            };
+            events.push((frame.parse()?, control.to_string(), pressed));
+        }
+
+        events.sort_by_key(|(frame, _, _)| *frame);
+        Ok(Movie { events })
+    }
+
+    /// Applies the events for `frame`, to be called before running it.
+    pub fn apply(&self, frame: u64, machine: &mut dyn Inputs) {
+        for (_, control, pressed) in self.events.iter().filter(|(at, _, _)| *at == frame) {
+            machine.set_control(control, *pressed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Recorder {
+        controls: Vec<(String, bool)>,
+        settings: Vec<(String, String)>,
+    }
+
+    impl Inputs for Recorder {
+        fn set_control(&mut self, name: &str, pressed: bool) -> bool {
+            self.controls.push((name.to_string(), pressed));
+            true
+        }
+
+        fn set_setting(&mut self, name: &str, value: &str) -> Result<(), Box<dyn error::Error>> {
+            self.settings.push((name.to_string(), value.to_string()));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn parse_map() {
+        let map = InputMap::parse(
+            "# Two keys for coin\n[keys]\nc = coin\n5=coin # arcade style\n\n[settings]\nlives = 5\n",
+        )
+        .unwrap();
+
+        assert_eq!(map.control("5"), Some("coin"));
+        assert_eq!(map.control("x"), None);
+        assert_eq!(map.settings, vec![("lives".to_string(), "5".to_string())]);
+
+        assert!(InputMap::parse("c = coin").is_err());
+        assert!(InputMap::parse("[keys]\nc coin").is_err());
+        assert!(InputMap::parse("[buttons]").is_err());
+    }
+
+    #[test]
+    fn update_from_held_keys() {
+        let map = InputMap::parse("[keys]\nc = coin\n5 = coin\nspace = fire1").unwrap();
+        let mut machine = Recorder::default();
+
+        map.update(&mut machine, &["5".to_string()]);
+
+        assert!(machine.controls.contains(&("coin".to_string(), true)));
+        assert!(!machine.controls.contains(&("coin".to_string(), false)));
+        assert!(machine.controls.contains(&("fire1".to_string(), false)));
+    }
+
+    #[test]
+    fn movie_by_frame() {
+        let movie =
+            Movie::parse("10 fire1 release\n# insert a coin\n2 coin press\n10 coin release")
+                .unwrap();
+        let mut machine = Recorder::default();
+
+        movie.apply(2, &mut machine);
+        assert_eq!(machine.controls, vec![("coin".to_string(), true)]);
+
+        movie.apply(10, &mut machine);
+        assert_eq!(machine.controls.len(), 3);
+
+        assert!(Movie::parse("1 coin tap").is_err());
+    }
+}
@@ -0,0 +1,348 @@
+use super::{
+    Button, Dip, Driver, Orientation, PortMap, ReadPort, RomFile, SoundBoard, Video, WritePort,
+};
+
+use ReadPort::{Input, ShiftResult, ShiftResultReversed};
+use WritePort::{Lamps, ShiftAmount, ShiftData, Sound, Watchdog};
+
+// Port map used by Space Invaders and the Taito boards derived from it
+const INVADERS_PORTS: PortMap = PortMap {
+    reads: [
+        Input(0),
+        Input(1),
+        Input(2),
+        ShiftResult,
+        ReadPort::Unmapped,
+        ReadPort::Unmapped,
+        ReadPort::Unmapped,
+        ReadPort::Unmapped,
+    ],
+    writes: [
+        WritePort::Unmapped,
+        WritePort::Unmapped,
+        ShiftAmount,
+        Sound(0),
+        ShiftData,
+        Sound(1),
+        Watchdog,
+        WritePort::Unmapped,
+    ],
+};
+
+const INVADERS_BUTTONS: &[(Button, usize, u8)] = &[
+    (Button::Coin, 1, 0x01),
+    (Button::Start2, 1, 0x02),
+    (Button::Start1, 1, 0x04),
+    (Button::Fire1, 1, 0x10),
+    (Button::Left1, 1, 0x20),
+    (Button::Right1, 1, 0x40),
+    (Button::Tilt, 2, 0x04),
+    (Button::Fire2, 2, 0x10),
+    (Button::Left2, 2, 0x20),
+    (Button::Right2, 2, 0x40),
+];
+
+const INVADERS_DIPS: &[Dip] = &[
+    Dip {
+        name: "lives",
+        port: 2,
+        mask: 0x03,
+        values: &[("3", 0x00), ("4", 0x01), ("5", 0x02), ("6", 0x03)],
+    },
+    Dip {
+        name: "bonus_life",
+        port: 2,
+        mask: 0x08,
+        values: &[("1500", 0x00), ("1000", 0x08)],
+    },
+    Dip {
+        name: "coin_info",
+        port: 2,
+        mask: 0x80,
+        values: &[("on", 0x00), ("off", 0x80)],
+    },
+];
+
+const INVADPT2_DIPS: &[Dip] = &[
+    Dip {
+        name: "lives",
+        port: 2,
+        mask: 0x01,
+        values: &[("3", 0x00), ("4", 0x01)],
+    },
+    Dip {
+        name: "preset_high_score",
+        port: 2,
+        mask: 0x08,
+        values: &[("off", 0x00), ("on", 0x08)],
+    },
+    Dip {
+        name: "coin_info",
+        port: 2,
+        mask: 0x80,
+        values: &[("on", 0x00), ("off", 0x80)],
+    },
+];
+
+const LRESCUE_DIPS: &[Dip] = &[Dip {
+    name: "lives",
+    port: 2,
+    mask: 0x03,
+    values: &[("3", 0x00), ("4", 0x01), ("5", 0x02), ("6", 0x03)],
+}];
+
+const BALLBOMB_DIPS: &[Dip] = &[
+    Dip {
+        name: "lives",
+        port: 2,
+        mask: 0x03,
+        values: &[("3", 0x00), ("4", 0x01), ("5", 0x02), ("6", 0x03)],
+    },
+    Dip {
+        name: "bonus_life",
+        port: 2,
+        mask: 0x08,
+        values: &[("1000", 0x00), ("1500", 0x08)],
+    },
+];
+
+// Coins in the low two bits and plays for them in the next two
+const GUNFIGHT_DIPS: &[Dip] = &[
+    Dip {
+        name: "coinage",
+        port: 2,
+        mask: 0x0F,
+        values: &[
+            ("1/1", 0x00),
+            ("2/1", 0x01),
+            ("3/1", 0x02),
+            ("4/1", 0x03),
+            ("1/2", 0x04),
+            ("2/2", 0x05),
+            ("3/2", 0x06),
+            ("4/2", 0x07),
+            ("1/3", 0x08),
+            ("2/3", 0x09),
+            ("3/3", 0x0A),
+            ("4/3", 0x0B),
+            ("1/4", 0x0C),
+            ("2/4", 0x0D),
+            ("3/4", 0x0E),
+            ("4/4", 0x0F),
+        ],
+    },
+    Dip {
+        name: "game_time",
+        port: 2,
+        mask: 0x30,
+        values: &[("60", 0x00), ("70", 0x10), ("80", 0x20), ("90", 0x30)],
+    },
+];
+
+const SEAWOLF_DIPS: &[Dip] = &[
+    Dip {
+        name: "game_time",
+        port: 1,
+        mask: 0x03,
+        values: &[("90", 0x00), ("80", 0x01), ("70", 0x02), ("60", 0x03)],
+    },
+    Dip {
+        name: "extended_time",
+        port: 1,
+        mask: 0x30,
+        values: &[
+            ("2000", 0x00),
+            ("3000", 0x10),
+            ("4000", 0x20),
+            ("none", 0x30),
+        ],
+    },
+];
+
+pub const INVADERS: Driver = Driver {
+    name: "invaders",
+    description: "Space Invaders",
+    roms: &[
+        RomFile::new("invaders.h", 0x0000, 0x0800),
+        RomFile::new("invaders.g", 0x0800, 0x0800),
+        RomFile::new("invaders.f", 0x1000, 0x0800),
+        RomFile::new("invaders.e", 0x1800, 0x0800),
+    ],
+    ports: INVADERS_PORTS,
+    // Port 1 bit 3 is pulled high. Port 2 DIPs: 3 ships, bonus at 1500, coin info shown
+    inputs: [0x0E, 0x08, 0x00, 0x00],
+    buttons: INVADERS_BUTTONS,
+    dips: INVADERS_DIPS,
+    video: Video::Overlay,
+    orientation: Orientation::Rotated,
+    sound: SoundBoard::Invaders,
+};
+
+pub const INVADPT2: Driver = Driver {
+    name: "invadpt2",
+    description: "Space Invaders Part II",
+    roms: &[
+        RomFile::new("pv01", 0x0000, 0x0800),
+        RomFile::new("pv02", 0x0800, 0x0800),
+        RomFile::new("pv03", 0x1000, 0x0800),
+        RomFile::new("pv04", 0x1800, 0x0800),
+        RomFile::new("pv05", 0x4000, 0x0800),
+    ],
+    ports: INVADERS_PORTS,
+    inputs: [0x0E, 0x08, 0x00, 0x00],
+    buttons: INVADERS_BUTTONS,
+    dips: INVADPT2_DIPS,
+    video: Video::ColorProm {
+        prom: RomFile::new("pv06.1", 0, 0x0400),
+    },
+    orientation: Orientation::Rotated,
+    sound: SoundBoard::Invaders,
+};
+
+pub const LRESCUE: Driver = Driver {
+    name: "lrescue",
+    description: "Lunar Rescue",
+    roms: &[
+        RomFile::new("lrescue.1", 0x0000, 0x0800),
+        RomFile::new("lrescue.2", 0x0800, 0x0800),
+        RomFile::new("lrescue.3", 0x1000, 0x0800),
+        RomFile::new("lrescue.4", 0x1800, 0x0800),
+        RomFile::new("lrescue.5", 0x4000, 0x0800),
+        RomFile::new("lrescue.6", 0x4800, 0x0800),
+    ],
+    ports: INVADERS_PORTS,
+    inputs: [0x0E, 0x08, 0x00, 0x00],
+    buttons: INVADERS_BUTTONS,
+    dips: LRESCUE_DIPS,
+    video: Video::ColorProm {
+        prom: RomFile::new("7643-1.cpu", 0, 0x0400),
+    },
+    orientation: Orientation::Rotated,
+    sound: SoundBoard::None,
+};
+
+pub const BALLBOMB: Driver = Driver {
+    name: "ballbomb",
+    description: "Balloon Bomber",
+    roms: &[
+        RomFile::new("tn01", 0x0000, 0x0800),
+        RomFile::new("tn02", 0x0800, 0x0800),
+        RomFile::new("tn03", 0x1000, 0x0800),
+        RomFile::new("tn04", 0x1800, 0x0800),
+        RomFile::new("tn05-1", 0x4000, 0x0800),
+    ],
+    ports: INVADERS_PORTS,
+    // Port 2 DIPs: 3 lives, bonus life at 1000
+    inputs: [0x0E, 0x08, 0x00, 0x00],
+    buttons: INVADERS_BUTTONS,
+    dips: BALLBOMB_DIPS,
+    video: Video::ColorProm {
+        prom: RomFile::new("tn06", 0, 0x0400),
+    },
+    orientation: Orientation::Rotated,
+    sound: SoundBoard::None,
+};
+
+pub const GUNFIGHT: Driver = Driver {
+    name: "gunfight",
+    description: "Gun Fight",
+    roms: &[
+        RomFile::new("7609h.bin", 0x0000, 0x0400),
+        RomFile::new("7609g.bin", 0x0400, 0x0400),
+        RomFile::new("7609f.bin", 0x0800, 0x0400),
+        RomFile::new("7609e.bin", 0x0C00, 0x0400),
+    ],
+    ports: PortMap {
+        reads: [
+            Input(0),
+            Input(1),
+            Input(2),
+            ShiftResult,
+            ReadPort::Unmapped,
+            ReadPort::Unmapped,
+            ReadPort::Unmapped,
+            ReadPort::Unmapped,
+        ],
+        writes: [
+            WritePort::Unmapped,
+            Sound(0),
+            ShiftAmount,
+            Watchdog,
+            ShiftData,
+            WritePort::Unmapped,
+            WritePort::Unmapped,
+            WritePort::Unmapped,
+        ],
+    },
+    // Controls are active low. Port 2 DIPs: 1 coin 1 play, 60 second game
+    inputs: [0xFF, 0xFF, 0x00, 0x00],
+    buttons: &[
+        (Button::Up1, 0, 0x01),
+        (Button::Down1, 0, 0x02),
+        (Button::Left1, 0, 0x04),
+        (Button::Right1, 0, 0x08),
+        (Button::Fire1, 0, 0x80),
+        (Button::Up2, 1, 0x01),
+        (Button::Down2, 1, 0x02),
+        (Button::Left2, 1, 0x04),
+        (Button::Right2, 1, 0x08),
+        (Button::Fire2, 1, 0x80),
+        (Button::Coin, 2, 0x40),
+        (Button::Start1, 2, 0x80),
+    ],
+    dips: GUNFIGHT_DIPS,
+    video: Video::Monochrome,
+    orientation: Orientation::Landscape,
+    sound: SoundBoard::None,
+};
+
+pub const SEAWOLF: Driver = Driver {
+    name: "seawolf",
+    description: "Sea Wolf",
+    roms: &[
+        RomFile::new("sw0041.h", 0x0000, 0x0400),
+        RomFile::new("sw0042.g", 0x0400, 0x0400),
+        RomFile::new("sw0043.f", 0x0800, 0x0400),
+        RomFile::new("sw0044.e", 0x0C00, 0x0400),
+    ],
+    ports: PortMap {
+        reads: [
+            ShiftResultReversed,
+            Input(0),
+            Input(1),
+            ShiftResult,
+            ReadPort::Unmapped,
+            ReadPort::Unmapped,
+            ReadPort::Unmapped,
+            ReadPort::Unmapped,
+        ],
+        writes: [
+            WritePort::Unmapped,
+            Lamps,
+            Lamps,
+            ShiftData,
+            ShiftAmount,
+            Sound(0),
+            WritePort::Unmapped,
+            WritePort::Unmapped,
+        ],
+    },
+    // The periscope is an analog dial in the low bits of port 0; only fire, coin
+    // and start are mapped. Port 1 DIPs: 90 second game, extended time at 2000
+    inputs: [0x00, 0x00, 0x00, 0x00],
+    buttons: &[
+        (Button::Fire1, 0, 0x20),
+        (Button::Coin, 1, 0x40),
+        (Button::Start1, 1, 0x80),
+    ],
+    dips: SEAWOLF_DIPS,
+    video: Video::Monochrome,
+    orientation: Orientation::Landscape,
+    sound: SoundBoard::None,
+};
+
+pub const DRIVERS: &[Driver] = &[INVADERS, INVADPT2, LRESCUE, BALLBOMB, GUNFIGHT, SEAWOLF];
+
+pub fn find(name: &str) -> Option<&'static Driver> {
+    DRIVERS.iter().find(|driver| driver.name == name)
+}
@@ -0,0 +1,678 @@
+use std::error;
+use std::fs;
+use std::ops::Range;
+use std::path::Path;
+
+use super::input::Inputs;
+use crate::cpu::{Cpu, CpuKind};
+use crate::emulator::Io;
+use crate::scheduler::{Clocked, Scheduler};
+
+pub mod drivers;
+pub mod sound;
+pub mod video;
+
+pub const CPU_HZ: u64 = 2_000_000;
+pub const FRAMES_PER_SECOND: u64 = 60;
+pub const CYCLES_PER_FRAME: u64 = CPU_HZ / FRAMES_PER_SECOND;
+pub const LINES_PER_FRAME: u64 = 262;
+// Lines of VRAM the beam draws, each 256 pixels packed into 32 bytes
+pub const VISIBLE_LINES: usize = 224;
+pub const BYTES_PER_LINE: usize = 32;
+
+pub const VRAM: Range<usize> = 0x2400..0x4000;
+
+// RST vectors raised by the video hardware, and the lines they're raised on
+const MID_SCREEN: u8 = 1;
+const VBLANK: u8 = 2;
+const MID_SCREEN_LINE: u64 = 96;
+const VBLANK_LINE: u64 = 224;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Button {
+    Coin,
+    Start1,
+    Start2,
+    Up1,
+    Down1,
+    Left1,
+    Right1,
+    Fire1,
+    Up2,
+    Down2,
+    Left2,
+    Right2,
+    Fire2,
+    Tilt,
+}
+
+// Names the buttons go by in input maps
+const BUTTON_NAMES: [(&str, Button); 14] = [
+    ("coin", Button::Coin),
+    ("start1", Button::Start1),
+    ("start2", Button::Start2),
+    ("up1", Button::Up1),
+    ("down1", Button::Down1),
+    ("left1", Button::Left1),
+    ("right1", Button::Right1),
+    ("fire1", Button::Fire1),
+    ("up2", Button::Up2),
+    ("down2", Button::Down2),
+    ("left2", Button::Left2),
+    ("right2", Button::Right2),
+    ("fire2", Button::Fire2),
+    ("tilt", Button::Tilt),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReadPort {
+    Unmapped,
+    /// One of the board's input latches
+    Input(usize),
+    ShiftResult,
+    /// The shift result with its bits reversed, used by Sea Wolf
+    ShiftResultReversed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WritePort {
+    Unmapped,
+    ShiftAmount,
+    ShiftData,
+    /// A bank of sound trigger bits
+    Sound(usize),
+    Watchdog,
+    Lamps,
+}
+
+pub struct PortMap {
+    pub reads: [ReadPort; 8],
+    pub writes: [WritePort; 8],
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RomFile {
+    pub name: &'static str,
+    pub offset: usize,
+    pub size: usize,
+}
+
+impl RomFile {
+    pub const fn new(name: &'static str, offset: usize, size: usize) -> RomFile {
+        RomFile { name, offset, size }
+    }
+}
+
+/// A bank of DIP switches: the bits of an input latch it sets, and what they read
+/// for each of its settings.
+pub struct Dip {
+    pub name: &'static str,
+    pub port: usize,
+    pub mask: u8,
+    pub values: &'static [(&'static str, u8)],
+}
+
+pub enum Video {
+    /// Plain white on black
+    Monochrome,
+    /// White on black under the coloured cellophane strips of the Invaders cabinet
+    Overlay,
+    /// A colour for each 8 by 8 cell of the screen, read from a PROM
+    ColorProm { prom: RomFile },
+}
+
+/// How the monitor is mounted in the cabinet.
+pub enum Orientation {
+    /// On its side, so each line of VRAM is a column of the picture
+    Rotated,
+    /// Upright, so each line of VRAM is a row
+    Landscape,
+}
+
+/// What a game's sound ports drive.
+pub enum SoundBoard {
+    /// Nothing emulated, so the game runs silently
+    None,
+    /// The Space Invaders discrete sound board, also fitted to Part II
+    Invaders,
+}
+
+/// A game on the Midway 8080 board: where its ROMs go, how its ports are wired and
+/// what its inputs and DIP switches read when idle.
+pub struct Driver {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub roms: &'static [RomFile],
+    pub ports: PortMap,
+    /// Idle value of each input latch, DIP switches included. A button is active high
+    /// if its bit is clear here and active low if set.
+    pub inputs: [u8; 4],
+    /// Input latch and bit for each button
+    pub buttons: &'static [(Button, usize, u8)],
+    pub dips: &'static [Dip],
+    pub video: Video,
+    pub orientation: Orientation,
+    pub sound: SoundBoard,
+}
+
+/// The I/O side of the board: input latches, the MB14241 shift register and the
+/// latched sound and lamp outputs.
+pub struct Board {
+    driver: &'static Driver,
+    inputs: [u8; 4],
+    shift: u16,
+    shift_amount: u8,
+    pub sound: [u8; 2],
+    /// Every write to a sound bank since the frontend last drained them
+    pub sound_writes: Vec<(usize, u8)>,
+    pub lamps: u8,
+    pub prom: Vec<u8>,
+}
+
+impl Board {
+    pub fn new(driver: &'static Driver) -> Board {
+        Board {
+            driver,
+            inputs: driver.inputs,
+            shift: 0,
+            shift_amount: 0,
+            sound: [0; 2],
+            sound_writes: Vec::new(),
+            lamps: 0,
+            prom: Vec::new(),
+        }
+    }
+
+    pub fn driver(&self) -> &'static Driver {
+        self.driver
+    }
+
+    /// Presses or releases `button`. Returns false if this game doesn't have it.
+    pub fn set_button(&mut self, button: Button, pressed: bool) -> bool {
+        let (port, mask) = match self.driver.buttons.iter().find(|(b, _, _)| *b == button) {
+            Some((_, port, mask)) => (*port, *mask),
+            None => return false,
+        };
+
+        let idle = self.driver.inputs[port] & mask;
+        let value = if pressed { !idle & mask } else { idle };
+        self.inputs[port] = (self.inputs[port] & !mask) | value;
+        true
+    }
+
+    /// Clears the shift register and the sound and lamp latches, as the reset line
+    /// does. Buttons stay as they are.
+    pub fn reset(&mut self) {
+        self.shift = 0;
+        self.shift_amount = 0;
+        self.sound = [0; 2];
+        self.sound_writes.clear();
+        self.lamps = 0;
+    }
+
+    fn shift_result(&self) -> u8 {
+        (self.shift >> (8 - self.shift_amount)) as u8
+    }
+}
+
+impl Inputs for Board {
+    fn set_control(&mut self, name: &str, pressed: bool) -> bool {
+        match BUTTON_NAMES.iter().find(|(button, _)| *button == name) {
+            Some((_, button)) => self.set_button(*button, pressed),
+            None => false,
+        }
+    }
+
+    fn set_setting(&mut self, name: &str, value: &str) -> Result<(), Box<dyn error::Error>> {
+        let dip = self
+            .driver
+            .dips
+            .iter()
+            .find(|dip| dip.name == name)
+            .ok_or_else(|| format!("{} has no {} switch", self.driver.name, name))?;
+        let (_, bits) = dip
+            .values
+            .iter()
+            .find(|(setting, _)| *setting == value)
+            .ok_or_else(|| format!("{} can't be set to {}", name, value))?;
+
+        self.inputs[dip.port] = (self.inputs[dip.port] & !dip.mask) | bits;
+        Ok(())
+    }
+}
+
+impl Io for Board {
+    fn input(&mut self, port: u8) -> u8 {
+        match self.driver.ports.reads[port as usize & 0x07] {
+            ReadPort::Input(latch) => self.inputs[latch],
+            ReadPort::ShiftResult => self.shift_result(),
+            ReadPort::ShiftResultReversed => self.shift_result().reverse_bits(),
+            ReadPort::Unmapped => 0,
+        }
+    }
+
+    fn output(&mut self, port: u8, value: u8) {
+        match self.driver.ports.writes[port as usize & 0x07] {
+            WritePort::ShiftAmount => self.shift_amount = value & 0x07,
+            WritePort::ShiftData => self.shift = (self.shift >> 8) | ((value as u16) << 8),
+            WritePort::Sound(bank) => {
+                self.sound[bank] = value;
+                self.sound_writes.push((bank, value));
+            }
+            WritePort::Lamps => self.lamps = value,
+            WritePort::Watchdog | WritePort::Unmapped => {}
+        }
+    }
+}
+
+/// A Midway 8080 board running `driver`.
+pub struct Midway<'a> {
+    cpu: Box<dyn Cpu + 'a>,
+    board: Board,
+    scheduler: Scheduler<Midway<'a>>,
+    /// Scanline the beam is on, and the cycle its frame started on
+    line: u64,
+    frame_start: u64,
+    /// VRAM as the beam drew it, one line at a time, when capture is on
+    screen: Option<Vec<u8>>,
+}
+
+impl<'a> Midway<'a> {
+    /// Loads the driver's ROMs from `rom_dir` into `mem` and write protects them.
+    pub fn new(
+        mem: &'a mut [u8],
+        driver: &'static Driver,
+        rom_dir: &Path,
+    ) -> Result<Midway<'a>, Box<dyn error::Error>> {
+        Self::with_kind(CpuKind::I8080, mem, driver, rom_dir)
+    }
+
+    /// The board with a `kind` CPU, such as a cached or lockstep 8080.
+    pub fn with_kind(
+        kind: CpuKind,
+        mem: &'a mut [u8],
+        driver: &'static Driver,
+        rom_dir: &Path,
+    ) -> Result<Midway<'a>, Box<dyn error::Error>> {
+        if mem.len() < 0x10000 {
+            return Err("The Midway board needs a 64K address space".into());
+        }
+
+        let mut board = Board::new(driver);
+        for rom in driver.roms.iter() {
+            let contents = read_rom(rom_dir, rom)?;
+            mem[rom.offset..rom.offset + rom.size].copy_from_slice(&contents);
+        }
+        if let Video::ColorProm { prom } = &driver.video {
+            board.prom = read_rom(rom_dir, prom)?;
+        }
+
+        let mut cpu = kind.build(mem);
+        for rom in driver.roms.iter() {
+            cpu.protect(rom.offset..rom.offset + rom.size);
+        }
+
+        Ok(Midway::with_cpu(cpu, board))
+    }
+
+    fn with_cpu(cpu: Box<dyn Cpu + 'a>, board: Board) -> Midway<'a> {
+        let mut scheduler = Scheduler::new();
+        scheduler.schedule(0, Self::scanline);
+
+        Midway {
+            cpu,
+            board,
+            scheduler,
+            line: 0,
+            frame_start: 0,
+            screen: None,
+        }
+    }
+
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    pub fn board_mut(&mut self) -> &mut Board {
+        &mut self.board
+    }
+
+    pub fn vram(&self) -> &[u8] {
+        &self.cpu.memory()[VRAM]
+    }
+
+    /// Copies each line of VRAM as the beam reaches it, so `screen` shows changes
+    /// made mid-frame where they appeared on the monitor.
+    pub fn set_scanline_capture(&mut self, enabled: bool) {
+        self.screen = if enabled {
+            Some(self.vram().to_vec())
+        } else {
+            None
+        };
+    }
+
+    /// The picture from the last frame: VRAM captured line by line if scanline
+    /// capture is on, or VRAM as it is now.
+    pub fn screen(&self) -> &[u8] {
+        match &self.screen {
+            Some(screen) => screen,
+            None => self.vram(),
+        }
+    }
+
+    /// The width and height of the picture from `render`.
+    pub fn size(&self) -> (usize, usize) {
+        video::size(self.board.driver())
+    }
+
+    /// Decodes `screen` to pixels for this game's monitor.
+    pub fn render(&self) -> Vec<u32> {
+        video::render(self.board.driver(), self.screen(), &self.board.prom)
+    }
+
+    /// Resets the CPU and board as the cabinet's reset switch would. The beam
+    /// carries on where it was.
+    pub fn reset(&mut self) {
+        self.cpu.reset();
+        self.board.reset();
+    }
+
+    /// Runs one video frame from its current line, raising the mid-screen and vblank
+    /// interrupts.
+    pub fn run_frame(&mut self) {
+        self.run_until(self.frame_start + CYCLES_PER_FRAME);
+    }
+
+    /// Runs the CPU while the beam draws the current scanline.
+    pub fn run_line(&mut self) {
+        self.run_until(self.frame_start + line_cycle(self.line + 1));
+    }
+
+    fn run_until(&mut self, cycle: u64) {
+        // The scheduler is lent the machine it belongs to for the run
+        let mut scheduler = std::mem::take(&mut self.scheduler);
+        scheduler.run_until(self, cycle);
+        self.scheduler = scheduler;
+    }
+
+    // The beam reaching the start of a line
+    fn scanline(&mut self, scheduler: &mut Scheduler<Midway<'a>>, _cycle: u64) {
+        let line = self.line;
+
+        if let Some(screen) = &mut self.screen {
+            if (line as usize) < VISIBLE_LINES {
+                let row = line as usize * BYTES_PER_LINE..(line as usize + 1) * BYTES_PER_LINE;
+                screen[row.clone()].copy_from_slice(&self.cpu.memory()[VRAM][row]);
+            }
+        }
+
+        match line {
+            MID_SCREEN_LINE => self.cpu.interrupt(MID_SCREEN),
+            VBLANK_LINE => self.cpu.interrupt(VBLANK),
+            _ => {}
+        }
+
+        self.line += 1;
+        if self.line == LINES_PER_FRAME {
+            self.line = 0;
+            self.frame_start += CYCLES_PER_FRAME;
+        }
+        scheduler.schedule(self.frame_start + line_cycle(self.line), Self::scanline);
+    }
+}
+
+impl Clocked for Midway<'_> {
+    fn cycles(&self) -> u64 {
+        self.cpu.cycles()
+    }
+
+    fn step(&mut self) {
+        // A halted CPU idles, using up cycles until the next interrupt
+        self.cpu.step_io(&mut self.board);
+    }
+}
+
+// Cycles from the start of a frame to the start of `line`
+fn line_cycle(line: u64) -> u64 {
+    line * CYCLES_PER_FRAME / LINES_PER_FRAME
+}
+
+fn read_rom(rom_dir: &Path, rom: &RomFile) -> Result<Vec<u8>, Box<dyn error::Error>> {
+    let path = rom_dir.join(rom.name);
+    let contents =
+        fs::read(&path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+    if contents.len() != rom.size {
+        return Err(format!(
+            "{} is {} bytes, expected {}",
+            path.display(),
+            contents.len(),
+            rom.size
+        )
+        .into());
+    }
+
+    Ok(contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shift_register() {
+        let mut board = Board::new(&drivers::INVADERS);
+
+        board.output(4, 0xAB);
+        board.output(4, 0xCD);
+        board.output(2, 0);
+        assert_eq!(board.input(3), 0xCD);
+
+        board.output(2, 4);
+        assert_eq!(board.input(3), 0xDA);
+    }
+
+    #[test]
+    fn reversed_shift_result() {
+        let mut board = Board::new(&drivers::SEAWOLF);
+
+        board.output(3, 0x01);
+        board.output(4, 0);
+        assert_eq!(board.input(3), 0x01);
+        assert_eq!(board.input(0), 0x80);
+    }
+
+    #[test]
+    fn active_high_button() {
+        let mut board = Board::new(&drivers::INVADERS);
+
+        assert!(board.set_button(Button::Coin, true));
+        assert_eq!(board.input(1), 0x09);
+
+        board.set_button(Button::Coin, false);
+        assert_eq!(board.input(1), 0x08);
+    }
+
+    #[test]
+    fn named_controls_and_dips() {
+        let mut board = Board::new(&drivers::INVADERS);
+
+        assert!(board.set_control("start1", true));
+        assert!(!board.set_control("up1", true));
+        board.set_setting("lives", "5").unwrap();
+        board.set_setting("coin_info", "off").unwrap();
+        assert!(board.set_setting("lives", "7").is_err());
+        assert!(board.set_setting("difficulty", "hard").is_err());
+
+        assert_eq!(board.input(1), 0x0C);
+        assert_eq!(board.input(2), 0x82);
+    }
+
+    // Every game's switches stay clear of its buttons, and read as one of their
+    // settings when idle
+    #[test]
+    fn dip_tables() {
+        for driver in drivers::DRIVERS.iter() {
+            assert!(!driver.dips.is_empty(), "{}", driver.name);
+            for dip in driver.dips.iter() {
+                let idle = driver.inputs[dip.port] & dip.mask;
+                assert!(
+                    dip.values.iter().any(|(_, bits)| *bits == idle),
+                    "{} {}",
+                    driver.name,
+                    dip.name
+                );
+                assert!(dip.values.iter().all(|(_, bits)| bits & !dip.mask == 0));
+                assert!(driver
+                    .buttons
+                    .iter()
+                    .all(|(_, port, mask)| *port != dip.port || mask & dip.mask == 0));
+            }
+        }
+
+        let mut board = Board::new(&drivers::GUNFIGHT);
+        board.set_setting("coinage", "2/1").unwrap();
+        board.set_setting("game_time", "90").unwrap();
+        assert_eq!(board.input(2), 0x31);
+    }
+
+    #[test]
+    fn active_low_button() {
+        let mut board = Board::new(&drivers::GUNFIGHT);
+
+        board.set_button(Button::Fire1, true);
+        assert_eq!(board.input(0), 0x7F);
+
+        assert!(!board.set_button(Button::Tilt, true));
+    }
+
+    #[test]
+    fn load_invaders() {
+        let rom_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("games/invaders");
+        let mut mem = vec![0; 0x10000];
+
+        let midway = Midway::new(&mut mem, drivers::find("invaders").unwrap(), &rom_dir);
+
+        assert!(midway.is_ok());
+        drop(midway);
+        assert_eq!(&mem[0x0000..0x0003], &[0x00, 0x00, 0x00]);
+        assert_eq!(mem[0x0003], 0xC3);
+    }
+
+    // EI and spin, counting RST 1 in B and RST 2 in C
+    fn counter(mem: &mut [u8]) -> Midway<'_> {
+        mem[..4].copy_from_slice(&[0xFB, 0xC3, 0x01, 0x00]);
+        mem[0x08..0x0B].copy_from_slice(&[0x04, 0xFB, 0xC9]);
+        mem[0x10..0x13].copy_from_slice(&[0x0C, 0xFB, 0xC9]);
+
+        let mut cpu = CpuKind::I8080.build(mem);
+        cpu.set_sp(0x2400);
+        Midway::with_cpu(cpu, Board::new(&drivers::INVADERS))
+    }
+
+    #[test]
+    fn interrupts_on_lines_96_and_224() {
+        let mut mem = vec![0; 0x10000];
+        let mut midway = counter(&mut mem);
+
+        for _ in 0..MID_SCREEN_LINE {
+            midway.run_line();
+        }
+        assert_eq!(midway.cpu.bc(), 0x0000);
+
+        midway.run_line();
+        assert_eq!(midway.cpu.bc(), 0x0100);
+
+        for _ in MID_SCREEN_LINE + 1..VBLANK_LINE + 1 {
+            midway.run_line();
+        }
+        assert_eq!(midway.cpu.bc(), 0x0101);
+
+        midway.run_frame();
+        assert_eq!(midway.cpu.bc(), 0x0101);
+        midway.run_frame();
+        assert_eq!(midway.cpu.bc(), 0x0202);
+    }
+
+    #[test]
+    fn sixty_frames_a_second() {
+        let mut mem = vec![0; 0x10000];
+        let mut midway = counter(&mut mem);
+
+        for _ in 0..FRAMES_PER_SECOND {
+            midway.run_frame();
+        }
+
+        let cycles = midway.cpu.cycles();
+        assert!((CPU_HZ - 20..CPU_HZ + 20).contains(&cycles), "{}", cycles);
+        assert_eq!(midway.cpu.bc(), 0x3C3C);
+    }
+
+    #[test]
+    fn scanline_capture() {
+        let mut mem = vec![0; 0x10000];
+        let mut midway = counter(&mut mem);
+        midway.set_scanline_capture(true);
+
+        // Fill VRAM halfway down the screen, after the beam has drawn the top
+        for _ in 0..100 {
+            midway.run_line();
+        }
+        for byte in midway.cpu.memory_mut()[VRAM].iter_mut() {
+            *byte = 0xFF;
+        }
+        midway.run_frame();
+
+        let screen = midway.screen();
+        assert!(screen[..100 * BYTES_PER_LINE].iter().all(|b| *b == 0));
+        assert!(screen[100 * BYTES_PER_LINE..].iter().all(|b| *b == 0xFF));
+        assert!(midway.vram().iter().all(|b| *b == 0xFF));
+    }
+
+    #[test]
+    fn invaders_draws_attract_screen() {
+        let rom_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("games/invaders");
+        let mut mem = vec![0; 0x10000];
+        let mut midway = Midway::new(&mut mem, &drivers::INVADERS, &rom_dir).unwrap();
+        midway.set_scanline_capture(true);
+
+        for _ in 0..600 {
+            midway.run_frame();
+        }
+
+        let pixels = midway.render();
+        assert!(pixels.iter().filter(|p| **p != video::BLACK).count() > 1000);
+    }
+
+    // The recompiled blocks, checked against the interpreter through the attract mode
+    #[test]
+    fn native_invaders_in_lockstep() {
+        use crate::emulator::native::{invaders, Native};
+        use crate::emulator::Lockstep;
+
+        let rom_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("games/invaders");
+        let mut mem = vec![0; 0x10000];
+        for rom in drivers::INVADERS.roms.iter() {
+            let contents = read_rom(&rom_dir, rom).unwrap();
+            mem[rom.offset..rom.offset + rom.size].copy_from_slice(&contents);
+        }
+        let native = Native::new(&mut mem, &invaders::BLOCKS);
+        let mut midway = Midway::with_cpu(
+            Box::new(Lockstep::new(Box::new(native))),
+            Board::new(&drivers::INVADERS),
+        );
+
+        for _ in 0..300 {
+            midway.run_frame();
+        }
+
+        assert!(midway.vram().iter().any(|b| *b != 0));
+    }
+
+    #[test]
+    fn missing_roms() {
+        let mut mem = vec![0; 0x10000];
+
+        assert!(Midway::new(&mut mem, &drivers::SEAWOLF, Path::new("/nonexistent")).is_err());
+    }
+}
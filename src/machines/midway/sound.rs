@@ -0,0 +1,346 @@
+use std::error;
+use std::f32::consts::PI;
+use std::fs;
+use std::path::Path;
+
+use super::{Driver, Midway, SoundBoard, FRAMES_PER_SECOND};
+use crate::machines::input::Movie;
+use crate::wav;
+
+pub const SAMPLE_RATE: u32 = 44_100;
+pub const SAMPLES_PER_FRAME: usize = (SAMPLE_RATE as u64 / FRAMES_PER_SECOND) as usize;
+
+// Voices, numbered like the usual invaders sample set (0.wav to 9.wav)
+const UFO: usize = 0;
+const SHOT: usize = 1;
+const PLAYER_DIE: usize = 2;
+const INVADER_DIE: usize = 3;
+const FLEET: [usize; 4] = [4, 5, 6, 7];
+const UFO_HIT: usize = 8;
+const EXTENDED_PLAY: usize = 9;
+const VOICES: usize = 10;
+
+// Sound bank, bit and voice for each trigger
+const TRIGGERS: [(usize, u8, usize); 10] = [
+    (0, 0x01, UFO),
+    (0, 0x02, SHOT),
+    (0, 0x04, PLAYER_DIE),
+    (0, 0x08, INVADER_DIE),
+    (0, 0x10, EXTENDED_PLAY),
+    (1, 0x01, FLEET[0]),
+    (1, 0x02, FLEET[1]),
+    (1, 0x04, FLEET[2]),
+    (1, 0x08, FLEET[3]),
+    (1, 0x10, UFO_HIT),
+];
+
+// Port 3 bit 5 gates the amplifier
+const AMP_ENABLE: u8 = 0x20;
+
+struct Voice {
+    data: Vec<i16>,
+    position: Option<usize>,
+    looping: bool,
+}
+
+/// The Space Invaders discrete sound board, driven by writes to sound ports 3 and 5.
+pub struct Sound {
+    voices: Vec<Voice>,
+    triggers: &'static [(usize, u8, usize)],
+    latches: [u8; 2],
+}
+
+impl Sound {
+    /// The sound board `driver` is fitted with, playing samples from `samples` if
+    /// given. Games whose board isn't emulated get silence.
+    pub fn for_driver(
+        driver: &Driver,
+        samples: Option<&Path>,
+    ) -> Result<Sound, Box<dyn error::Error>> {
+        match (&driver.sound, samples) {
+            (SoundBoard::Invaders, Some(dir)) => Self::from_samples(dir),
+            (SoundBoard::Invaders, None) => Ok(Self::synthesized()),
+            (SoundBoard::None, _) => Ok(Sound {
+                voices: Vec::new(),
+                triggers: &[],
+                latches: [0; 2],
+            }),
+        }
+    }
+
+    /// Sound board with every voice synthesized.
+    pub fn synthesized() -> Sound {
+        Sound {
+            voices: (0..VOICES)
+                .map(|voice| Voice {
+                    data: synthesize(voice),
+                    position: None,
+                    looping: voice == UFO,
+                })
+                .collect(),
+            triggers: &TRIGGERS,
+            latches: [0; 2],
+        }
+    }
+
+    /// Sound board playing `0.wav` to `9.wav` from `dir`, synthesizing any that are missing.
+    pub fn from_samples(dir: &Path) -> Result<Sound, Box<dyn error::Error>> {
+        let mut sound = Self::synthesized();
+
+        for (index, voice) in sound.voices.iter_mut().enumerate() {
+            let path = dir.join(format!("{}.wav", index));
+            if !path.exists() {
+                continue;
+            }
+
+            let (samples, rate) =
+                wav::decode(&fs::read(&path)?).map_err(|e| format!("{}: {}", path.display(), e))?;
+            voice.data = resample(&samples, rate, SAMPLE_RATE);
+        }
+
+        Ok(sound)
+    }
+
+    /// Latches a write to sound `bank`. Rising edges start sounds; the UFO plays for
+    /// as long as its bit is held.
+    pub fn write(&mut self, bank: usize, value: u8) {
+        let previous = self.latches[bank];
+        self.latches[bank] = value;
+
+        for (trigger_bank, bit, voice) in self.triggers.iter() {
+            if *trigger_bank != bank {
+                continue;
+            }
+
+            let voice = &mut self.voices[*voice];
+            let (was_on, is_on) = (previous & bit != 0, value & bit != 0);
+            // An empty sample has nothing to play
+            if is_on && !was_on && !voice.data.is_empty() {
+                voice.position = Some(0);
+            } else if !is_on && voice.looping {
+                voice.position = None;
+            }
+        }
+    }
+
+    /// Mixes the next `count` samples.
+    pub fn render(&mut self, count: usize) -> Vec<i16> {
+        let mut mix = vec![0i32; count];
+
+        for voice in self.voices.iter_mut() {
+            for sample in mix.iter_mut() {
+                let position = match voice.position {
+                    Some(position) => position,
+                    None => break,
+                };
+
+                *sample += voice.data[position] as i32;
+
+                voice.position = if position + 1 < voice.data.len() {
+                    Some(position + 1)
+                } else if voice.looping {
+                    Some(0)
+                } else {
+                    None
+                };
+            }
+        }
+
+        if self.latches[0] & AMP_ENABLE == 0 {
+            return vec![0; count];
+        }
+
+        mix.iter()
+            .map(|sample| (*sample).clamp(i16::MIN as i32, i16::MAX as i32) as i16)
+            .collect()
+    }
+}
+
+/// Runs `frames` frames with input from `movie`, feeding the board's sound writes
+/// to `sound`, and returns the audio they produced.
+pub fn run_headless(
+    midway: &mut Midway,
+    sound: &mut Sound,
+    frames: u64,
+    movie: &Movie,
+) -> Vec<i16> {
+    let mut samples = Vec::with_capacity(frames as usize * SAMPLES_PER_FRAME);
+
+    for frame in 0..frames {
+        movie.apply(frame, midway.board_mut());
+        midway.run_frame();
+        for (bank, value) in midway.board_mut().sound_writes.drain(..) {
+            sound.write(bank, value);
+        }
+        samples.extend(sound.render(SAMPLES_PER_FRAME));
+    }
+
+    samples
+}
+
+fn resample(samples: &[i16], from: u32, to: u32) -> Vec<i16> {
+    if from == to || samples.is_empty() {
+        return samples.to_vec();
+    }
+
+    let len = samples.len() as u64 * to as u64 / from as u64;
+    (0..len)
+        .map(|i| samples[(i * from as u64 / to as u64) as usize])
+        .collect()
+}
+
+// Cheap deterministic noise for the explosion sounds
+struct Noise(u32);
+
+impl Noise {
+    fn next(&mut self) -> f32 {
+        self.0 = self.0.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        ((self.0 >> 16) & 0x7FFF) as f32 / 16384.0 - 1.0
+    }
+}
+
+/// Approximates the analog circuit behind each voice.
+fn synthesize(voice: usize) -> Vec<i16> {
+    let rate = SAMPLE_RATE as f32;
+    let length = match voice {
+        UFO => 0.2,
+        SHOT => 0.25,
+        INVADER_DIE => 0.15,
+        PLAYER_DIE | UFO_HIT | EXTENDED_PLAY => 1.0,
+        _ => 0.1,
+    };
+
+    let mut noise = Noise(voice as u32 + 1);
+    let mut phase = 0.0f32;
+    let mut samples = Vec::with_capacity((length * rate) as usize);
+
+    for i in 0..(length * rate) as usize {
+        let t = i as f32 / rate;
+        let mut tone = |frequency: f32| {
+            phase += 2.0 * PI * frequency / rate;
+            phase.sin()
+        };
+
+        let level = match voice {
+            UFO => {
+                // Warbling tone, swept by a 5 Hz triangle
+                let sweep = 1.0 - (2.0 * ((t * 5.0) % 1.0) - 1.0).abs();
+                tone(500.0 + 400.0 * sweep) * 0.3
+            }
+            SHOT => {
+                let sweep = tone((1200.0 - 3600.0 * t).max(200.0));
+                (sweep * 0.6 + noise.next() * 0.4) * (1.0 - t / length) * 0.5
+            }
+            PLAYER_DIE | INVADER_DIE => noise.next() * (1.0 - t / length) * 0.6,
+            UFO_HIT => {
+                // Alternates between two tones
+                let high = ((t * 16.0) as u32).is_multiple_of(2);
+                tone(if high { 1500.0 } else { 1000.0 }).signum() * 0.25
+            }
+            EXTENDED_PLAY => {
+                let gate = ((t * 8.0) as u32).is_multiple_of(2);
+                tone(2000.0) * if gate { 0.3 } else { 0.0 }
+            }
+            fleet => {
+                // The four marching notes, each lower than the last
+                let frequency = [110.0, 98.0, 87.0, 82.0][fleet - FLEET[0]];
+                tone(frequency).signum() * (1.0 - t / length) * 0.5
+            }
+        };
+
+        samples.push((level * i16::MAX as f32) as i16);
+    }
+
+    samples
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn loud(samples: &[i16]) -> bool {
+        samples.iter().any(|s| s.abs() > 1000)
+    }
+
+    #[test]
+    fn silent_without_amp() {
+        let mut sound = Sound::synthesized();
+
+        sound.write(0, 0x02);
+
+        assert!(!loud(&sound.render(SAMPLES_PER_FRAME)));
+    }
+
+    #[test]
+    fn shot_plays_once() {
+        let mut sound = Sound::synthesized();
+
+        sound.write(0, AMP_ENABLE | 0x02);
+        assert!(loud(&sound.render(SAMPLES_PER_FRAME)));
+
+        sound.render(SAMPLE_RATE as usize);
+        assert!(!loud(&sound.render(SAMPLES_PER_FRAME)));
+    }
+
+    #[test]
+    fn ufo_loops_while_held() {
+        let mut sound = Sound::synthesized();
+
+        sound.write(0, AMP_ENABLE | 0x01);
+        sound.render(SAMPLE_RATE as usize);
+        assert!(loud(&sound.render(SAMPLES_PER_FRAME)));
+
+        sound.write(0, AMP_ENABLE);
+        assert!(!loud(&sound.render(SAMPLES_PER_FRAME)));
+    }
+
+    #[test]
+    fn fleet_on_port_5() {
+        let mut sound = Sound::synthesized();
+
+        sound.write(0, AMP_ENABLE);
+        sound.write(1, 0x01);
+
+        assert!(loud(&sound.render(SAMPLES_PER_FRAME)));
+    }
+
+    #[test]
+    fn samples_from_dir() {
+        let dir = std::env::temp_dir().join(format!("emurs-samples-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        wav::write(&dir.join("1.wav"), &[20000; 100], 22050).unwrap();
+
+        let mut sound = Sound::from_samples(&dir).unwrap();
+        sound.write(0, AMP_ENABLE | 0x02);
+        let samples = sound.render(300);
+
+        assert_eq!(samples[199], 20000);
+        assert_eq!(samples[200], 0);
+    }
+
+    #[test]
+    fn empty_sample_is_silent() {
+        let dir = std::env::temp_dir().join(format!("emurs-empty-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        wav::write(&dir.join("0.wav"), &[], 22050).unwrap();
+
+        let mut sound = Sound::from_samples(&dir).unwrap();
+        sound.write(0, AMP_ENABLE | 0x01);
+
+        assert!(!loud(&sound.render(SAMPLES_PER_FRAME)));
+    }
+
+    #[test]
+    fn sound_depends_on_the_driver() {
+        use super::super::drivers;
+
+        let mut invaders = Sound::for_driver(&drivers::INVADERS, None).unwrap();
+        let mut gunfight = Sound::for_driver(&drivers::GUNFIGHT, None).unwrap();
+        for sound in [&mut invaders, &mut gunfight] {
+            sound.write(0, AMP_ENABLE | 0x02);
+        }
+
+        assert!(loud(&invaders.render(SAMPLES_PER_FRAME)));
+        assert!(!loud(&gunfight.render(SAMPLES_PER_FRAME)));
+    }
+}
@@ -0,0 +1,143 @@
+use super::{Driver, Orientation, Video, BYTES_PER_LINE, VISIBLE_LINES};
+
+// Pixels in each line of VRAM
+const LINE_PIXELS: usize = BYTES_PER_LINE * 8;
+
+pub const BLACK: u32 = 0x000000;
+pub const WHITE: u32 = 0xFFFFFF;
+const RED: u32 = 0xFF2020;
+const GREEN: u32 = 0x20FF20;
+
+/// The width and height of the picture `render` makes for `driver`.
+pub fn size(driver: &Driver) -> (usize, usize) {
+    match driver.orientation {
+        Orientation::Rotated => (VISIBLE_LINES, LINE_PIXELS),
+        Orientation::Landscape => (LINE_PIXELS, VISIBLE_LINES),
+    }
+}
+
+/// Decodes a screen's worth of VRAM into pixels of the picture's `size`, row by
+/// row, as 0x00RRGGBB.
+pub fn render(driver: &Driver, vram: &[u8], prom: &[u8]) -> Vec<u32> {
+    let (width, height) = size(driver);
+    let mut pixels = vec![BLACK; width * height];
+
+    for (offset, byte) in vram.iter().enumerate().take(VISIBLE_LINES * BYTES_PER_LINE) {
+        let line = offset / BYTES_PER_LINE;
+        for bit in 0..8 {
+            if byte & (1 << bit) == 0 {
+                continue;
+            }
+
+            let pixel = (offset % BYTES_PER_LINE) * 8 + bit;
+            // On its side, each line of VRAM is a column of the picture, drawn from
+            // the bottom up
+            let (x, y) = match driver.orientation {
+                Orientation::Rotated => (line, height - 1 - pixel),
+                Orientation::Landscape => (pixel, line),
+            };
+            pixels[y * width + x] = match &driver.video {
+                Video::Monochrome => WHITE,
+                Video::Overlay => overlay(x, y),
+                Video::ColorProm { .. } => cell_color(offset, prom),
+            };
+        }
+    }
+
+    pixels
+}
+
+// The cellophane strips on the Invaders cabinet: red across the UFO's path, green
+// over the bases and the spare lives at the bottom left
+fn overlay(x: usize, y: usize) -> u32 {
+    match y {
+        32..=63 => RED,
+        184..=239 => GREEN,
+        240..=255 if (16..134).contains(&x) => GREEN,
+        _ => WHITE,
+    }
+}
+
+// The PROM has an entry for each 8 line by 8 pixel cell, 32 to a row of cells,
+// with bits 0 to 2 for red, blue and green
+fn cell_color(offset: usize, prom: &[u8]) -> u32 {
+    let cell = (offset / (BYTES_PER_LINE * 8)) * BYTES_PER_LINE + offset % BYTES_PER_LINE;
+    let color = prom.get(cell).copied().unwrap_or(0x07) & 0x07;
+
+    [(0x01, 0xFF0000), (0x02, 0x0000FF), (0x04, 0x00FF00)]
+        .iter()
+        .filter(|(bit, _)| color & bit != 0)
+        .fold(BLACK, |pixel, (_, channel)| pixel | channel)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::drivers;
+    use super::*;
+
+    #[test]
+    fn rotated_pixels() {
+        let mut vram = vec![0; VISIBLE_LINES * BYTES_PER_LINE];
+        // First pixel of the first line, and the last pixel of the last
+        vram[0] = 0x01;
+        vram[VISIBLE_LINES * BYTES_PER_LINE - 1] = 0x80;
+
+        let (width, height) = size(&drivers::LRESCUE);
+        let pixels = render(&drivers::LRESCUE, &vram, &[0x07; 0x400]);
+
+        assert_eq!((width, height), (224, 256));
+        assert_eq!(pixels[(height - 1) * width], WHITE);
+        assert_eq!(pixels[width - 1], WHITE);
+        assert_eq!(pixels.iter().filter(|p| **p != BLACK).count(), 2);
+    }
+
+    #[test]
+    fn landscape_pixels() {
+        let mut vram = vec![0; VISIBLE_LINES * BYTES_PER_LINE];
+        vram[0] = 0x01;
+        vram[VISIBLE_LINES * BYTES_PER_LINE - 1] = 0x80;
+
+        let (width, height) = size(&drivers::SEAWOLF);
+        let pixels = render(&drivers::SEAWOLF, &vram, &[]);
+
+        assert_eq!((width, height), (256, 224));
+        assert_eq!(pixels[0], WHITE);
+        assert_eq!(pixels[width * height - 1], WHITE);
+        assert_eq!(pixels.iter().filter(|p| **p != BLACK).count(), 2);
+    }
+
+    #[test]
+    fn invaders_overlay() {
+        let vram = vec![0xFF; VISIBLE_LINES * BYTES_PER_LINE];
+        let (width, _) = size(&drivers::INVADERS);
+
+        let pixels = render(&drivers::INVADERS, &vram, &[]);
+
+        assert_eq!(pixels[40 * width + 100], RED);
+        assert_eq!(pixels[100 * width + 100], WHITE);
+        assert_eq!(pixels[200 * width + 100], GREEN);
+        assert_eq!(pixels[250 * width + 20], GREEN);
+        assert_eq!(pixels[250 * width + 200], WHITE);
+    }
+
+    #[test]
+    fn prom_cells() {
+        let vram = vec![0xFF; VISIBLE_LINES * BYTES_PER_LINE];
+        let mut prom = vec![0; 0x400];
+        // The first cell, at the bottom left of the picture, is blue, and the one
+        // eight lines to its right is green
+        prom[0] = 0x02;
+        prom[32] = 0x04;
+        // Cells go up to 27 rows of 32
+        prom[27 * 32 + 31] = 0x01;
+        let (width, height) = size(&drivers::BALLBOMB);
+
+        let pixels = render(&drivers::BALLBOMB, &vram, &prom);
+
+        assert_eq!(pixels[(height - 1) * width], 0x0000FF);
+        assert_eq!(pixels[(height - 8) * width + 7], 0x0000FF);
+        assert_eq!(pixels[(height - 9) * width], BLACK);
+        assert_eq!(pixels[(height - 1) * width + 8], 0x00FF00);
+        assert_eq!(pixels[width - 1], 0xFF0000);
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::VecDeque;
+use std::io::{self, Read, Write};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+
+pub mod altair;
+pub mod cpm;
+pub mod input;
+pub mod midway;
+
+/// A character terminal attached to a machine, e.g. the CP/M console.
+pub trait Console {
+    /// Whether a byte can be read without blocking.
+    fn ready(&mut self) -> bool;
+    /// Blocks until a byte is available. Returns `None` once input is exhausted.
+    fn read(&mut self) -> Option<u8>;
+    fn write(&mut self, byte: u8);
+
+    /// Whether the host already echoes typed characters, so machines shouldn't.
+    fn local_echo(&self) -> bool {
+        false
+    }
+}
+
+/// Console on the host's stdin and stdout. Stdin is drained by a background thread so
+/// `ready` never blocks.
+pub struct StdConsole {
+    input: Receiver<u8>,
+    pending: Option<u8>,
+    closed: bool,
+}
+
+impl StdConsole {
+    pub fn new() -> StdConsole {
+        let (sender, input) = mpsc::channel();
+        thread::spawn(move || {
+            for byte in io::stdin().lock().bytes() {
+                match byte {
+                    Ok(byte) if sender.send(byte).is_ok() => {}
+                    _ => break,
+                }
+            }
+        });
+
+        StdConsole {
+            input,
+            pending: None,
+            closed: false,
+        }
+    }
+}
+
+impl Default for StdConsole {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Console for StdConsole {
+    fn ready(&mut self) -> bool {
+        if self.pending.is_none() && !self.closed {
+            match self.input.try_recv() {
+                Ok(byte) => self.pending = Some(byte),
+                Err(TryRecvError::Disconnected) => self.closed = true,
+                Err(TryRecvError::Empty) => {}
+            }
+        }
+        self.pending.is_some()
+    }
+
+    fn read(&mut self) -> Option<u8> {
+        self.pending.take().or_else(|| self.input.recv().ok())
+    }
+
+    fn write(&mut self, byte: u8) {
+        let mut stdout = io::stdout();
+        stdout
+            .write_all(&[byte])
+            .expect("Failed to write to stdout");
+        stdout.flush().expect("Failed to flush stdout");
+    }
+
+    fn local_echo(&self) -> bool {
+        true
+    }
+}
+
+/// Console fed from a fixed input buffer that records everything written to it.
+#[derive(Default)]
+pub struct BufferConsole {
+    pub input: VecDeque<u8>,
+    pub output: Vec<u8>,
+}
+
+impl BufferConsole {
+    pub fn new(input: &[u8]) -> BufferConsole {
+        BufferConsole {
+            input: input.iter().copied().collect(),
+            output: Vec::new(),
+        }
+    }
+}
+
+impl Console for BufferConsole {
+    fn ready(&mut self) -> bool {
+        !self.input.is_empty()
+    }
+
+    fn read(&mut self) -> Option<u8> {
+        self.input.pop_front()
+    }
+
+    fn write(&mut self, byte: u8) {
+        self.output.push(byte);
+    }
+}
@@ -1,23 +1,312 @@
 use std::env;
 use std::error;
 use std::fs;
-use std::io::Read;
+use std::path::Path;
 
+use machines::input::{InputMap, Movie};
+use pacer::{Pacer, Speed};
+
+pub mod bench;
+pub mod cpu;
 pub mod disasm;
 pub mod emulator;
+pub mod frontend;
+pub mod loader;
+pub mod machines;
+pub mod pacer;
+pub mod recompiler;
+pub mod scheduler;
+pub mod wav;
+pub mod z80;
+
+fn run_cpm(args: &[String]) -> Result<(), Box<dyn error::Error>> {
+    let (kind, args) = match args.split_first() {
+        Some((flag, rest)) if flag == "--z80" => (cpu::CpuKind::Z80, rest),
+        Some((flag, rest)) if flag == "--8085" => (cpu::CpuKind::I8085, rest),
+        Some((flag, rest)) if flag == "--cached" => (cpu::CpuKind::Cached, rest),
+        Some((flag, rest)) if flag == "--lockstep" => (cpu::CpuKind::Lockstep, rest),
+        Some((flag, rest)) if flag == "--native" => (cpu::CpuKind::Native, rest),
+        _ => (cpu::CpuKind::I8080, args),
+    };
+    let program_path = args.first().expect("No .COM file found in arguments");
+    let program = fs::read(program_path)?;
+    let mut memory = vec![0; 0x10000];
+
+    let mut cpm = machines::cpm::Cpm::with_cpu(
+        kind,
+        &mut memory,
+        &program,
+        &args[1..],
+        &env::current_dir()?,
+        machines::StdConsole::new(),
+    )?;
+    cpm.run()
+}
+
+// Key bindings and settings from the config file at `path`, or the defaults
+fn input_map(path: Option<&String>) -> Result<InputMap, Box<dyn error::Error>> {
+    match path {
+        Some(path) => InputMap::load(Path::new(path)),
+        None => Ok(InputMap::default()),
+    }
+}
+
+fn run_altair(args: &[String]) -> Result<(), Box<dyn error::Error>> {
+    let image_path = args.first().expect("No image found in arguments");
+    let mut config = machines::altair::Config::default();
+    if let Some(ram) = args.get(1) {
+        config.ram = ram.parse::<usize>()? * 1024;
+    }
+    if let Some(switches) = args.get(2) {
+        config.sense_switches = u8::from_str_radix(switches, 16)?;
+    }
+
+    let inputs = input_map(args.get(3))?;
+
+    let mut memory = vec![0; 0x10000];
+    let mut altair =
+        machines::altair::Altair::new(&mut memory, config, machines::StdConsole::new());
+    let start = loader::load(Path::new(image_path), altair.memory_mut(), 0)?;
+    inputs.apply_settings(altair.ports())?;
+    altair.set_pc(start.unwrap_or(0));
+    if altair.run() == emulator::Status::Deadlock {
+        eprintln!("Halted with interrupts disabled");
+    }
+
+    Ok(())
+}
+
+fn run_midway(args: &[String]) -> Result<(), Box<dyn error::Error>> {
+    use machines::midway::{self, sound};
+
+    let name = args.first().expect("No driver found in arguments");
+    let driver = midway::drivers::find(name).ok_or_else(|| format!("Unknown driver {}", name))?;
+    let rom_dir = args.get(1).expect("No ROM directory found in arguments");
+    let frames = match args.get(2) {
+        Some(frames) => frames.parse()?,
+        None => midway::FRAMES_PER_SECOND * 10,
+    };
+
+    let mut memory = vec![0; 0x10000];
+    let mut machine = midway::Midway::new(&mut memory, driver, Path::new(rom_dir))?;
+    let mut sounds = sound::Sound::for_driver(driver, args.get(4).map(Path::new))?;
+
+    let movie = match args.get(5) {
+        Some(path) => Movie::load(Path::new(path))?,
+        None => Movie::default(),
+    };
+
+    let samples = sound::run_headless(&mut machine, &mut sounds, frames, &movie);
+    if let Some(wav_path) = args.get(3) {
+        wav::write(Path::new(wav_path), &samples, sound::SAMPLE_RATE)?;
+    }
+
+    Ok(())
+}
+
+// Splits --speed=N (N times real time), --uncapped and --frameskip=N off `args`
+fn pacer(args: &[String], clock_hz: u64) -> Result<(Vec<String>, Pacer), Box<dyn error::Error>> {
+    let mut pacer = Pacer::new(clock_hz, Speed::Scaled(1));
+    let mut rest = Vec::new();
+
+    for arg in args {
+        if let Some(speed) = arg.strip_prefix("--speed=") {
+            pacer.set_speed(Speed::Scaled(speed.parse()?), 0);
+        } else if arg == "--uncapped" {
+            pacer.set_speed(Speed::Uncapped, 0);
+        } else if let Some(skip) = arg.strip_prefix("--frameskip=") {
+            pacer.set_frame_skip(skip.parse()?);
+        } else {
+            rest.push(arg.clone());
+        }
+    }
+
+    Ok((rest, pacer))
+}
+
+fn run_bench(args: &[String]) -> Result<(), Box<dyn error::Error>> {
+    use machines::midway;
+    use scheduler::Clocked;
+
+    let (kind, args) = match args.split_first() {
+        Some((flag, rest)) if flag == "--cached" => (cpu::CpuKind::Cached, rest),
+        Some((flag, rest)) if flag == "--lockstep" => (cpu::CpuKind::Lockstep, rest),
+        Some((flag, rest)) if flag == "--native" => (cpu::CpuKind::Native, rest),
+        _ => (cpu::CpuKind::I8080, args),
+    };
+    let name = args.first().expect("No driver found in arguments");
+    let driver = midway::drivers::find(name).ok_or_else(|| format!("Unknown driver {}", name))?;
+    let rom_dir = args.get(1).expect("No ROM directory found in arguments");
+    let frames: u64 = match args.get(2) {
+        Some(frames) => frames.parse()?,
+        None => midway::FRAMES_PER_SECOND * 60,
+    };
+
+    let mut memory = vec![0; 0x10000];
+    let mut machine = midway::Midway::with_kind(kind, &mut memory, driver, Path::new(rom_dir))?;
+    let pacer = Pacer::new(midway::CPU_HZ, Speed::Uncapped);
+    for _ in 0..frames {
+        machine.run_frame();
+    }
+
+    let mhz = pacer.mhz(machine.cycles());
+    println!(
+        "{} frames, {} cycles: {:.2} MHz, {:.1}x real time",
+        frames,
+        machine.cycles(),
+        mhz,
+        mhz * 1_000_000.0 / midway::CPU_HZ as f64
+    );
+    Ok(())
+}
+
+fn run_bench_cpu(args: &[String]) -> Result<(), Box<dyn error::Error>> {
+    let kind = match args.first().map(String::as_str) {
+        None | Some("8080") => cpu::CpuKind::I8080,
+        Some("8085") => cpu::CpuKind::I8085,
+        Some("z80") => cpu::CpuKind::Z80,
+        Some("cached") => cpu::CpuKind::Cached,
+        Some("lockstep") => cpu::CpuKind::Lockstep,
+        Some("native") => cpu::CpuKind::Native,
+        Some(other) => return Err(format!("Unknown CPU {}", other).into()),
+    };
+    let megacycles: u64 = match args.get(1) {
+        Some(megacycles) => megacycles.parse()?,
+        None => 1000,
+    };
+
+    let (cycles, elapsed) = bench::run(kind, megacycles * 1_000_000);
+    println!(
+        "{:?}: {} cycles in {:.2?}, {:.2} MHz",
+        kind,
+        cycles,
+        elapsed,
+        cycles as f64 / elapsed.as_secs_f64() / 1_000_000.0
+    );
+    Ok(())
+}
+
+fn run_tty(args: &[String]) -> Result<(), Box<dyn error::Error>> {
+    use frontend::terminal::{self, Mode};
+    use machines::midway;
+
+    let (args, mut pacer) = pacer(args, midway::CPU_HZ)?;
+
+    let name = args.first().expect("No driver found in arguments");
+    let driver = midway::drivers::find(name).ok_or_else(|| format!("Unknown driver {}", name))?;
+    let rom_dir = args.get(1).expect("No ROM directory found in arguments");
+    let mode = match args.get(2).map(String::as_str) {
+        Some("halfblock") => Mode::HalfBlock,
+        Some("braille") | None => Mode::Braille,
+        Some(other) => return Err(format!("Unknown mode {}", other).into()),
+    };
+    let inputs = input_map(args.get(3))?;
+
+    let mut memory = vec![0; 0x10000];
+    let mut machine = midway::Midway::new(&mut memory, driver, Path::new(rom_dir))?;
+    inputs.apply_settings(machine.board_mut())?;
+    machine.set_scanline_capture(true);
+    terminal::run(&mut machine, mode, &inputs, &mut pacer)
+}
+
+#[cfg(feature = "window")]
+fn run_window(args: &[String]) -> Result<(), Box<dyn error::Error>> {
+    use frontend::window;
+    use machines::midway;
+    use minifb::Scale;
+
+    let (args, mut pacer) = pacer(args, midway::CPU_HZ)?;
+    let name = args.first().expect("No driver found in arguments");
+    let driver = midway::drivers::find(name).ok_or_else(|| format!("Unknown driver {}", name))?;
+    let rom_dir = args.get(1).expect("No ROM directory found in arguments");
+    let scale = match args.get(2).map(String::as_str) {
+        Some("1") => Scale::X1,
+        Some("2") | None => Scale::X2,
+        Some("4") => Scale::X4,
+        Some("fit") => Scale::FitScreen,
+        Some(other) => return Err(format!("Unknown scale {}", other).into()),
+    };
+    let frames = match args.get(3) {
+        Some(frames) => Some(frames.parse()?),
+        None => None,
+    };
+    let inputs = input_map(args.get(4))?;
+
+    let mut memory = vec![0; 0x10000];
+    let mut machine = midway::Midway::new(&mut memory, driver, Path::new(rom_dir))?;
+    inputs.apply_settings(machine.board_mut())?;
+    machine.set_scanline_capture(true);
+    window::run(&mut machine, scale, frames, &inputs, &mut pacer)
+}
+
+#[cfg(not(feature = "window"))]
+fn run_window(_args: &[String]) -> Result<(), Box<dyn error::Error>> {
+    Err("Built without the window feature".into())
+}
+
+fn run_disasm(args: &[String]) -> Result<(), Box<dyn error::Error>> {
+    let (flag_undocumented, args) = match args.split_first() {
+        Some((flag, rest)) if flag == "--undocumented" => (true, rest),
+        _ => (false, args),
+    };
+    let path = args.first().expect("No file found in arguments");
+
+    print!(
+        "{}",
+        disasm::disasm_with(&fs::read(path)?, flag_undocumented)?
+    );
+    Ok(())
+}
+
+fn run_recompile(args: &[String]) -> Result<(), Box<dyn error::Error>> {
+    let (entries, args) = match args.split_first() {
+        Some((flag, rest)) if flag == "--entry" => {
+            let list = rest.first().expect("No entry points found in arguments");
+            let entries = list
+                .split(',')
+                .map(|entry| usize::from_str_radix(entry.trim_start_matches("0x"), 16))
+                .collect::<Result<Vec<_>, _>>()?;
+            (entries, &rest[1..])
+        }
+        // The reset and interrupt vectors
+        _ => ((0..8).map(|vector| vector * 8).collect(), args),
+    };
+    if args.is_empty() {
+        return Err("No file found in arguments".into());
+    }
+
+    let mut code = Vec::new();
+    for path in args {
+        code.extend(fs::read(path)?);
+    }
+    print!("{}", recompiler::recompile(&code, &entries)?);
+    Ok(())
+}
 
 fn main() -> Result<(), Box<dyn error::Error>> {
-    let game_path: String = env::args().nth(1).expect("No filename found in arguments");
-    let mut file_contents: Vec<u8> = Vec::new();
-    let mut buffer = vec![0; 0xF000];
+    let args: Vec<String> = env::args().skip(1).collect();
+    let game_path = args.first().expect("No filename found in arguments");
 
-    fs::File::open(game_path)?.read_to_end(&mut file_contents)?;
-    file_contents.append(&mut buffer);
+    match game_path.as_str() {
+        "cpm" => return run_cpm(&args[1..]),
+        "disasm" => return run_disasm(&args[1..]),
+        "altair" => return run_altair(&args[1..]),
+        "midway" => return run_midway(&args[1..]),
+        "tty" => return run_tty(&args[1..]),
+        "bench" => return run_bench(&args[1..]),
+        "bench-cpu" => return run_bench_cpu(&args[1..]),
+        "recompile" => return run_recompile(&args[1..]),
+        "window" => return run_window(&args[1..]),
+        _ => {}
+    }
 
-    let asm = disasm::disasm(&file_contents)?;
-    //println!("{}", asm);
+    let mut memory = vec![0; 0x10000];
+    let start = loader::load(Path::new(game_path), &mut memory, 0)?;
 
-    let mut runner = emulator::State::new(&mut file_contents);
+    let mut runner = emulator::State::new(&mut memory);
+    if let Some(start) = start {
+        runner.set_pc(start);
+    }
     runner.steps();
     //runner.start();
 
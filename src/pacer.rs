@@ -0,0 +1,137 @@
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How fast to run a machine against its real clock.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Speed {
+    /// Real time multiplied by this: 1 is the real machine, more is turbo
+    Scaled(u32),
+    /// As fast as the host can go
+    Uncapped,
+}
+
+// Lost time the pacer won't try to make up, after the host stalls for instance
+const MAX_LAG: Duration = Duration::from_millis(100);
+
+/// Keeps emulation in step with the host clock using the CPU's cycle count.
+pub struct Pacer {
+    clock_hz: u64,
+    speed: Speed,
+    frame_skip: u32,
+    // Host time and cycle count the pace is measured from
+    start: Instant,
+    start_cycles: u64,
+}
+
+impl Pacer {
+    pub fn new(clock_hz: u64, speed: Speed) -> Pacer {
+        Pacer {
+            clock_hz,
+            speed,
+            frame_skip: 0,
+            start: Instant::now(),
+            start_cycles: 0,
+        }
+    }
+
+    pub fn speed(&self) -> Speed {
+        self.speed
+    }
+
+    /// Changes speed from `cycles` on.
+    pub fn set_speed(&mut self, speed: Speed, cycles: u64) {
+        if speed != self.speed {
+            self.speed = speed;
+            self.resync(cycles);
+        }
+    }
+
+    /// Draws only one frame in every `skip + 1`, for hosts too slow to keep up.
+    pub fn set_frame_skip(&mut self, skip: u32) {
+        self.frame_skip = skip;
+    }
+
+    /// Measures the pace from `cycles` and now, forgetting any time gained or lost,
+    /// e.g. after a pause.
+    pub fn resync(&mut self, cycles: u64) {
+        self.start = Instant::now();
+        self.start_cycles = cycles;
+    }
+
+    /// Host time since the pace was last synced that `cycles` should take, or
+    /// `None` when uncapped.
+    pub fn target(&self, cycles: u64) -> Option<Duration> {
+        match self.speed {
+            Speed::Scaled(multiplier) => {
+                let hz = self.clock_hz * multiplier.max(1) as u64;
+                let nanos = (cycles - self.start_cycles) as u128 * 1_000_000_000 / hz as u128;
+                Some(Duration::from_nanos(nanos as u64))
+            }
+            Speed::Uncapped => None,
+        }
+    }
+
+    /// Sleeps until the host clock catches up with the machine at `cycles`. If the
+    /// host has fallen too far behind, it picks up from here instead.
+    pub fn wait(&mut self, cycles: u64) {
+        let target = match self.target(cycles) {
+            Some(target) => target,
+            None => return,
+        };
+
+        let elapsed = self.start.elapsed();
+        if let Some(early) = target.checked_sub(elapsed) {
+            thread::sleep(early);
+        } else if elapsed - target > MAX_LAG {
+            self.resync(cycles);
+        }
+    }
+
+    /// Whether frame number `frame` should be drawn.
+    pub fn draw(&self, frame: u64) -> bool {
+        frame.is_multiple_of(self.frame_skip as u64 + 1)
+    }
+
+    /// Emulated clock speed, in MHz, since the pace was last synced.
+    pub fn mhz(&self, cycles: u64) -> f64 {
+        let seconds = self.start.elapsed().as_secs_f64();
+        (cycles - self.start_cycles) as f64 / seconds / 1_000_000.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn real_time_and_turbo() {
+        let mut pacer = Pacer::new(2_000_000, Speed::Scaled(1));
+        assert_eq!(pacer.target(1_000_000), Some(Duration::from_millis(500)));
+
+        pacer.set_speed(Speed::Scaled(4), 1_000_000);
+        assert_eq!(pacer.target(3_000_000), Some(Duration::from_millis(250)));
+
+        pacer.set_speed(Speed::Uncapped, 3_000_000);
+        assert_eq!(pacer.target(100_000_000), None);
+    }
+
+    #[test]
+    fn uncapped_never_sleeps() {
+        let mut pacer = Pacer::new(2_000_000, Speed::Uncapped);
+
+        pacer.wait(u32::MAX as u64);
+
+        assert!(pacer.start.elapsed() < Duration::from_secs(1));
+        assert!(pacer.mhz(u32::MAX as u64) > 1000.0);
+    }
+
+    #[test]
+    fn frame_skip() {
+        let mut pacer = Pacer::new(2_000_000, Speed::Scaled(1));
+        assert!((0..4).all(|frame| pacer.draw(frame)));
+
+        pacer.set_frame_skip(2);
+        let drawn: Vec<u64> = (0..7).filter(|frame| pacer.draw(*frame)).collect();
+        assert_eq!(drawn, vec![0, 3, 6]);
+    }
+}
@@ -0,0 +1,314 @@
+use std::collections::BTreeSet;
+use std::error;
+use std::fmt::Write;
+
+use crate::disasm::{self, Flow};
+use crate::emulator::native::checksum;
+use crate::emulator::OPCODES;
+
+const REGISTERS: [&str; 8] = ["b", "c", "d", "e", "h", "l", "m", "a"];
+
+const CONDITIONS: [&str; 8] = [
+    "!cpu.cc.z",
+    "cpu.cc.z",
+    "!cpu.cc.cy",
+    "cpu.cc.cy",
+    "!cpu.cc.p",
+    "cpu.cc.p",
+    "!cpu.cc.s",
+    "cpu.cc.s",
+];
+
+// Instructions that write memory, after which a block stops if it wrote code
+fn writes_memory(opcode: u8) -> bool {
+    matches!(
+        opcode,
+        0x02 | 0x12 | 0x22 | 0x32 | 0x34..=0x36 | 0x70..=0x75 | 0x77 | 0xC5 | 0xD5 | 0xE3 | 0xE5 | 0xF5
+    )
+}
+
+// A recompiled instruction
+struct Instruction {
+    // The instruction as disassembled
+    comment: String,
+    cycles: u8,
+    body: Vec<String>,
+    // The block's result, if the instruction ends it
+    exit: Option<String>,
+    uses_io: bool,
+    writes: bool,
+}
+
+/// Recompiles the 8080 code in `code`, loaded at 0, that is reachable from
+/// `entries`. The result is the source of a module for `emulator::native`, with
+/// a function for each basic block and a `BLOCKS` table of them.
+pub fn recompile(code: &[u8], entries: &[usize]) -> Result<String, Box<dyn error::Error>> {
+    let found = disasm::trace(code, entries);
+
+    // Blocks start at entry points, branch targets and wherever control returns
+    // to after a branch
+    let mut leaders: BTreeSet<usize> = entries
+        .iter()
+        .copied()
+        .filter(|entry| found.contains(entry))
+        .collect();
+    for &pos in found.iter() {
+        let flow = disasm::flow(code, pos);
+        if let Flow::Jump { target, .. } | Flow::Call { target, .. } = flow {
+            if found.contains(&target) {
+                leaders.insert(target);
+            }
+        }
+        let next = pos + OPCODES[code[pos] as usize].len as usize;
+        if flow != Flow::Next && flow.falls_through() && found.contains(&next) {
+            leaders.insert(next);
+        }
+    }
+
+    let mut blocks = Vec::new();
+    for &start in leaders.iter() {
+        let mut pos = start;
+        let mut instructions = Vec::new();
+        loop {
+            let instruction = recompile_one(code, pos)?;
+            pos += OPCODES[code[pos] as usize].len as usize;
+            let ends = instruction.exit.is_some();
+            instructions.push(instruction);
+            if ends || !found.contains(&pos) || leaders.contains(&pos) {
+                break;
+            }
+        }
+        blocks.push((start, pos, instructions));
+    }
+
+    let mut source = String::new();
+    writeln!(
+        source,
+        "// Recompiled from 8080 code by `emurs recompile`. Each basic block is a function"
+    )?;
+    writeln!(source, "// returning the address to carry on from.")?;
+    writeln!(source)?;
+    writeln!(source, "use super::{{block, Block}};")?;
+    let calls_handlers = blocks
+        .iter()
+        .flat_map(|(_, _, instructions)| instructions.iter())
+        .any(|instruction| {
+            instruction
+                .body
+                .iter()
+                .any(|line| line.contains("opcodes::"))
+        });
+    if calls_handlers {
+        writeln!(source, "use crate::emulator::opcodes;")?;
+    }
+    writeln!(source, "use crate::emulator::{{Io, State}};")?;
+    writeln!(source)?;
+
+    writeln!(source, "pub static BLOCKS: [Block; {}] = [", blocks.len())?;
+    for (start, end, _) in blocks.iter() {
+        writeln!(
+            source,
+            "    block(0x{:04X}, {}, 0x{:08X}, block_{:04x}),",
+            start,
+            end - start,
+            checksum(&code[*start..*end]),
+            start
+        )?;
+    }
+    writeln!(source, "];")?;
+
+    for (start, end, instructions) in blocks.iter() {
+        let io = if instructions.iter().any(|instruction| instruction.uses_io) {
+            "io"
+        } else {
+            "_io"
+        };
+        writeln!(source)?;
+        writeln!(
+            source,
+            "fn block_{:04x}(cpu: &mut State, {}: &mut dyn Io) -> usize {{",
+            start, io
+        )?;
+
+        let mut pos = *start;
+        for (index, instruction) in instructions.iter().enumerate() {
+            pos += OPCODES[code[pos] as usize].len as usize;
+            writeln!(source, "    // {}", instruction.comment)?;
+            writeln!(source, "    cpu.cycles += {};", instruction.cycles)?;
+            for line in instruction.body.iter() {
+                writeln!(source, "    {}", line)?;
+            }
+
+            if instruction.writes && index + 1 < instructions.len() {
+                writeln!(source, "    if !cpu.written.is_empty() {{")?;
+                writeln!(source, "        return 0x{:04X};", pos & 0xFFFF)?;
+                writeln!(source, "    }}")?;
+            }
+            if let Some(exit) = &instruction.exit {
+                writeln!(source, "    {}", exit)?;
+            }
+        }
+        if instructions.last().unwrap().exit.is_none() {
+            writeln!(source, "    0x{:04X}", end & 0xFFFF)?;
+        }
+        writeln!(source, "}}")?;
+    }
+
+    Ok(source)
+}
+
+fn recompile_one(code: &[u8], pos: usize) -> Result<Instruction, Box<dyn error::Error>> {
+    let opcode = code[pos];
+    let op = &OPCODES[opcode as usize];
+    let next = (pos + op.len as usize) & 0xFFFF;
+    let byte = code.get(pos + 1).copied().unwrap_or(0);
+    let word = u16::from_le_bytes([byte, code.get(pos + 2).copied().unwrap_or(0)]);
+    let condition = CONDITIONS[(opcode >> 3) as usize & 0x07];
+    let register = REGISTERS[(opcode >> 3) as usize & 0x07];
+
+    let mut asm = String::new();
+    disasm::disasm_single(&mut asm, code, pos)?;
+    let comment = asm[7..].split_whitespace().collect::<Vec<_>>().join(" ");
+
+    let mut body = Vec::new();
+    let mut exit = None;
+    let mut uses_io = false;
+    match disasm::flow(code, pos) {
+        Flow::Next => match opcode {
+            _ if op.mnemonic == "NOP" => {}
+            0x01 | 0x11 | 0x21 => {
+                let pair = ["bc", "de", "hl"][opcode as usize >> 4];
+                body.push(format!("cpu.set_{}(0x{:04X});", pair, word));
+            }
+            0x31 => body.push(format!("cpu.sp = 0x{:04X};", word)),
+            0x22 => {
+                body.push(format!("cpu.write_byte(0x{:04X}, cpu.l);", word));
+                body.push(format!(
+                    "cpu.write_byte(0x{:04X}, cpu.h);",
+                    word.wrapping_add(1)
+                ));
+            }
+            0x2A => {
+                body.push(format!("cpu.l = cpu.mem[0x{:04X}];", word));
+                body.push(format!("cpu.h = cpu.mem[0x{:04X}];", word.wrapping_add(1)));
+            }
+            0x32 => body.push(format!("cpu.write_byte(0x{:04X}, cpu.a);", word)),
+            0x3A => body.push(format!("cpu.a = cpu.mem[0x{:04X}];", word)),
+            0x36 => body.push(format!(
+                "cpu.write_byte(cpu.hl() as usize, 0x{:02X});",
+                byte
+            )),
+            0x06 | 0x0E | 0x16 | 0x1E | 0x26 | 0x2E | 0x3E => {
+                body.push(format!("cpu.{} = 0x{:02X};", register, byte))
+            }
+            0xC6 | 0xCE | 0xD6 | 0xDE | 0xE6 | 0xEE | 0xF6 | 0xFE => {
+                let alu = ["add", "add_cy", "sub", "sub_cy", "and", "xor", "or", "cmp"];
+                body.push(format!(
+                    "cpu.{}(0x{:02X});",
+                    alu[(opcode >> 3) as usize & 0x07],
+                    byte
+                ));
+            }
+            0xD3 => {
+                body.push(format!("io.output(0x{:02X}, cpu.a);", byte));
+                uses_io = true;
+            }
+            0xDB => {
+                body.push(format!("cpu.a = io.input(0x{:02X});", byte));
+                uses_io = true;
+            }
+            _ => {
+                // Everything else is a byte long, so the interpreter's handler runs it
+                let handler = op.mnemonic.to_lowercase().replace([' ', ','], "_");
+                body.push(format!("opcodes::{}(cpu, io);", handler));
+                uses_io = true;
+            }
+        },
+        Flow::Jump {
+            conditional: false, ..
+        } => exit = Some(format!("0x{:04X}", word)),
+        Flow::Jump { .. } => {
+            body.push(format!("if {} {{", condition));
+            body.push(format!("    return 0x{:04X};", word));
+            body.push("}".to_string());
+        }
+        Flow::Call {
+            target,
+            conditional: false,
+        } => {
+            body.push(format!("cpu.push(0x{:04X});", next));
+            exit = Some(format!("0x{:04X}", target));
+        }
+        Flow::Call { .. } => {
+            body.push(format!("if {} {{", condition));
+            body.push("    cpu.cycles += 6;".to_string());
+            body.push(format!("    cpu.push(0x{:04X});", next));
+            body.push(format!("    return 0x{:04X};", word));
+            body.push("}".to_string());
+        }
+        Flow::Return { conditional: false } => exit = Some("cpu.pop() as usize".to_string()),
+        Flow::Return { .. } => {
+            body.push(format!("if {} {{", condition));
+            body.push("    cpu.cycles += 6;".to_string());
+            body.push("    return cpu.pop() as usize;".to_string());
+            body.push("}".to_string());
+        }
+        Flow::Indirect => exit = Some("cpu.hl() as usize".to_string()),
+        Flow::Halt => {
+            body.push("cpu.halted = true;".to_string());
+            exit = Some(format!("0x{:04X}", next));
+        }
+    }
+
+    // Conditional branches end the block too, carrying on with the next one
+    if exit.is_none() && disasm::flow(code, pos) != Flow::Next {
+        exit = Some(format!("0x{:04X}", next));
+    }
+
+    Ok(Instruction {
+        comment,
+        cycles: op.cycles,
+        body,
+        exit,
+        uses_io,
+        writes: writes_memory(opcode),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::Path;
+
+    // The checked-in Space Invaders module is what the recompiler writes today
+    #[test]
+    fn invaders_is_up_to_date() {
+        let rom_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("games/invaders");
+        let mut code = Vec::new();
+        for name in ["invaders.h", "invaders.g", "invaders.f", "invaders.e"] {
+            code.extend(fs::read(rom_dir.join(name)).unwrap());
+        }
+        let entries: Vec<usize> = (0..8).map(|vector| vector * 8).collect();
+
+        assert_eq!(
+            recompile(&code, &entries).unwrap(),
+            include_str!("emulator/native/invaders.rs")
+        );
+    }
+
+    #[test]
+    fn blocks_end_at_branches_and_targets() {
+        // 0000 MVI A,1  0002 DCR A  0003 JNZ 0002  0006 MOV M,A  0007 INR B  0008 PCHL
+        let code = [0x3E, 0x01, 0x3D, 0xC2, 0x02, 0x00, 0x77, 0x04, 0xE9];
+        let source = recompile(&code, &[0]).unwrap();
+
+        assert!(source.contains("pub static BLOCKS: [Block; 3]"));
+        assert!(source.contains("block(0x0000, 2, "));
+        assert!(source.contains("block(0x0002, 4, "));
+        assert!(source.contains("block(0x0006, 3, "));
+        assert!(source.contains("    if !cpu.cc.z {\n        return 0x0002;\n    }\n    0x0006\n"));
+        assert!(source.contains("opcodes::mov_m_a(cpu, io);\n    if !cpu.written.is_empty() {"));
+        assert!(source.contains("    cpu.hl() as usize\n}"));
+    }
+}
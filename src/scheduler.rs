@@ -0,0 +1,185 @@
+/// Something driven by a CPU clock: a machine the scheduler can run.
+pub trait Clocked {
+    fn cycles(&self) -> u64;
+    /// Runs one instruction or block of them, or idles for a few cycles.
+    fn step(&mut self);
+}
+
+/// A device callback, given the cycle it was scheduled for.
+pub type Callback<M> = fn(&mut M, &mut Scheduler<M>, u64);
+
+struct Event<M> {
+    cycle: u64,
+    // Keeps events for the same cycle in the order they were scheduled
+    order: u64,
+    callback: Callback<M>,
+}
+
+/// Device events for machine `M`, keyed on the CPU cycle they're due.
+pub struct Scheduler<M> {
+    // Latest first, so the next event is at the end
+    events: Vec<Event<M>>,
+    scheduled: u64,
+}
+
+impl<M> Default for Scheduler<M> {
+    fn default() -> Self {
+        Scheduler {
+            events: Vec::new(),
+            scheduled: 0,
+        }
+    }
+}
+
+impl<M: Clocked> Scheduler<M> {
+    pub fn new() -> Scheduler<M> {
+        Self::default()
+    }
+
+    /// Runs `callback` once the CPU reaches `cycle`.
+    pub fn schedule(&mut self, cycle: u64, callback: Callback<M>) {
+        let order = self.scheduled;
+        self.scheduled += 1;
+
+        let index = self
+            .events
+            .partition_point(|event| (event.cycle, event.order) > (cycle, order));
+        self.events.insert(
+            index,
+            Event {
+                cycle,
+                order,
+                callback,
+            },
+        );
+    }
+
+    /// Cycle of the next event, if there is one.
+    pub fn next_event(&self) -> Option<u64> {
+        self.events.last().map(|event| event.cycle)
+    }
+
+    pub fn clear(&mut self) {
+        self.events.clear();
+    }
+
+    /// Runs `machine` until its CPU reaches `cycle`, stopping between instructions
+    /// to run each event as it falls due. Events due once it gets there are left for
+    /// the next run.
+    pub fn run_until(&mut self, machine: &mut M, cycle: u64) {
+        loop {
+            // A step can carry the CPU past both an event and `cycle`
+            while let Some(event) = self.pop_due(machine.cycles(), cycle) {
+                (event.callback)(machine, self, event.cycle);
+            }
+            if machine.cycles() >= cycle {
+                return;
+            }
+
+            let target = self.next_event().map_or(cycle, |next| next.min(cycle));
+            while machine.cycles() < target {
+                machine.step();
+            }
+        }
+    }
+
+    // The next event due by `now` and before `end`
+    fn pop_due(&mut self, now: u64, end: u64) -> Option<Event<M>> {
+        match self.events.last() {
+            Some(event) if event.cycle <= now && event.cycle < end => self.events.pop(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Three cycles an instruction, logging each event with the cycle it ran on
+    #[derive(Default)]
+    struct Machine {
+        cycles: u64,
+        log: Vec<(&'static str, u64, u64)>,
+    }
+
+    impl Clocked for Machine {
+        fn cycles(&self) -> u64 {
+            self.cycles
+        }
+
+        fn step(&mut self) {
+            self.cycles += 3;
+        }
+    }
+
+    fn timer(machine: &mut Machine, scheduler: &mut Scheduler<Machine>, cycle: u64) {
+        machine.log.push(("timer", cycle, machine.cycles));
+        scheduler.schedule(cycle + 10, timer);
+    }
+
+    fn first(machine: &mut Machine, _: &mut Scheduler<Machine>, cycle: u64) {
+        machine.log.push(("first", cycle, machine.cycles));
+    }
+
+    fn second(machine: &mut Machine, _: &mut Scheduler<Machine>, cycle: u64) {
+        machine.log.push(("second", cycle, machine.cycles));
+    }
+
+    #[test]
+    fn periodic_timer() {
+        let mut machine = Machine::default();
+        let mut scheduler = Scheduler::new();
+        scheduler.schedule(5, timer);
+
+        scheduler.run_until(&mut machine, 30);
+
+        // Each runs on the first instruction boundary at or after its cycle
+        assert_eq!(
+            machine.log,
+            vec![("timer", 5, 6), ("timer", 15, 15), ("timer", 25, 27)]
+        );
+        assert_eq!(machine.cycles, 30);
+        assert_eq!(scheduler.next_event(), Some(35));
+    }
+
+    #[test]
+    fn same_cycle_in_order() {
+        let mut machine = Machine::default();
+        let mut scheduler = Scheduler::new();
+        scheduler.schedule(9, second);
+        scheduler.schedule(3, first);
+        scheduler.schedule(9, first);
+
+        scheduler.run_until(&mut machine, 12);
+
+        assert_eq!(
+            machine.log,
+            vec![("first", 3, 3), ("second", 9, 9), ("first", 9, 9)]
+        );
+    }
+
+    #[test]
+    fn events_at_the_end_wait() {
+        let mut machine = Machine::default();
+        let mut scheduler = Scheduler::new();
+        scheduler.schedule(6, first);
+
+        scheduler.run_until(&mut machine, 6);
+        assert!(machine.log.is_empty());
+
+        scheduler.run_until(&mut machine, 9);
+        assert_eq!(machine.log, vec![("first", 6, 6)]);
+    }
+
+    #[test]
+    fn events_passed_on_the_last_step_run() {
+        let mut machine = Machine::default();
+        let mut scheduler = Scheduler::new();
+        scheduler.schedule(4, first);
+
+        scheduler.run_until(&mut machine, 5);
+
+        assert_eq!(machine.log, vec![("first", 4, 6)]);
+    }
+}
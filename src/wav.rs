@@ -0,0 +1,128 @@
+use std::convert::TryInto;
+use std::error;
+use std::fs;
+use std::path::Path;
+
+/// Encodes mono 16-bit PCM `samples` as a WAV file.
+pub fn encode(samples: &[i16], sample_rate: u32) -> Vec<u8> {
+    let data_len = (samples.len() * 2) as u32;
+    let mut out = Vec::with_capacity(44 + data_len as usize);
+
+    out.extend_from_slice(b"RIFF");
+    out.extend_from_slice(&(36 + data_len).to_le_bytes());
+    out.extend_from_slice(b"WAVE");
+
+    out.extend_from_slice(b"fmt ");
+    out.extend_from_slice(&16u32.to_le_bytes());
+    out.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    out.extend_from_slice(&1u16.to_le_bytes()); // Mono
+    out.extend_from_slice(&sample_rate.to_le_bytes());
+    out.extend_from_slice(&(sample_rate * 2).to_le_bytes());
+    out.extend_from_slice(&2u16.to_le_bytes());
+    out.extend_from_slice(&16u16.to_le_bytes());
+
+    out.extend_from_slice(b"data");
+    out.extend_from_slice(&data_len.to_le_bytes());
+    for sample in samples.iter() {
+        out.extend_from_slice(&sample.to_le_bytes());
+    }
+
+    out
+}
+
+pub fn write(path: &Path, samples: &[i16], sample_rate: u32) -> Result<(), Box<dyn error::Error>> {
+    fs::write(path, encode(samples, sample_rate))?;
+    Ok(())
+}
+
+/// Decodes an 8 or 16-bit PCM WAV file, mixing multiple channels down to mono.
+/// Returns the samples and their sample rate.
+pub fn decode(bytes: &[u8]) -> Result<(Vec<i16>, u32), Box<dyn error::Error>> {
+    if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        return Err("Not a WAV file".into());
+    }
+
+    let mut format = None;
+    let mut pos = 12;
+    while pos + 8 <= bytes.len() {
+        let id = &bytes[pos..pos + 4];
+        let len = u32::from_le_bytes(bytes[pos + 4..pos + 8].try_into()?) as usize;
+        let body = bytes
+            .get(pos + 8..pos + 8 + len)
+            .ok_or("Truncated WAV chunk")?;
+
+        match id {
+            b"fmt " if len >= 16 => {
+                let tag = u16::from_le_bytes(body[0..2].try_into()?);
+                let channels = u16::from_le_bytes(body[2..4].try_into()?) as usize;
+                let rate = u32::from_le_bytes(body[4..8].try_into()?);
+                let bits = u16::from_le_bytes(body[14..16].try_into()?);
+                if tag != 1 || channels == 0 || (bits != 8 && bits != 16) {
+                    return Err("Only 8 and 16-bit PCM WAV files are supported".into());
+                }
+                if rate == 0 {
+                    return Err("WAV sample rate is 0".into());
+                }
+                format = Some((channels, rate, bits));
+            }
+            b"data" => {
+                let (channels, rate, bits) = format.ok_or("WAV data before its format")?;
+                let frame_len = channels * bits as usize / 8;
+
+                let samples = body
+                    .chunks_exact(frame_len)
+                    .map(|frame| {
+                        let sum: i32 = if bits == 8 {
+                            frame.iter().map(|b| (*b as i32 - 0x80) << 8).sum()
+                        } else {
+                            frame
+                                .chunks_exact(2)
+                                .map(|s| i16::from_le_bytes([s[0], s[1]]) as i32)
+                                .sum()
+                        };
+                        (sum / channels as i32) as i16
+                    })
+                    .collect();
+
+                return Ok((samples, rate));
+            }
+            _ => {}
+        }
+
+        // Chunks are padded to an even length
+        pos += 8 + len + (len & 1);
+    }
+
+    Err("WAV file has no data".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let samples = [0, 1, -1, i16::MAX, i16::MIN, 1234];
+
+        let (decoded, rate) = decode(&encode(&samples, 22050)).unwrap();
+
+        assert_eq!(decoded, samples);
+        assert_eq!(rate, 22050);
+    }
+
+    #[test]
+    fn zero_sample_rate() {
+        let bytes = encode(&[0; 10], 0);
+
+        assert!(decode(&bytes).is_err());
+    }
+
+    #[test]
+    fn header() {
+        let bytes = encode(&[0; 10], 44100);
+
+        assert_eq!(bytes.len(), 64);
+        assert_eq!(&bytes[0..4], b"RIFF");
+        assert_eq!(u32::from_le_bytes(bytes[40..44].try_into().unwrap()), 20);
+    }
+}
@@ -0,0 +1,1435 @@
+use std::error;
+use std::ops::Range;
+
+use crate::cpu::{Cpu, Registers, StateReader};
+use crate::emulator::{Io, Status, Unconnected};
+
+// Flag bits in F. X and Y are undocumented copies of bits 3 and 5 of a result.
+pub const SIGN: u8 = 0x80;
+pub const ZERO: u8 = 0x40;
+pub const Y: u8 = 0x20;
+pub const HALF_CARRY: u8 = 0x10;
+pub const X: u8 = 0x08;
+pub const PARITY: u8 = 0x04;
+pub const SUBTRACT: u8 = 0x02;
+pub const CARRY: u8 = 0x01;
+
+// Clock cycles used by each step while halted, running NOPs
+const HALT_CYCLES: u64 = 4;
+
+// What HL means in an instruction, after a DD or FD prefix
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Index {
+    HL,
+    IX,
+    IY,
+}
+
+/// A Zilog Z80: the 8080's registers and instructions, plus the alternate register
+/// set, IX and IY, and the CB, DD, ED and FD prefixed instructions.
+pub struct Z80<'a> {
+    a: u8,
+    f: u8,
+    b: u8,
+    c: u8,
+    d: u8,
+    e: u8,
+    h: u8,
+    l: u8,
+    // AF', BC', DE' and HL'
+    alternate: [u16; 4],
+    ix: u16,
+    iy: u16,
+    sp: u16,
+    pc: u16,
+    i: u8,
+    r: u8,
+    // Internal address latch (MEMPTR), which shows through in BIT n,(HL)'s flags
+    wz: u16,
+
+    iff1: bool,
+    iff2: bool,
+    im: u8,
+    halted: bool,
+    // Set by EI, which holds off maskable interrupts until the next instruction
+    // has run, so that EI; RETI returns before another interrupt comes in
+    ei_delay: bool,
+    // A maskable interrupt requested during that delay, taken once it's over
+    pending: Option<Vec<u8>>,
+
+    mem: &'a mut [u8],
+    read_only: Vec<Range<usize>>,
+    // Instruction placed on the data bus by an interrupting device in mode 0
+    bus: Option<Vec<u8>>,
+    cycles: u64,
+}
+
+impl Z80<'_> {
+    /// A Z80 as it comes out of reset, in interrupt mode 0 with interrupts off.
+    pub fn new(mem: &mut [u8]) -> Z80<'_> {
+        Z80 {
+            a: 0xFF,
+            f: 0xFF,
+            b: 0,
+            c: 0,
+            d: 0,
+            e: 0,
+            h: 0,
+            l: 0,
+            alternate: [0; 4],
+            ix: 0xFFFF,
+            iy: 0xFFFF,
+            sp: 0xFFFF,
+            pc: 0,
+            i: 0,
+            r: 0,
+            wz: 0,
+
+            iff1: false,
+            iff2: false,
+            im: 0,
+            halted: false,
+            ei_delay: false,
+            pending: None,
+
+            mem,
+            read_only: Vec::new(),
+            bus: None,
+            cycles: 0,
+        }
+    }
+
+    pub fn a(&self) -> u8 {
+        self.a
+    }
+
+    pub fn set_a(&mut self, value: u8) {
+        self.a = value;
+    }
+
+    pub fn flags(&self) -> u8 {
+        self.f
+    }
+
+    pub fn set_flags(&mut self, value: u8) {
+        self.f = value;
+    }
+
+    pub fn af(&self) -> u16 {
+        u16::from_be_bytes([self.a, self.f])
+    }
+
+    pub fn set_af(&mut self, value: u16) {
+        let [a, f] = value.to_be_bytes();
+        self.a = a;
+        self.f = f;
+    }
+
+    pub fn bc(&self) -> u16 {
+        u16::from_be_bytes([self.b, self.c])
+    }
+
+    pub fn set_bc(&mut self, value: u16) {
+        let [b, c] = value.to_be_bytes();
+        self.b = b;
+        self.c = c;
+    }
+
+    pub fn de(&self) -> u16 {
+        u16::from_be_bytes([self.d, self.e])
+    }
+
+    pub fn set_de(&mut self, value: u16) {
+        let [d, e] = value.to_be_bytes();
+        self.d = d;
+        self.e = e;
+    }
+
+    pub fn hl(&self) -> u16 {
+        u16::from_be_bytes([self.h, self.l])
+    }
+
+    pub fn set_hl(&mut self, value: u16) {
+        let [h, l] = value.to_be_bytes();
+        self.h = h;
+        self.l = l;
+    }
+
+    pub fn ix(&self) -> u16 {
+        self.ix
+    }
+
+    pub fn set_ix(&mut self, value: u16) {
+        self.ix = value;
+    }
+
+    pub fn iy(&self) -> u16 {
+        self.iy
+    }
+
+    pub fn set_iy(&mut self, value: u16) {
+        self.iy = value;
+    }
+
+    pub fn sp(&self) -> u16 {
+        self.sp
+    }
+
+    pub fn set_sp(&mut self, value: u16) {
+        self.sp = value;
+    }
+
+    pub fn pc(&self) -> u16 {
+        self.pc
+    }
+
+    pub fn set_pc(&mut self, value: u16) {
+        self.pc = value;
+    }
+
+    /// AF', BC', DE' and HL'.
+    pub fn alternate(&self) -> [u16; 4] {
+        self.alternate
+    }
+
+    pub fn set_alternate(&mut self, registers: [u16; 4]) {
+        self.alternate = registers;
+    }
+
+    pub fn i(&self) -> u8 {
+        self.i
+    }
+
+    pub fn set_i(&mut self, value: u8) {
+        self.i = value;
+    }
+
+    pub fn r(&self) -> u8 {
+        self.r
+    }
+
+    pub fn interrupt_mode(&self) -> u8 {
+        self.im
+    }
+
+    pub fn memory(&self) -> &[u8] {
+        self.mem
+    }
+
+    /// Memory for the host to write directly, ignoring write protection.
+    pub fn memory_mut(&mut self) -> &mut [u8] {
+        self.mem
+    }
+
+    /// Ignores writes to `range`, as for ROM or unpopulated address space.
+    pub fn protect(&mut self, range: Range<usize>) {
+        self.read_only.push(range);
+    }
+
+    /// Pulls the RESET line: jumps to 0 in interrupt mode 0 with interrupts off,
+    /// leaving the other registers, memory and the cycle count alone.
+    pub fn reset(&mut self) {
+        self.pc = 0;
+        self.i = 0;
+        self.r = 0;
+        self.iff1 = false;
+        self.iff2 = false;
+        self.im = 0;
+        self.halted = false;
+        self.ei_delay = false;
+        self.pending = None;
+        self.bus = None;
+    }
+
+    pub fn cycles(&self) -> u64 {
+        self.cycles
+    }
+
+    pub fn status(&self) -> Status {
+        if !self.halted {
+            Status::Running
+        } else if self.iff1 {
+            Status::Halted
+        } else {
+            Status::Deadlock
+        }
+    }
+
+    /// Runs until the CPU halts, with nothing to interrupt it.
+    pub fn start(&mut self) -> Status {
+        loop {
+            let status = self.step();
+            if status != Status::Running {
+                return status;
+            }
+        }
+    }
+
+    /// Requests a maskable interrupt with an RST to `vector` on the data bus.
+    pub fn interrupt(&mut self, vector: u8) {
+        self.interrupt_with(&[0xC7 | (vector & 0x07) << 3]);
+    }
+
+    /// Requests a maskable interrupt with `data` on the bus during acknowledge. Mode 0
+    /// executes it as an instruction, mode 1 ignores it and calls 0x0038, and mode 2
+    /// calls through the vector table entry at I and its first byte. Right after EI
+    /// the request waits until the next instruction has run.
+    pub fn interrupt_with(&mut self, data: &[u8]) {
+        assert!(
+            (1..=4).contains(&data.len()),
+            "A Z80 instruction is 1 to 4 bytes"
+        );
+        if !self.iff1 {
+            return;
+        }
+        if self.ei_delay {
+            self.pending = Some(data.to_vec());
+            return;
+        }
+
+        self.iff1 = false;
+        self.iff2 = false;
+        self.halted = false;
+
+        match self.im {
+            0 => {
+                // Fetching the instruction from the bus is the acknowledge's M1
+                // cycle, which increments R
+                self.bus = Some(data.to_vec());
+                // Acknowledge takes two more cycles than a fetch
+                self.cycles += 2;
+                self.step_io(&mut Unconnected);
+                self.bus = None;
+            }
+            1 => {
+                self.increment_r();
+                self.call(0x0038);
+                self.cycles += 13;
+            }
+            _ => {
+                self.increment_r();
+                let entry = u16::from_be_bytes([self.i, data[0]]);
+                let target = self.read_word(entry);
+                self.call(target);
+                self.cycles += 19;
+            }
+        }
+    }
+
+    /// Raises the non-maskable interrupt, calling 0x0066.
+    pub fn nmi(&mut self) {
+        self.iff1 = false;
+        self.halted = false;
+        self.ei_delay = false;
+        self.pending = None;
+        self.increment_r();
+        self.call(0x0066);
+        self.cycles += 11;
+    }
+
+    pub fn step(&mut self) -> Status {
+        self.step_io(&mut Unconnected)
+    }
+
+    /// Executes one instruction, or idles for a few cycles while halted.
+    pub fn step_io(&mut self, io: &mut dyn Io) -> Status {
+        if self.halted {
+            self.increment_r();
+            self.cycles += HALT_CYCLES;
+            return self.status();
+        }
+
+        let delayed = std::mem::take(&mut self.ei_delay);
+        let mut index = Index::HL;
+        let mut opcode = self.fetch_opcode();
+        // Only the last of a run of DD and FD prefixes counts
+        while opcode == 0xDD || opcode == 0xFD {
+            index = if opcode == 0xDD { Index::IX } else { Index::IY };
+            self.cycles += 4;
+            opcode = self.fetch_opcode();
+        }
+
+        match opcode {
+            0xCB if index == Index::HL => {
+                let opcode = self.fetch_opcode();
+                self.execute_cb(opcode);
+            }
+            0xCB => self.execute_index_cb(index),
+            0xED => {
+                let opcode = self.fetch_opcode();
+                self.execute_ed(io, opcode);
+            }
+            _ => self.execute(io, opcode, index),
+        }
+
+        if delayed {
+            if let Some(data) = self.pending.take() {
+                self.interrupt_with(&data);
+            }
+        }
+        self.status()
+    }
+
+    fn increment_r(&mut self) {
+        self.r = (self.r & 0x80) | (self.r.wrapping_add(1) & 0x7F);
+    }
+
+    fn fetch_opcode(&mut self) -> u8 {
+        self.increment_r();
+        self.fetch()
+    }
+
+    fn fetch(&mut self) -> u8 {
+        if let Some(bus) = &mut self.bus {
+            if !bus.is_empty() {
+                return bus.remove(0);
+            }
+        }
+
+        let byte = self.read(self.pc);
+        self.pc = self.pc.wrapping_add(1);
+        byte
+    }
+
+    fn fetch_word(&mut self) -> u16 {
+        u16::from_le_bytes([self.fetch(), self.fetch()])
+    }
+
+    fn read(&self, address: u16) -> u8 {
+        self.mem.get(address as usize).copied().unwrap_or(0xFF)
+    }
+
+    fn write(&mut self, address: u16, value: u8) {
+        let offset = address as usize;
+        if offset < self.mem.len() && !self.read_only.iter().any(|range| range.contains(&offset)) {
+            self.mem[offset] = value;
+        }
+    }
+
+    fn read_word(&self, address: u16) -> u16 {
+        u16::from_le_bytes([self.read(address), self.read(address.wrapping_add(1))])
+    }
+
+    fn write_word(&mut self, address: u16, value: u16) {
+        let [low, high] = value.to_le_bytes();
+        self.write(address, low);
+        self.write(address.wrapping_add(1), high);
+    }
+
+    fn push(&mut self, value: u16) {
+        self.sp = self.sp.wrapping_sub(2);
+        self.write_word(self.sp, value);
+    }
+
+    fn pop(&mut self) -> u16 {
+        let value = self.read_word(self.sp);
+        self.sp = self.sp.wrapping_add(2);
+        value
+    }
+
+    fn call(&mut self, address: u16) {
+        self.push(self.pc);
+        self.pc = address;
+        self.wz = address;
+    }
+
+    fn ret(&mut self) {
+        self.pc = self.pop();
+        self.wz = self.pc;
+    }
+
+    fn index_register(&self, index: Index) -> u16 {
+        match index {
+            Index::HL => self.hl(),
+            Index::IX => self.ix,
+            Index::IY => self.iy,
+        }
+    }
+
+    fn set_index_register(&mut self, index: Index, value: u16) {
+        match index {
+            Index::HL => self.set_hl(value),
+            Index::IX => self.ix = value,
+            Index::IY => self.iy = value,
+        }
+    }
+
+    // Address of the (HL) operand, or (IX+d) with its displacement fetched
+    fn operand_address(&mut self, index: Index) -> u16 {
+        match index {
+            Index::HL => self.hl(),
+            _ => {
+                let displacement = self.fetch() as i8 as u16;
+                self.wz = self.index_register(index).wrapping_add(displacement);
+                self.cycles += 8;
+                self.wz
+            }
+        }
+    }
+
+    // Registers by their 3-bit code, B C D E H L - A, with H and L as the halves of
+    // IX or IY after a prefix. Code 6, the memory operand, is handled by callers.
+    fn register(&self, code: u8, index: Index) -> u8 {
+        match code {
+            0 => self.b,
+            1 => self.c,
+            2 => self.d,
+            3 => self.e,
+            4 => (self.index_register(index) >> 8) as u8,
+            5 => self.index_register(index) as u8,
+            _ => self.a,
+        }
+    }
+
+    fn set_register(&mut self, code: u8, index: Index, value: u8) {
+        match code {
+            0 => self.b = value,
+            1 => self.c = value,
+            2 => self.d = value,
+            3 => self.e = value,
+            4 => {
+                let low = self.index_register(index) & 0xFF;
+                self.set_index_register(index, (value as u16) << 8 | low);
+            }
+            5 => {
+                let high = self.index_register(index) & 0xFF00;
+                self.set_index_register(index, high | value as u16);
+            }
+            _ => self.a = value,
+        }
+    }
+
+    // Register pairs by their 2-bit code: BC DE HL SP, or BC DE HL AF for PUSH and POP
+    fn pair(&self, code: u8, index: Index, af: bool) -> u16 {
+        match code {
+            0 => self.bc(),
+            1 => self.de(),
+            2 => self.index_register(index),
+            _ if af => self.af(),
+            _ => self.sp,
+        }
+    }
+
+    fn set_pair(&mut self, code: u8, index: Index, af: bool, value: u16) {
+        match code {
+            0 => self.set_bc(value),
+            1 => self.set_de(value),
+            2 => self.set_index_register(index, value),
+            _ if af => self.set_af(value),
+            _ => self.sp = value,
+        }
+    }
+
+    // Conditions by their 3-bit code: NZ Z NC C PO PE P M
+    fn condition(&self, code: u8) -> bool {
+        let flag = [ZERO, CARRY, PARITY, SIGN][code as usize >> 1];
+        (self.f & flag != 0) == (code & 1 != 0)
+    }
+
+    fn execute(&mut self, io: &mut dyn Io, opcode: u8, index: Index) {
+        let (x, y, z) = (opcode >> 6, (opcode >> 3) & 7, opcode & 7);
+        let (p, q) = (y >> 1, y & 1);
+
+        match (x, z) {
+            (0, 0) => match y {
+                0 => self.cycles += 4, // NOP
+                1 => {
+                    // EX AF,AF'
+                    let af = self.af();
+                    self.set_af(self.alternate[0]);
+                    self.alternate[0] = af;
+                    self.cycles += 4;
+                }
+                2 => {
+                    // DJNZ
+                    self.b = self.b.wrapping_sub(1);
+                    self.jump_relative(self.b != 0, 8);
+                }
+                3 => self.jump_relative(true, 7), // JR
+                _ => self.jump_relative(self.condition(y - 4), 7), // JR cc
+            },
+            (0, 1) if q == 0 => {
+                // LD rr,nn
+                let value = self.fetch_word();
+                self.set_pair(p, index, false, value);
+                self.cycles += 10;
+            }
+            (0, 1) => {
+                // ADD HL,rr
+                let value = self.pair(p, index, false);
+                let result = self.add16(self.index_register(index), value);
+                self.set_index_register(index, result);
+                self.cycles += 11;
+            }
+            (0, 2) => self.load_indirect(p, q, index),
+            (0, 3) => {
+                // INC rr, DEC rr
+                let value = self.pair(p, index, false);
+                let value = if q == 0 {
+                    value.wrapping_add(1)
+                } else {
+                    value.wrapping_sub(1)
+                };
+                self.set_pair(p, index, false, value);
+                self.cycles += 6;
+            }
+            (0, 4) | (0, 5) => {
+                // INC r, DEC r
+                let update = |cpu: &mut Self, value| {
+                    if z == 4 {
+                        cpu.inc8(value)
+                    } else {
+                        cpu.dec8(value)
+                    }
+                };
+                if y == 6 {
+                    let address = self.operand_address(index);
+                    let value = update(self, self.read(address));
+                    self.write(address, value);
+                    self.cycles += 11;
+                } else {
+                    let value = update(self, self.register(y, index));
+                    self.set_register(y, index, value);
+                    self.cycles += 4;
+                }
+            }
+            (0, 6) => {
+                // LD r,n
+                if y == 6 {
+                    let address = self.operand_address(index);
+                    let value = self.fetch();
+                    self.write(address, value);
+                    // The displacement and data are fetched together
+                    self.cycles += if index == Index::HL { 10 } else { 7 };
+                } else {
+                    let value = self.fetch();
+                    self.set_register(y, index, value);
+                    self.cycles += 7;
+                }
+            }
+            (0, 7) => {
+                self.accumulator_op(y);
+                self.cycles += 4;
+            }
+            (1, 6) if y == 6 => {
+                // HALT
+                self.halted = true;
+                self.cycles += 4;
+            }
+            (1, _) => {
+                // LD r,r'. With a memory operand, H and L are never IX's halves.
+                if y == 6 {
+                    let address = self.operand_address(index);
+                    self.write(address, self.register(z, Index::HL));
+                    self.cycles += 7;
+                } else if z == 6 {
+                    let address = self.operand_address(index);
+                    let value = self.read(address);
+                    self.set_register(y, Index::HL, value);
+                    self.cycles += 7;
+                } else {
+                    let value = self.register(z, index);
+                    self.set_register(y, index, value);
+                    self.cycles += 4;
+                }
+            }
+            (2, _) => {
+                // ALU A,r
+                let value = if z == 6 {
+                    let address = self.operand_address(index);
+                    self.cycles += 3;
+                    self.read(address)
+                } else {
+                    self.register(z, index)
+                };
+                self.alu(y, value);
+                self.cycles += 4;
+            }
+            (3, 0) => {
+                // RET cc
+                if self.condition(y) {
+                    self.ret();
+                    self.cycles += 11;
+                } else {
+                    self.cycles += 5;
+                }
+            }
+            (3, 1) if q == 0 => {
+                // POP rr
+                let value = self.pop();
+                self.set_pair(p, index, true, value);
+                self.cycles += 10;
+            }
+            (3, 1) => match p {
+                0 => {
+                    self.ret();
+                    self.cycles += 10;
+                }
+                1 => {
+                    // EXX
+                    let pairs = [self.bc(), self.de(), self.hl()];
+                    self.set_bc(self.alternate[1]);
+                    self.set_de(self.alternate[2]);
+                    self.set_hl(self.alternate[3]);
+                    self.alternate[1..].copy_from_slice(&pairs);
+                    self.cycles += 4;
+                }
+                2 => {
+                    // JP (HL)
+                    self.pc = self.index_register(index);
+                    self.cycles += 4;
+                }
+                _ => {
+                    // LD SP,HL
+                    self.sp = self.index_register(index);
+                    self.cycles += 6;
+                }
+            },
+            (3, 2) => {
+                // JP cc,nn
+                let address = self.fetch_word();
+                self.wz = address;
+                if self.condition(y) {
+                    self.pc = address;
+                }
+                self.cycles += 10;
+            }
+            (3, 3) => match y {
+                0 => {
+                    // JP nn
+                    self.pc = self.fetch_word();
+                    self.wz = self.pc;
+                    self.cycles += 10;
+                }
+                2 => {
+                    // OUT (n),A
+                    let port = self.fetch();
+                    io.output(port, self.a);
+                    self.wz = u16::from_be_bytes([self.a, port.wrapping_add(1)]);
+                    self.cycles += 11;
+                }
+                3 => {
+                    // IN A,(n)
+                    let port = self.fetch();
+                    self.wz = u16::from_be_bytes([self.a, port]).wrapping_add(1);
+                    self.a = io.input(port);
+                    self.cycles += 11;
+                }
+                4 => {
+                    // EX (SP),HL
+                    let value = self.read_word(self.sp);
+                    self.write_word(self.sp, self.index_register(index));
+                    self.set_index_register(index, value);
+                    self.wz = value;
+                    self.cycles += 19;
+                }
+                5 => {
+                    // EX DE,HL, which ignores the prefixes
+                    let de = self.de();
+                    self.set_de(self.hl());
+                    self.set_hl(de);
+                    self.cycles += 4;
+                }
+                6 => {
+                    // DI
+                    self.iff1 = false;
+                    self.iff2 = false;
+                    self.cycles += 4;
+                }
+                _ => {
+                    // EI
+                    self.iff1 = true;
+                    self.iff2 = true;
+                    self.ei_delay = true;
+                    self.cycles += 4;
+                }
+            },
+            (3, 4) => {
+                // CALL cc,nn
+                let address = self.fetch_word();
+                self.wz = address;
+                if self.condition(y) {
+                    self.call(address);
+                    self.cycles += 17;
+                } else {
+                    self.cycles += 10;
+                }
+            }
+            (3, 5) if q == 0 => {
+                // PUSH rr
+                self.push(self.pair(p, index, true));
+                self.cycles += 11;
+            }
+            (3, 5) => {
+                // CALL nn, the only one left once the prefixes are decoded
+                let address = self.fetch_word();
+                self.call(address);
+                self.cycles += 17;
+            }
+            (3, 6) => {
+                // ALU A,n
+                let value = self.fetch();
+                self.alu(y, value);
+                self.cycles += 7;
+            }
+            _ => {
+                // RST
+                self.call(y as u16 * 8);
+                self.cycles += 11;
+            }
+        }
+    }
+
+    fn jump_relative(&mut self, taken: bool, cycles: u64) {
+        let offset = self.fetch() as i8 as u16;
+        if taken {
+            self.pc = self.pc.wrapping_add(offset);
+            self.wz = self.pc;
+            self.cycles += cycles + 5;
+        } else {
+            self.cycles += cycles;
+        }
+    }
+
+    // LD (BC),A  LD (DE),A  LD (nn),HL  LD (nn),A and the loads the other way
+    fn load_indirect(&mut self, p: u8, q: u8, index: Index) {
+        match (p, q) {
+            (0, 0) | (1, 0) => {
+                let address = self.pair(p, index, false);
+                self.write(address, self.a);
+                self.wz = u16::from_be_bytes([self.a, (address as u8).wrapping_add(1)]);
+                self.cycles += 7;
+            }
+            (0, 1) | (1, 1) => {
+                let address = self.pair(p, index, false);
+                self.a = self.read(address);
+                self.wz = address.wrapping_add(1);
+                self.cycles += 7;
+            }
+            (2, 0) => {
+                let address = self.fetch_word();
+                self.write_word(address, self.index_register(index));
+                self.wz = address.wrapping_add(1);
+                self.cycles += 16;
+            }
+            (2, 1) => {
+                let address = self.fetch_word();
+                let value = self.read_word(address);
+                self.set_index_register(index, value);
+                self.wz = address.wrapping_add(1);
+                self.cycles += 16;
+            }
+            (3, 0) => {
+                let address = self.fetch_word();
+                self.write(address, self.a);
+                self.wz = u16::from_be_bytes([self.a, (address as u8).wrapping_add(1)]);
+                self.cycles += 13;
+            }
+            _ => {
+                let address = self.fetch_word();
+                self.a = self.read(address);
+                self.wz = address.wrapping_add(1);
+                self.cycles += 13;
+            }
+        }
+    }
+
+    // RLCA RRCA RLA RRA DAA CPL SCF CCF
+    fn accumulator_op(&mut self, op: u8) {
+        let kept = self.f & (SIGN | ZERO | PARITY);
+        let carry = self.f & CARRY;
+
+        match op {
+            0 => {
+                self.a = self.a.rotate_left(1);
+                self.f = kept | (self.a & (X | Y | CARRY));
+            }
+            1 => {
+                self.f = kept | (self.a & CARRY);
+                self.a = self.a.rotate_right(1);
+                self.f |= self.a & (X | Y);
+            }
+            2 => {
+                let out = self.a >> 7;
+                self.a = self.a << 1 | carry;
+                self.f = kept | (self.a & (X | Y)) | out;
+            }
+            3 => {
+                let out = self.a & 1;
+                self.a = self.a >> 1 | carry << 7;
+                self.f = kept | (self.a & (X | Y)) | out;
+            }
+            4 => self.daa(),
+            5 => {
+                self.a = !self.a;
+                self.f = (self.f & (SIGN | ZERO | PARITY | CARRY))
+                    | HALF_CARRY
+                    | SUBTRACT
+                    | (self.a & (X | Y));
+            }
+            6 => self.f = kept | (self.a & (X | Y)) | CARRY,
+            _ => self.f = kept | (self.a & (X | Y)) | carry << 4 | (carry ^ CARRY),
+        }
+    }
+
+    fn daa(&mut self) {
+        let mut correction = 0;
+        let mut carry = self.f & CARRY;
+        if self.f & HALF_CARRY != 0 || self.a & 0x0F > 9 {
+            correction |= 0x06;
+        }
+        if carry != 0 || self.a > 0x99 {
+            correction |= 0x60;
+            carry = CARRY;
+        }
+
+        let subtract = self.f & SUBTRACT != 0;
+        let half = if subtract {
+            self.f & HALF_CARRY != 0 && self.a & 0x0F < 6
+        } else {
+            self.a & 0x0F > 9
+        };
+        self.a = if subtract {
+            self.a.wrapping_sub(correction)
+        } else {
+            self.a.wrapping_add(correction)
+        };
+
+        self.f = sign_zero(self.a)
+            | parity(self.a)
+            | (self.f & SUBTRACT)
+            | if half { HALF_CARRY } else { 0 }
+            | carry;
+    }
+
+    // ADD ADC SUB SBC AND XOR OR CP
+    fn alu(&mut self, op: u8, value: u8) {
+        let carry = self.f & CARRY;
+        match op {
+            0 => self.a = self.add8(value, 0),
+            1 => self.a = self.add8(value, carry),
+            2 => self.a = self.sub8(value, 0),
+            3 => self.a = self.sub8(value, carry),
+            4 => {
+                self.a &= value;
+                self.f = sign_zero(self.a) | parity(self.a) | HALF_CARRY;
+            }
+            5 => {
+                self.a ^= value;
+                self.f = sign_zero(self.a) | parity(self.a);
+            }
+            6 => {
+                self.a |= value;
+                self.f = sign_zero(self.a) | parity(self.a);
+            }
+            _ => {
+                // X and Y come from the operand, not the discarded result
+                self.sub8(value, 0);
+                self.f = (self.f & !(X | Y)) | (value & (X | Y));
+            }
+        }
+    }
+
+    fn add8(&mut self, value: u8, carry: u8) -> u8 {
+        let result = self.a as u16 + value as u16 + carry as u16;
+        let byte = result as u8;
+        let overflow = (self.a ^ !value) & (self.a ^ byte) & 0x80 != 0;
+
+        self.f = sign_zero(byte)
+            | ((self.a ^ value ^ byte) & HALF_CARRY)
+            | if overflow { PARITY } else { 0 }
+            | (result >> 8) as u8;
+        byte
+    }
+
+    fn sub8(&mut self, value: u8, carry: u8) -> u8 {
+        let result = (self.a as u16)
+            .wrapping_sub(value as u16)
+            .wrapping_sub(carry as u16);
+        let byte = result as u8;
+        let overflow = (self.a ^ value) & (self.a ^ byte) & 0x80 != 0;
+
+        self.f = sign_zero(byte)
+            | SUBTRACT
+            | ((self.a ^ value ^ byte) & HALF_CARRY)
+            | if overflow { PARITY } else { 0 }
+            | ((result >> 8) as u8 & CARRY);
+        byte
+    }
+
+    fn inc8(&mut self, value: u8) -> u8 {
+        let result = value.wrapping_add(1);
+        self.f = (self.f & CARRY)
+            | sign_zero(result)
+            | if value & 0x0F == 0x0F { HALF_CARRY } else { 0 }
+            | if value == 0x7F { PARITY } else { 0 };
+        result
+    }
+
+    fn dec8(&mut self, value: u8) -> u8 {
+        let result = value.wrapping_sub(1);
+        self.f = (self.f & CARRY)
+            | SUBTRACT
+            | sign_zero(result)
+            | if value & 0x0F == 0 { HALF_CARRY } else { 0 }
+            | if value == 0x80 { PARITY } else { 0 };
+        result
+    }
+
+    fn add16(&mut self, left: u16, right: u16) -> u16 {
+        let result = left as u32 + right as u32;
+        self.wz = left.wrapping_add(1);
+        self.f = (self.f & (SIGN | ZERO | PARITY))
+            | ((result >> 8) as u8 & (X | Y))
+            | (((left ^ right ^ result as u16) >> 8) as u8 & HALF_CARRY)
+            | (result >> 16) as u8;
+        result as u16
+    }
+
+    fn adc16(&mut self, value: u16) {
+        let hl = self.hl();
+        let result = hl as u32 + value as u32 + (self.f & CARRY) as u32;
+        let word = result as u16;
+        let overflow = (hl ^ !value) & (hl ^ word) & 0x8000 != 0;
+
+        self.wz = hl.wrapping_add(1);
+        self.f = ((word >> 8) as u8 & (SIGN | X | Y))
+            | if word == 0 { ZERO } else { 0 }
+            | (((hl ^ value ^ word) >> 8) as u8 & HALF_CARRY)
+            | if overflow { PARITY } else { 0 }
+            | (result >> 16) as u8;
+        self.set_hl(word);
+    }
+
+    fn sbc16(&mut self, value: u16) {
+        let hl = self.hl();
+        let result = (hl as u32)
+            .wrapping_sub(value as u32)
+            .wrapping_sub((self.f & CARRY) as u32);
+        let word = result as u16;
+        let overflow = (hl ^ value) & (hl ^ word) & 0x8000 != 0;
+
+        self.wz = hl.wrapping_add(1);
+        self.f = ((word >> 8) as u8 & (SIGN | X | Y))
+            | if word == 0 { ZERO } else { 0 }
+            | SUBTRACT
+            | (((hl ^ value ^ word) >> 8) as u8 & HALF_CARRY)
+            | if overflow { PARITY } else { 0 }
+            | ((result >> 16) as u8 & CARRY);
+        self.set_hl(word);
+    }
+
+    // RLC RRC RL RR SLA SRA SLL SRL, setting the flags
+    fn rotate(&mut self, op: u8, value: u8) -> u8 {
+        let carry = self.f & CARRY;
+        let (result, out) = match op {
+            0 => (value.rotate_left(1), value >> 7),
+            1 => (value.rotate_right(1), value & 1),
+            2 => (value << 1 | carry, value >> 7),
+            3 => (value >> 1 | carry << 7, value & 1),
+            4 => (value << 1, value >> 7),
+            5 => (value >> 1 | (value & 0x80), value & 1),
+            // Undocumented: shifts a 1 in
+            6 => (value << 1 | 1, value >> 7),
+            _ => (value >> 1, value & 1),
+        };
+
+        self.f = sign_zero(result) | parity(result) | out;
+        result
+    }
+
+    // BIT n, with X and Y from wherever the chip took them
+    fn bit(&mut self, bit: u8, value: u8, xy: u8) {
+        let set = value & (1 << bit);
+        self.f = (self.f & CARRY)
+            | HALF_CARRY
+            | (xy & (X | Y))
+            | if set == 0 { ZERO | PARITY } else { 0 }
+            | (set & SIGN);
+    }
+
+    fn execute_cb(&mut self, opcode: u8) {
+        let (x, y, z) = (opcode >> 6, (opcode >> 3) & 7, opcode & 7);
+        let value = if z == 6 {
+            self.read(self.hl())
+        } else {
+            self.register(z, Index::HL)
+        };
+
+        if x == 1 {
+            let xy = if z == 6 { (self.wz >> 8) as u8 } else { value };
+            self.bit(y, value, xy);
+            self.cycles += if z == 6 { 12 } else { 8 };
+            return;
+        }
+
+        let result = match x {
+            0 => self.rotate(y, value),
+            2 => value & !(1 << y),
+            _ => value | 1 << y,
+        };
+        if z == 6 {
+            self.write(self.hl(), result);
+            self.cycles += 15;
+        } else {
+            self.set_register(z, Index::HL, result);
+            self.cycles += 8;
+        }
+    }
+
+    // DD CB d op and FD CB d op. Besides the memory operand, the undocumented
+    // forms copy the result into a register.
+    fn execute_index_cb(&mut self, index: Index) {
+        let displacement = self.fetch() as i8 as u16;
+        let address = self.index_register(index).wrapping_add(displacement);
+        self.wz = address;
+        // The opcode is read like an operand, without refreshing R
+        let opcode = self.fetch();
+        let (x, y, z) = (opcode >> 6, (opcode >> 3) & 7, opcode & 7);
+        let value = self.read(address);
+
+        if x == 1 {
+            self.bit(y, value, (address >> 8) as u8);
+            self.cycles += 16;
+            return;
+        }
+
+        let result = match x {
+            0 => self.rotate(y, value),
+            2 => value & !(1 << y),
+            _ => value | 1 << y,
+        };
+        self.write(address, result);
+        if z != 6 {
+            self.set_register(z, Index::HL, result);
+        }
+        self.cycles += 19;
+    }
+
+    fn execute_ed(&mut self, io: &mut dyn Io, opcode: u8) {
+        let (x, y, z) = (opcode >> 6, (opcode >> 3) & 7, opcode & 7);
+        let (p, q) = (y >> 1, y & 1);
+
+        match (x, z) {
+            (1, 0) => {
+                // IN r,(C). IN (C) only sets the flags.
+                self.wz = self.bc().wrapping_add(1);
+                let value = io.input(self.c);
+                self.f = (self.f & CARRY) | sign_zero(value) | parity(value);
+                if y != 6 {
+                    self.set_register(y, Index::HL, value);
+                }
+                self.cycles += 12;
+            }
+            (1, 1) => {
+                // OUT (C),r. OUT (C),0 is undocumented.
+                let value = if y == 6 {
+                    0
+                } else {
+                    self.register(y, Index::HL)
+                };
+                io.output(self.c, value);
+                self.wz = self.bc().wrapping_add(1);
+                self.cycles += 12;
+            }
+            (1, 2) => {
+                // SBC HL,rr and ADC HL,rr
+                let value = self.pair(p, Index::HL, false);
+                if q == 0 {
+                    self.sbc16(value);
+                } else {
+                    self.adc16(value);
+                }
+                self.cycles += 15;
+            }
+            (1, 3) => {
+                // LD (nn),rr and LD rr,(nn)
+                let address = self.fetch_word();
+                if q == 0 {
+                    self.write_word(address, self.pair(p, Index::HL, false));
+                } else {
+                    let value = self.read_word(address);
+                    self.set_pair(p, Index::HL, false, value);
+                }
+                self.wz = address.wrapping_add(1);
+                self.cycles += 20;
+            }
+            (1, 4) => {
+                // NEG
+                let value = self.a;
+                self.a = 0;
+                self.a = self.sub8(value, 0);
+                self.cycles += 8;
+            }
+            (1, 5) => {
+                // RETN, and RETI, which the CPU treats the same
+                self.iff1 = self.iff2;
+                self.ret();
+                self.cycles += 14;
+            }
+            (1, 6) => {
+                self.im = [0, 0, 1, 2][y as usize & 3];
+                self.cycles += 8;
+            }
+            (1, 7) => {
+                match y {
+                    0 => self.i = self.a,
+                    1 => self.r = self.a,
+                    2 | 3 => {
+                        self.a = if y == 2 { self.i } else { self.r };
+                        self.f = (self.f & CARRY)
+                            | sign_zero(self.a)
+                            | if self.iff2 { PARITY } else { 0 };
+                    }
+                    4 | 5 => self.rotate_decimal(y == 5),
+                    _ => {}
+                }
+                self.cycles += match y {
+                    4 | 5 => 18,
+                    6 | 7 => 8,
+                    _ => 9,
+                };
+            }
+            (2, 0..=3) if y >= 4 => self.block(io, y, z),
+            // Everything else is an 8 cycle NOP
+            _ => self.cycles += 8,
+        }
+    }
+
+    // RRD, or RLD if `left`: rotates the low nibble of A with the byte at (HL)
+    fn rotate_decimal(&mut self, left: bool) {
+        let address = self.hl();
+        let value = self.read(address);
+        let result = if left {
+            let result = value << 4 | (self.a & 0x0F);
+            self.a = (self.a & 0xF0) | value >> 4;
+            result
+        } else {
+            let result = self.a << 4 | value >> 4;
+            self.a = (self.a & 0xF0) | (value & 0x0F);
+            result
+        };
+
+        self.write(address, result);
+        self.wz = address.wrapping_add(1);
+        self.f = (self.f & CARRY) | sign_zero(self.a) | parity(self.a);
+    }
+
+    // LDI CPI INI OUTI and their decrementing and repeating forms. `y` is 4 to 7 for
+    // increment, decrement, increment and repeat, decrement and repeat.
+    fn block(&mut self, io: &mut dyn Io, y: u8, z: u8) {
+        let step = if y & 1 == 0 { 1 } else { 0xFFFF };
+        let repeat = y >= 6;
+        let hl = self.hl();
+
+        let again = match z {
+            0 => {
+                // LDI
+                let value = self.read(hl);
+                self.write(self.de(), value);
+                self.set_hl(hl.wrapping_add(step));
+                self.set_de(self.de().wrapping_add(step));
+                self.set_bc(self.bc().wrapping_sub(1));
+
+                let n = value.wrapping_add(self.a);
+                self.f = (self.f & (SIGN | ZERO | CARRY))
+                    | (n & X)
+                    | (n << 4 & Y)
+                    | if self.bc() != 0 { PARITY } else { 0 };
+                self.bc() != 0
+            }
+            1 => {
+                // CPI
+                let value = self.read(hl);
+                let result = self.a.wrapping_sub(value);
+                let half = (self.a ^ value ^ result) & HALF_CARRY;
+                self.set_hl(hl.wrapping_add(step));
+                self.set_bc(self.bc().wrapping_sub(1));
+                self.wz = self.wz.wrapping_add(step);
+
+                let n = result.wrapping_sub(half >> 4);
+                self.f = (self.f & CARRY)
+                    | SUBTRACT
+                    | (sign_zero(result) & (SIGN | ZERO))
+                    | half
+                    | (n & X)
+                    | (n << 4 & Y)
+                    | if self.bc() != 0 { PARITY } else { 0 };
+                self.bc() != 0 && result != 0
+            }
+            2 => {
+                // INI
+                let value = io.input(self.c);
+                self.wz = self.bc().wrapping_add(step);
+                self.write(hl, value);
+                self.set_hl(hl.wrapping_add(step));
+                self.b = self.b.wrapping_sub(1);
+
+                let k = value as u16 + self.c.wrapping_add(step as u8) as u16;
+                self.block_io_flags(value, k);
+                self.b != 0
+            }
+            _ => {
+                // OUTI
+                let value = self.read(hl);
+                self.b = self.b.wrapping_sub(1);
+                self.wz = self.bc().wrapping_add(step);
+                io.output(self.c, value);
+                self.set_hl(hl.wrapping_add(step));
+
+                let k = value as u16 + self.l as u16;
+                self.block_io_flags(value, k);
+                self.b != 0
+            }
+        };
+
+        if repeat && again {
+            self.pc = self.pc.wrapping_sub(2);
+            self.wz = self.pc.wrapping_add(1);
+            self.cycles += 21;
+        } else {
+            self.cycles += 16;
+        }
+    }
+
+    fn block_io_flags(&mut self, value: u8, k: u16) {
+        let carry = if k > 0xFF { HALF_CARRY | CARRY } else { 0 };
+        self.f =
+            sign_zero(self.b) | (value >> 6 & SUBTRACT) | carry | parity((k as u8 & 7) ^ self.b);
+    }
+}
+
+// Tag and length of a Z80's saved state
+const SAVED_TAG: &[u8] = b"Z80 ";
+const SAVED_LEN: usize = 44;
+
+impl Cpu for Z80<'_> {
+    fn step_io(&mut self, io: &mut dyn Io) -> Status {
+        Z80::step_io(self, io)
+    }
+
+    fn reset(&mut self) {
+        Z80::reset(self)
+    }
+
+    fn interrupt(&mut self, vector: u8) {
+        Z80::interrupt(self, vector)
+    }
+
+    fn interrupt_with(&mut self, data: &[u8]) {
+        Z80::interrupt_with(self, data)
+    }
+
+    fn cycles(&self) -> u64 {
+        self.cycles
+    }
+
+    fn status(&self) -> Status {
+        Z80::status(self)
+    }
+
+    fn registers(&self) -> Registers {
+        Registers {
+            a: self.a,
+            flags: self.f,
+            bc: self.bc(),
+            de: self.de(),
+            hl: self.hl(),
+            sp: self.sp,
+            pc: self.pc,
+        }
+    }
+
+    fn set_registers(&mut self, registers: Registers) {
+        self.a = registers.a;
+        self.f = registers.flags;
+        self.set_bc(registers.bc);
+        self.set_de(registers.de);
+        self.set_hl(registers.hl);
+        self.sp = registers.sp;
+        self.pc = registers.pc;
+    }
+
+    fn memory(&self) -> &[u8] {
+        self.mem
+    }
+
+    fn memory_mut(&mut self) -> &mut [u8] {
+        self.mem
+    }
+
+    fn protect(&mut self, range: Range<usize>) {
+        Z80::protect(self, range)
+    }
+
+    fn save_state(&self) -> Vec<u8> {
+        let mut state = SAVED_TAG.to_vec();
+        state.extend_from_slice(&[
+            self.a, self.f, self.b, self.c, self.d, self.e, self.h, self.l,
+        ]);
+        for word in self
+            .alternate
+            .iter()
+            .chain(&[self.ix, self.iy, self.sp, self.pc])
+        {
+            state.extend_from_slice(&word.to_le_bytes());
+        }
+        state.extend_from_slice(&[self.i, self.r]);
+        state.extend_from_slice(&self.wz.to_le_bytes());
+        state.extend_from_slice(&[self.iff1 as u8, self.iff2 as u8, self.im, self.halted as u8]);
+        state.extend_from_slice(&self.cycles.to_le_bytes());
+        state
+    }
+
+    fn load_state(&mut self, state: &[u8]) -> Result<(), Box<dyn error::Error>> {
+        let mut state = StateReader::new(state, SAVED_TAG, SAVED_LEN)?;
+
+        for register in [
+            &mut self.a,
+            &mut self.f,
+            &mut self.b,
+            &mut self.c,
+            &mut self.d,
+            &mut self.e,
+            &mut self.h,
+            &mut self.l,
+        ] {
+            *register = state.byte();
+        }
+        for word in self.alternate.iter_mut() {
+            *word = state.word();
+        }
+        self.ix = state.word();
+        self.iy = state.word();
+        self.sp = state.word();
+        self.pc = state.word();
+        self.i = state.byte();
+        self.r = state.byte();
+        self.wz = state.word();
+        self.iff1 = state.byte() != 0;
+        self.iff2 = state.byte() != 0;
+        self.im = state.byte();
+        self.halted = state.byte() != 0;
+        self.cycles = state.long();
+        self.ei_delay = false;
+        self.pending = None;
+        self.bus = None;
+        Ok(())
+    }
+}
+
+// S, Z and the undocumented X and Y for a result
+fn sign_zero(value: u8) -> u8 {
+    (value & (SIGN | X | Y)) | if value == 0 { ZERO } else { 0 }
+}
+
+fn parity(value: u8) -> u8 {
+    if value.count_ones().is_multiple_of(2) {
+        PARITY
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,391 @@
+use super::*;
+
+// Runs `program` from 0 until it halts, in 64K of memory
+fn run(program: &[u8]) -> (Z80<'static>, u64) {
+    let mem = Box::leak(vec![0; 0x10000].into_boxed_slice());
+    mem[..program.len()].copy_from_slice(program);
+    let mut cpu = Z80::new(mem);
+    cpu.set_sp(0xF000);
+    cpu.start();
+    let cycles = cpu.cycles();
+    (cpu, cycles)
+}
+
+#[test]
+fn exchange_alternate_registers() {
+    // LD BC,1111 LD HL,2222 EXX LD BC,3333 EX AF,AF' HALT
+    let (cpu, _) = run(&[
+        0x01, 0x11, 0x11, 0x21, 0x22, 0x22, 0xD9, 0x01, 0x33, 0x33, 0x08, 0x76,
+    ]);
+
+    assert_eq!(cpu.bc(), 0x3333);
+    assert_eq!(cpu.hl(), 0);
+    assert_eq!(cpu.alternate(), [0xFFFF, 0x1111, 0, 0x2222]);
+    assert_eq!(cpu.af(), 0);
+}
+
+#[test]
+fn index_registers() {
+    // LD IX,1000 LD (IX+5),42 LD A,(IX+5) LD IYH,12 LD IYL,34 INC (IX-1) HALT
+    let (cpu, cycles) = run(&[
+        0xDD, 0x21, 0x00, 0x10, 0xDD, 0x36, 0x05, 0x42, 0xDD, 0x7E, 0x05, 0xFD, 0x26, 0x12, 0xFD,
+        0x2E, 0x34, 0xDD, 0x34, 0xFF, 0x76,
+    ]);
+
+    assert_eq!(cpu.ix(), 0x1000);
+    assert_eq!(cpu.a(), 0x42);
+    assert_eq!(cpu.iy(), 0x1234);
+    assert_eq!(cpu.memory()[0x0FFF], 1);
+    assert_eq!(cycles, 14 + 19 + 19 + 11 + 11 + 23 + 4);
+}
+
+#[test]
+fn index_halves_not_used_with_memory_operand() {
+    // LD IX,1000 LD H,(IX+0) HALT
+    let mut program = vec![0xDD, 0x21, 0x00, 0x10, 0xDD, 0x66, 0x00, 0x76];
+    program.resize(0x1001, 0);
+    program[0x1000] = 0x99;
+    let (cpu, _) = run(&program);
+
+    assert_eq!(cpu.hl() >> 8, 0x99);
+    assert_eq!(cpu.ix(), 0x1000);
+}
+
+#[test]
+fn relative_jumps() {
+    // LD B,3 XOR A loop: INC A DJNZ loop JR Z,0 HALT
+    let (cpu, cycles) = run(&[0x06, 0x03, 0xAF, 0x3C, 0x10, 0xFD, 0x28, 0xF8, 0x76]);
+
+    assert_eq!(cpu.a(), 3);
+    assert_eq!(cpu.bc() >> 8, 0);
+    assert_eq!(cycles, 7 + 4 + 3 * 4 + 2 * 13 + 8 + 7 + 4);
+}
+
+#[test]
+fn block_copy() {
+    // LD HL,100 LD DE,200 LD BC,4 LDIR HALT
+    let mut program = vec![
+        0x21, 0x00, 0x01, 0x11, 0x00, 0x02, 0x01, 0x04, 0x00, 0xED, 0xB0, 0x76,
+    ];
+    program.resize(0x104, 0);
+    program[0x100..].copy_from_slice(b"Z80!");
+    let (cpu, cycles) = run(&program);
+
+    assert_eq!(&cpu.memory()[0x200..0x204], b"Z80!");
+    assert_eq!((cpu.hl(), cpu.de(), cpu.bc()), (0x104, 0x204, 0));
+    assert_eq!(cpu.flags() & PARITY, 0);
+    assert_eq!(cycles, 30 + 3 * 21 + 16 + 4);
+}
+
+#[test]
+fn block_compare_stops_on_match() {
+    // LD HL,100 LD BC,10 LD A,'!' CPIR HALT
+    let mut program = vec![
+        0x21, 0x00, 0x01, 0x01, 0x10, 0x00, 0x3E, b'!', 0xED, 0xB1, 0x76,
+    ];
+    program.resize(0x104, 0);
+    program[0x100..].copy_from_slice(b"Z80!");
+    let (cpu, _) = run(&program);
+
+    assert_eq!(cpu.hl(), 0x104);
+    assert_eq!(cpu.bc(), 0x0C);
+    assert_ne!(cpu.flags() & ZERO, 0);
+}
+
+#[test]
+fn sixteen_bit_arithmetic() {
+    // LD HL,8000 LD DE,8000 SCF ADC HL,DE HALT
+    let (cpu, _) = run(&[0x21, 0x00, 0x80, 0x11, 0x00, 0x80, 0x37, 0xED, 0x5A, 0x76]);
+    assert_eq!(cpu.hl(), 1);
+    assert_eq!(cpu.flags() & (CARRY | PARITY | ZERO), CARRY | PARITY);
+
+    // LD HL,1 LD DE,1 AND A SBC HL,DE HALT
+    let (cpu, _) = run(&[0x21, 0x01, 0x00, 0x11, 0x01, 0x00, 0xA7, 0xED, 0x52, 0x76]);
+    assert_eq!(cpu.hl(), 0);
+    assert_eq!(cpu.flags() & (ZERO | SUBTRACT | CARRY), ZERO | SUBTRACT);
+}
+
+#[test]
+fn negate_and_decimal_adjust() {
+    // LD A,1 NEG HALT
+    let (cpu, _) = run(&[0x3E, 0x01, 0xED, 0x44, 0x76]);
+    assert_eq!(cpu.a(), 0xFF);
+    assert_eq!(
+        cpu.flags() & (CARRY | SUBTRACT | SIGN),
+        CARRY | SUBTRACT | SIGN
+    );
+
+    // LD A,15 SUB 6 DAA HALT: BCD 15 - 6 is 09
+    let (cpu, _) = run(&[0x3E, 0x15, 0xD6, 0x06, 0x27, 0x76]);
+    assert_eq!(cpu.a(), 0x09);
+
+    // LD A,99 ADD A,1 DAA HALT
+    let (cpu, _) = run(&[0x3E, 0x99, 0xC6, 0x01, 0x27, 0x76]);
+    assert_eq!(cpu.a(), 0x00);
+    assert_eq!(cpu.flags() & (CARRY | ZERO), CARRY | ZERO);
+}
+
+#[test]
+fn undocumented_flag_bits() {
+    // LD A,28 CP 20 HALT: X and Y come from the operand
+    let (cpu, _) = run(&[0x3E, 0x28, 0xFE, 0x20, 0x76]);
+    assert_eq!(cpu.flags() & (X | Y), Y);
+
+    // LD A,28 SUB 20 HALT: and from the result otherwise
+    let (cpu, _) = run(&[0x3E, 0x28, 0xD6, 0x20, 0x76]);
+    assert_eq!(cpu.flags() & (X | Y), X);
+}
+
+#[test]
+fn bit_test_flags() {
+    // LD B,80 BIT 7,B BIT 0,B HALT
+    let (cpu, _) = run(&[0x06, 0x80, 0xCB, 0x78, 0x76]);
+    assert_eq!(cpu.flags() & (ZERO | SIGN | HALF_CARRY), SIGN | HALF_CARRY);
+
+    let (cpu, _) = run(&[0x06, 0x80, 0xCB, 0x40, 0x76]);
+    assert_eq!(cpu.flags() & (ZERO | PARITY), ZERO | PARITY);
+}
+
+#[test]
+fn undocumented_shift_and_register_copy() {
+    // LD B,80 SLL B LD IX,1000 LD (IX+1),81 RLC (IX+1),C HALT
+    let (cpu, cycles) = run(&[
+        0x06, 0x80, 0xCB, 0x30, 0xDD, 0x21, 0x00, 0x10, 0xDD, 0x36, 0x01, 0x81, 0xDD, 0xCB, 0x01,
+        0x01, 0x76,
+    ]);
+
+    assert_eq!(cpu.bc(), 0x0103);
+    assert_eq!(cpu.memory()[0x1001], 0x03);
+    assert_eq!(cycles, 7 + 8 + 14 + 19 + 23 + 4);
+}
+
+#[test]
+fn rotate_decimal() {
+    // LD HL,100 LD (HL),34 LD A,12 RLD HALT
+    let (cpu, _) = run(&[0x21, 0x00, 0x01, 0x36, 0x34, 0x3E, 0x12, 0xED, 0x6F, 0x76]);
+
+    assert_eq!(cpu.a(), 0x13);
+    assert_eq!(cpu.memory()[0x100], 0x42);
+}
+
+#[test]
+fn interrupt_modes() {
+    let mut mem = vec![0; 0x10000];
+    // EI IM 1 HALT at 0, EI RETI at 38
+    mem[..4].copy_from_slice(&[0xFB, 0xED, 0x56, 0x76]);
+    mem[0x38..0x3B].copy_from_slice(&[0xFB, 0xED, 0x4D]);
+    mem[0x1234] = 0xAA;
+    let mut cpu = Z80::new(&mut mem);
+    cpu.set_sp(0xF000);
+
+    assert_eq!(cpu.start(), Status::Halted);
+    cpu.interrupt(2);
+    assert_eq!(cpu.pc(), 0x38);
+    assert_eq!(cpu.status(), Status::Running);
+    cpu.step();
+    cpu.step();
+    assert_eq!(cpu.pc(), 4);
+
+    // Mode 2 calls through the table at I
+    cpu.memory_mut()[0x80FE..0x8100].copy_from_slice(&[0x34, 0x12]);
+    cpu.set_i(0x80);
+    cpu.memory_mut()[4..6].copy_from_slice(&[0xED, 0x5E]);
+    cpu.step();
+    assert_eq!(cpu.interrupt_mode(), 2);
+    cpu.interrupt_with(&[0xFE]);
+    assert_eq!(cpu.pc(), 0x1234);
+
+    // Masked until interrupts are enabled again
+    cpu.interrupt(0);
+    assert_eq!(cpu.pc(), 0x1234);
+}
+
+#[test]
+fn mode_zero_executes_the_bus() {
+    let mut mem = vec![0; 0x10000];
+    mem[0] = 0xFB; // EI, then a NOP
+    let mut cpu = Z80::new(&mut mem);
+    cpu.set_sp(0xF000);
+    cpu.step();
+    cpu.step();
+    let r = cpu.r();
+
+    // CALL 1234 on the bus, fetched by the one M1 cycle of the acknowledge
+    cpu.interrupt_with(&[0xCD, 0x34, 0x12]);
+
+    assert_eq!(cpu.pc(), 0x1234);
+    assert_eq!(cpu.memory()[0xEFFE], 2);
+    assert_eq!(cpu.r(), r + 1);
+}
+
+#[test]
+fn ei_holds_off_interrupts_for_an_instruction() {
+    let mut mem = vec![0; 0x10000];
+    // IM 1 EI HALT at 0, EI RETI at 38
+    mem[..4].copy_from_slice(&[0xED, 0x56, 0xFB, 0x76]);
+    mem[0x38..0x3B].copy_from_slice(&[0xFB, 0xED, 0x4D]);
+    let mut cpu = Z80::new(&mut mem);
+    cpu.set_sp(0xF000);
+    cpu.step();
+    cpu.step();
+
+    // Held until HALT has run, then taken straight away
+    cpu.interrupt(7);
+    assert_eq!(cpu.pc(), 3);
+    cpu.step();
+    assert_eq!(cpu.pc(), 0x38);
+    assert_eq!(cpu.sp(), 0xEFFE);
+
+    // Another request after the handler's EI waits for RETI instead of nesting
+    cpu.step();
+    cpu.interrupt(7);
+    assert_eq!(cpu.pc(), 0x39);
+    cpu.step();
+    assert_eq!(cpu.pc(), 0x38);
+    assert_eq!(cpu.sp(), 0xEFFE);
+    assert_eq!(cpu.memory()[0xEFFE], 4);
+}
+
+#[test]
+fn non_maskable_interrupt() {
+    let mut mem = vec![0; 0x10000];
+    // EI, then RETN at 66
+    mem[0] = 0xFB;
+    mem[0x66..0x68].copy_from_slice(&[0xED, 0x45]);
+    let mut cpu = Z80::new(&mut mem);
+    cpu.set_sp(0xF000);
+    cpu.step();
+
+    cpu.nmi();
+    assert_eq!(cpu.pc(), 0x66);
+    cpu.interrupt(7);
+    assert_eq!(cpu.pc(), 0x66);
+
+    cpu.step();
+    assert_eq!(cpu.pc(), 1);
+    cpu.interrupt(7);
+    assert_eq!(cpu.pc(), 0x38);
+}
+
+#[test]
+fn port_io() {
+    struct Echo(Vec<(u8, u8)>);
+    impl Io for Echo {
+        fn input(&mut self, port: u8) -> u8 {
+            port.wrapping_add(1)
+        }
+        fn output(&mut self, port: u8, value: u8) {
+            self.0.push((port, value));
+        }
+    }
+
+    let mut mem = vec![0; 0x10000];
+    // IN A,(10) LD C,20 OUT (C),A IN B,(C) HALT
+    mem[..9].copy_from_slice(&[0xDB, 0x10, 0x0E, 0x20, 0xED, 0x79, 0xED, 0x40, 0x76]);
+    let mut cpu = Z80::new(&mut mem);
+    let mut io = Echo(Vec::new());
+    while cpu.step_io(&mut io) == Status::Running {}
+
+    assert_eq!(io.0, vec![(0x20, 0x11)]);
+    assert_eq!(cpu.bc(), 0x2120);
+}
+
+#[test]
+fn refresh_register() {
+    // NOP LD IX,0 HALT: one for each opcode fetch, prefix included
+    let (cpu, _) = run(&[0x00, 0xDD, 0x21, 0x00, 0x00, 0x76]);
+
+    assert_eq!(cpu.r(), 4);
+}
+
+// Opcodes that mean something else on a Z80, or whose effects the cores are allowed
+// to disagree on: DAA's half carry, PUSH PSW's unused flag bits, and I/O
+fn shared_with_8080(opcode: u8) -> bool {
+    !matches!(
+        opcode,
+        0x08 | 0x10
+            | 0x18
+            | 0x20
+            | 0x28
+            | 0x30
+            | 0x38
+            | 0x27
+            | 0xCB
+            | 0xD9
+            | 0xDD
+            | 0xED
+            | 0xFD
+            | 0xF5
+            | 0xD3
+            | 0xDB
+            | 0x76
+    )
+}
+
+#[test]
+fn matches_8080_on_shared_opcodes() {
+    use crate::emulator::State;
+
+    let mut seed = 0x2545_F491_u32;
+    let mut random = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        seed
+    };
+    let memory: Vec<u8> = (0..0x10000).map(|_| random() as u8).collect();
+
+    for opcode in (0..=0xFF).filter(|opcode| shared_with_8080(*opcode)) {
+        for _ in 0..16 {
+            let mut mem_8080 = memory.clone();
+            let mut mem_z80 = memory.clone();
+            let code = [opcode, random() as u8, random() as u8];
+            mem_8080[0x100..0x103].copy_from_slice(&code);
+            mem_z80[0x100..0x103].copy_from_slice(&code);
+            let af = random() as u16;
+            let pairs = [random() as u16, random() as u16, random() as u16];
+
+            let mut i8080 = State::new(&mut mem_8080);
+            let mut z80 = Z80::new(&mut mem_z80);
+            i8080.set_psw(af);
+            z80.set_af(af);
+            i8080.set_bc(pairs[0]);
+            z80.set_bc(pairs[0]);
+            i8080.set_de(pairs[1]);
+            z80.set_de(pairs[1]);
+            i8080.set_hl(pairs[2]);
+            z80.set_hl(pairs[2]);
+            i8080.set_sp(0x8000);
+            z80.set_sp(0x8000);
+            i8080.set_pc(0x100);
+            z80.set_pc(0x100);
+
+            i8080.step();
+            z80.step();
+
+            assert_eq!(
+                (z80.a(), z80.bc(), z80.de(), z80.hl(), z80.sp(), z80.pc()),
+                (
+                    i8080.a(),
+                    i8080.bc(),
+                    i8080.de(),
+                    i8080.hl(),
+                    i8080.sp(),
+                    i8080.pc()
+                ),
+                "Opcode {:02X}",
+                opcode
+            );
+            let sign_zero_carry = (SIGN | ZERO | CARRY) as u16;
+            assert_eq!(
+                z80.af() & sign_zero_carry,
+                i8080.psw() & sign_zero_carry,
+                "Opcode {:02X} flags",
+                opcode
+            );
+            drop(i8080);
+            drop(z80);
+            assert!(mem_8080 == mem_z80, "Opcode {:02X} memory", opcode);
+        }
+    }
+}